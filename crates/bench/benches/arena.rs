@@ -0,0 +1,68 @@
+extern crate ast_python;
+extern crate auto_lsp;
+extern crate divan;
+
+use ast_python::db::PYTHON;
+use ast_python::generated::{Attribute, Call, ExpressionStatement, Identifier};
+use auto_lsp::{
+    core::ast::{AstArena, AstNode},
+    default::db::{BaseDb, FileManager, file::File, tracked::get_ast},
+    lsp_types::Url,
+};
+use divan::{AllocProfiler, Bencher};
+
+#[global_allocator]
+static ALLOC: AllocProfiler = AllocProfiler::system();
+
+static DJANGO: &str = include_str!("./django.py");
+
+fn main() {
+    divan::main();
+}
+
+/// Nodes of type `T` of the AST of `django.py`.
+fn nodes<T: AstNode + Clone>() -> Vec<T> {
+    let url = Url::parse("file:///django.py").expect("Failed to parse URL");
+    let mut db = BaseDb::default();
+    let file = File::from_string()
+        .db(&db)
+        .source(DJANGO.to_string())
+        .url(&url)
+        .parsers(&PYTHON)
+        .call()
+        .expect("Failed to create file");
+
+    db.add_file(file).expect("Failed to add file");
+    get_ast(&db, file).of_type::<T>().cloned().collect()
+}
+
+/// Stores the nodes with one allocation per node, like ASTs did before the arena.
+#[divan::bench(types = [Identifier, Attribute, Call, ExpressionStatement])]
+fn store_boxed<T: AstNode + Clone>(bencher: Bencher) {
+    let nodes = nodes::<T>();
+
+    bencher
+        .with_inputs(|| nodes.clone())
+        .bench_local_values(|nodes| {
+            nodes
+                .into_iter()
+                .map(|node| Box::new(node) as Box<dyn AstNode>)
+                .collect::<Vec<_>>()
+        });
+}
+
+/// Stores the nodes in an [`AstArena`], in a column per type.
+#[divan::bench(types = [Identifier, Attribute, Call, ExpressionStatement])]
+fn store_arena<T: AstNode + Clone>(bencher: Bencher) {
+    let nodes = nodes::<T>();
+
+    bencher
+        .with_inputs(|| nodes.clone())
+        .bench_local_values(|nodes| {
+            let mut arena = AstArena::default();
+            for (id, node) in nodes.into_iter().enumerate() {
+                arena.insert(id, node);
+            }
+            arena
+        });
+}
@@ -62,6 +62,30 @@ fn build_ast(bencher: Bencher) {
     assert!(errors.is_empty(), "Expected no errors, found: {:?}", errors);
 }
 
+/// Build the AST of a new file on each iteration, to count the allocations of a full build.
+#[divan::bench]
+fn build_ast_allocations(bencher: Bencher) {
+    let url = Url::parse("file:///django.py").expect("Failed to parse URL");
+
+    bencher
+        .with_inputs(|| {
+            let mut db = BaseDb::default();
+            let file = File::from_string()
+                .db(&db)
+                .source(DJANGO.to_string())
+                .url(&url)
+                .parsers(&PYTHON)
+                .call()
+                .expect("Failed to create file");
+
+            db.add_file(file).expect("Failed to add file");
+            (db, file)
+        })
+        .bench_local_refs(|(db, file)| {
+            get_ast(db, *file);
+        });
+}
+
 /// Reparse the file after a change.
 #[divan::bench]
 fn reparse(bencher: Bencher) {
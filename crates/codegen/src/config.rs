@@ -0,0 +1,134 @@
+use crate::CodegenError;
+use proc_macro2::TokenStream;
+use std::collections::{BTreeSet, HashMap};
+
+/// Configuration of the generated code, see [`crate::Generator::with_config`]
+///
+/// # Example
+///
+/// ```rust
+/// use auto_lsp_codegen::{CodegenConfig, Generator};
+///
+/// let _result = Generator::new(&tree_sitter_python::LANGUAGE.into())
+///     .with_config(
+///         CodegenConfig::new()
+///             .rename("CompoundStatement_SimpleStatement", "Statement")
+///             .skip_rule("print_statement")
+///             .derive("Eq")
+///             .derive("Hash")
+///             .visibility("pub(crate)"),
+///     )
+///     .generate(tree_sitter_python::NODE_TYPES)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CodegenConfig {
+    pub(crate) renames: HashMap<String, String>,
+    pub(crate) skipped_rules: BTreeSet<String>,
+    derives: Vec<String>,
+    attributes: Vec<String>,
+    visibility: String,
+    crate_path: String,
+}
+
+impl Default for CodegenConfig {
+    fn default() -> Self {
+        Self {
+            renames: HashMap::new(),
+            skipped_rules: BTreeSet::new(),
+            derives: vec![],
+            attributes: vec![],
+            visibility: "pub".to_string(),
+            crate_path: "auto_lsp::core".to_string(),
+        }
+    }
+}
+
+impl CodegenConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames a generated type, given its default name such as `CompoundStatement_SimpleStatement`.
+    ///
+    /// Enum variants, `visit_<node>` methods and `NodeKind` variants follow the new name.
+    pub fn rename(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.renames.insert(from.into(), to.into());
+        self
+    }
+
+    /// Skips a rule, given its tree-sitter kind such as `print_statement`.
+    ///
+    /// No type is generated for the rule, and it is removed from the enums and fields it appears in.
+    /// Fields whose types are all skipped are removed as well.
+    ///
+    /// A skipped node found among the children of a node is ignored,
+    /// but one found in a field that has other types is reported as an unexpected child.
+    pub fn skip_rule(mut self, kind: impl Into<String>) -> Self {
+        self.skipped_rules.insert(kind.into());
+        self
+    }
+
+    /// Adds a derive to every generated struct and enum, such as `Hash` or `serde::Deserialize`.
+    pub fn derive(mut self, derive: impl Into<String>) -> Self {
+        self.derives.push(derive.into());
+        self
+    }
+
+    /// Adds an attribute to every generated struct and enum, such as `#[non_exhaustive]`.
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.attributes.push(attribute.into());
+        self
+    }
+
+    /// Sets the visibility of the generated items, `pub` by default.
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = visibility.into();
+        self
+    }
+
+    /// Sets the path of the `auto_lsp_core` crate in the generated code, `auto_lsp::core` by default.
+    ///
+    /// Use `auto_lsp_core` when depending on the core crate directly.
+    pub fn crate_path(mut self, crate_path: impl Into<String>) -> Self {
+        self.crate_path = crate_path.into();
+        self
+    }
+
+    /// Parses the configuration into tokens
+    pub(crate) fn tokens(&self) -> Result<ConfigTokens, CodegenError> {
+        Ok(ConfigTokens {
+            derives: self
+                .derives
+                .iter()
+                .map(|derive| parse("derive", derive))
+                .collect::<Result<_, _>>()?,
+            attributes: self
+                .attributes
+                .iter()
+                .map(|attribute| parse("attribute", attribute))
+                .collect::<Result<_, _>>()?,
+            visibility: parse("visibility", &self.visibility)?,
+            crate_path: parse("crate path", &self.crate_path)?,
+        })
+    }
+}
+
+/// Parsed [`CodegenConfig`]
+#[derive(Default, Clone)]
+pub(crate) struct ConfigTokens {
+    pub(crate) derives: Vec<TokenStream>,
+    pub(crate) attributes: Vec<TokenStream>,
+    pub(crate) visibility: TokenStream,
+    pub(crate) crate_path: TokenStream,
+}
+
+fn parse(option: &'static str, value: &str) -> Result<TokenStream, CodegenError> {
+    value
+        .parse()
+        .map_err(|error: proc_macro2::LexError| CodegenError::InvalidConfig {
+            option,
+            value: value.to_string(),
+            error: error.to_string(),
+        })
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+/// Error returned by [`crate::generate`] when the node types do not match the language,
+/// or when the [`crate::CodegenConfig`] is invalid.
+#[derive(Error, Debug)]
+pub enum CodegenError {
+    #[error("Invalid node-types.json: {0}")]
+    InvalidNodeTypes(#[from] serde_json::Error),
+    #[error("Invalid grammar.json: {0}")]
+    InvalidGrammar(serde_json::Error),
+    #[error("Invalid parser.c: {0}")]
+    InvalidParser(String),
+    #[error("Unknown {} node kind {kind:?} in language", if *named { "named" } else { "anonymous" })]
+    UnknownNodeKind { kind: String, named: bool },
+    #[error("Unknown field {0:?} in language")]
+    UnknownField(String),
+    #[error("Invalid {option} {value:?}: {error}")]
+    InvalidConfig {
+        option: &'static str,
+        value: String,
+        error: String,
+    },
+    #[error("All the variants of {0} are skipped")]
+    EmptyEnum(String),
+}
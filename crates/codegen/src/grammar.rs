@@ -0,0 +1,279 @@
+use crate::Generator;
+use crate::ir::{Child, FieldOrChildren, Kind};
+use crate::json::{TypeInfo, base_type};
+use crate::utils::{pascal_to_snake, sanitize_string};
+use quote::{format_ident, quote};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Anonymous tokens of a rule that are neither in a field nor among its named children
+///
+/// Since `node-types.json` does not list them, they are found in the `grammar.json` of the language.
+pub(crate) struct AnonymousChild {
+    pub(crate) kind: AnonymousKind,
+    /// Possible tokens, in grammar order
+    pub(crate) types: Vec<TypeInfo>,
+    /// `false` if the rule always has one of the tokens
+    pub(crate) optional: bool,
+}
+
+pub(crate) enum AnonymousKind {
+    /// An optional keyword, such as `async` in `optional('async')`
+    Keyword,
+    /// A choice between several tokens, such as `choice('*', '**')`
+    Operator,
+}
+
+impl AnonymousChild {
+    /// Returns the field of this child, named `<keyword>_token` or `operator_token`
+    ///
+    /// `index` is the number of operators found before this one in the rule, to name the next ones `operator_token_<index>`.
+    /// Returns `None` if one of the tokens is skipped or is not part of the node types.
+    pub(crate) fn child_code_gen(
+        &self,
+        generator: &mut Generator,
+        index: usize,
+    ) -> Option<FieldOrChildren> {
+        if self.types.iter().any(|t| {
+            generator.is_skipped(&t.kind) || generator.registered_node_ids(&t.kind, false).is_err()
+        }) {
+            return None;
+        }
+
+        let (name, base_type) = match self.kind {
+            AnonymousKind::Keyword => {
+                let token = sanitize_string(&self.types[0].kind, &generator.tokens);
+                (
+                    format!("{}_token", pascal_to_snake(&token)),
+                    generator.type_ident(&format!("Token_{token}")),
+                )
+            }
+            AnonymousKind::Operator => (
+                match index {
+                    0 => "operator_token".to_string(),
+                    _ => format!("operator_token_{index}"),
+                },
+                base_type(generator, &self.types)?,
+            ),
+        };
+
+        let kind = match (self.optional, generator.options.lenient_fields) {
+            (true, _) => Kind::Option,
+            (false, true) => Kind::Lenient,
+            (false, false) => Kind::Base,
+        };
+
+        Some(FieldOrChildren::Child(Child {
+            ident: format_ident!("{}", name),
+            name,
+            kind,
+            field_name: quote! { #base_type },
+            expected: self.types.iter().map(|t| t.kind.clone()).collect(),
+        }))
+    }
+}
+
+/// Returns the anonymous children of each visible rule of a `grammar.json`
+///
+/// Only keywords that may be missing and choices of tokens are kept,
+/// and only if each of their tokens appears once in the rule (fields and repetitions aside),
+/// so that every token of the node belongs to a single child.
+pub(crate) fn anonymous_children(
+    grammar: &Value,
+) -> Result<BTreeMap<String, Vec<AnonymousChild>>, serde_json::Error> {
+    let rules: BTreeMap<String, Value> = serde_json::from_value(grammar["rules"].clone())?;
+    let names = |key: &str| -> Result<BTreeSet<String>, serde_json::Error> {
+        Ok(
+            serde_json::from_value::<Option<Vec<Value>>>(grammar[key].clone())?
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|value| value.as_str().map(str::to_string))
+                .collect(),
+        )
+    };
+    let supertypes = names("supertypes")?;
+    let inline = names("inline")?;
+
+    let mut result = BTreeMap::new();
+    for (name, rule) in &rules {
+        if name.starts_with('_') || inline.contains(name) {
+            continue;
+        }
+
+        let mut walk = RuleWalk {
+            rules: &rules,
+            supertypes: &supertypes,
+            inline: &inline,
+            inlining: vec![],
+            counts: HashMap::new(),
+            found: vec![],
+        };
+        walk.walk(rule, false, false);
+
+        let counts = walk.counts;
+        let children: Vec<_> = walk
+            .found
+            .into_iter()
+            .filter(|child| child.types.iter().all(|t| counts[&t.kind] == 1))
+            .collect();
+        if !children.is_empty() {
+            result.insert(name.clone(), children);
+        }
+    }
+    Ok(result)
+}
+
+struct RuleWalk<'a> {
+    rules: &'a BTreeMap<String, Value>,
+    supertypes: &'a BTreeSet<String>,
+    inline: &'a BTreeSet<String>,
+    /// Hidden rules being inlined, to avoid cycles
+    inlining: Vec<String>,
+    /// Number of occurrences of each token in the rule
+    counts: HashMap<String, usize>,
+    found: Vec<AnonymousChild>,
+}
+
+impl RuleWalk<'_> {
+    fn walk(&mut self, rule: &Value, optional: bool, repeated: bool) {
+        match rule["type"].as_str().unwrap_or_default() {
+            // Tokens in fields are matched by field id, and aliases and tokens are nodes of their own
+            "FIELD" | "ALIAS" | "TOKEN" | "IMMEDIATE_TOKEN" => {}
+            "STRING" => {
+                let value = rule["value"].as_str().unwrap_or_default();
+                *self.counts.entry(value.to_string()).or_default() += 1;
+                if optional && !repeated && is_keyword(value) {
+                    self.found.push(AnonymousChild {
+                        kind: AnonymousKind::Keyword,
+                        types: vec![anonymous(value)],
+                        optional: true,
+                    });
+                }
+            }
+            "SYMBOL" => {
+                // Hidden rules are inlined in the parent node, unlike supertypes
+                let name = rule["name"].as_str().unwrap_or_default();
+                let hidden = name.starts_with('_') || self.inline.contains(name);
+                if hidden
+                    && !self.supertypes.contains(name)
+                    && !self.inlining.iter().any(|n| n == name)
+                    && let Some(rule) = self.rules.get(name)
+                {
+                    self.inlining.push(name.to_string());
+                    self.walk(rule, optional, repeated);
+                    self.inlining.pop();
+                }
+            }
+            "CHOICE" => {
+                let members = rule["members"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                let values: Vec<_> = members.iter().filter(|m| m["type"] != "BLANK").collect();
+                let blank = values.len() < members.len();
+
+                if values.len() > 1 && values.iter().all(|m| m["type"] == "STRING") {
+                    let types: Vec<_> = values
+                        .iter()
+                        .map(|m| anonymous(m["value"].as_str().unwrap_or_default()))
+                        .collect();
+                    for t in &types {
+                        *self.counts.entry(t.kind.clone()).or_default() += 1;
+                    }
+                    if !repeated {
+                        self.found.push(AnonymousChild {
+                            kind: AnonymousKind::Operator,
+                            types,
+                            optional: optional || blank,
+                        });
+                    }
+                } else {
+                    // Each alternative may be missing
+                    let optional = optional || values.len() > 1 || blank;
+                    for value in values {
+                        self.walk(value, optional, repeated);
+                    }
+                }
+            }
+            "REPEAT" => self.walk(&rule["content"], true, true),
+            "REPEAT1" => self.walk(&rule["content"], optional, true),
+            _ => {
+                if let Some(members) = rule["members"].as_array() {
+                    for member in members {
+                        self.walk(member, optional, repeated);
+                    }
+                } else if rule.get("content").is_some() {
+                    self.walk(&rule["content"], optional, repeated);
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if a token is a word, like `async` or `from`
+fn is_keyword(value: &str) -> bool {
+    value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn anonymous(kind: &str) -> TypeInfo {
+    TypeInfo {
+        kind: kind.to_string(),
+        named: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optional_keyword() {
+        let grammar = serde_json::json!({
+            "rules": {
+                "function": {"type": "SEQ", "members": [
+                    {"type": "CHOICE", "members": [{"type": "STRING", "value": "async"}, {"type": "BLANK"}]},
+                    {"type": "STRING", "value": "def"},
+                    {"type": "FIELD", "name": "name", "content": {"type": "SYMBOL", "name": "identifier"}},
+                    {"type": "SYMBOL", "name": "_star"},
+                ]},
+                "_star": {"type": "CHOICE", "members": [
+                    {"type": "STRING", "value": "*"},
+                    {"type": "STRING", "value": "**"},
+                ]},
+                "identifier": {"type": "PATTERN", "value": "[a-z]+"},
+            }
+        });
+
+        let children = anonymous_children(&grammar).unwrap();
+        let function = &children["function"];
+        assert_eq!(function.len(), 2);
+
+        // `def` is always there, so only `async` is kept
+        assert!(matches!(function[0].kind, AnonymousKind::Keyword));
+        assert_eq!(function[0].types[0].kind, "async");
+        assert!(function[0].optional);
+
+        // Choices are found through hidden rules
+        assert!(matches!(function[1].kind, AnonymousKind::Operator));
+        assert_eq!(function[1].types.len(), 2);
+        assert!(!function[1].optional);
+
+        assert!(!children.contains_key("_star"));
+        assert!(!children.contains_key("identifier"));
+    }
+
+    #[test]
+    fn ambiguous_tokens() {
+        let keyword = serde_json::json!({"type": "CHOICE", "members": [{"type": "STRING", "value": "async"}, {"type": "BLANK"}]});
+        let grammar = serde_json::json!({
+            "rules": {
+                // Tokens found twice or repeated can not be told apart
+                "twice": {"type": "SEQ", "members": [keyword, keyword]},
+                "repeated": {"type": "REPEAT", "content": keyword},
+            }
+        });
+
+        assert!(anonymous_children(&grammar).unwrap().is_empty());
+    }
+}
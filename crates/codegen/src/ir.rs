@@ -1,6 +1,5 @@
-use crate::{utils::sanitize_string, FIELD_ID_FOR_NAME};
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
 
 pub(crate) enum FieldOrChildren {
     Child(Child),
@@ -8,10 +7,10 @@ pub(crate) enum FieldOrChildren {
 }
 
 impl FieldOrChildren {
-    pub(crate) fn generate_field(&self) -> TokenStream {
+    pub(crate) fn generate_field(&self, core: &TokenStream) -> TokenStream {
         match self {
-            FieldOrChildren::Field(field) => field.generate_field(),
-            FieldOrChildren::Child(child) => child.generate_field(),
+            FieldOrChildren::Field(field) => field.generate_field(core),
+            FieldOrChildren::Child(child) => child.generate_field(core),
         }
     }
 
@@ -29,33 +28,143 @@ impl FieldOrChildren {
         }
     }
 
-    pub(crate) fn generate_field_finalize(&self) -> TokenStream {
+    pub(crate) fn generate_field_finalize(&self, core: &TokenStream) -> TokenStream {
         match self {
-            FieldOrChildren::Field(field) => field.generate_field_finalize(),
-            FieldOrChildren::Child(child) => child.generate_field_finalize(),
+            FieldOrChildren::Field(field) => field.generate_field_finalize(core),
+            FieldOrChildren::Child(child) => child.generate_field_finalize(core),
+        }
+    }
+
+    pub(crate) fn generate_field_serialize(&self, core: &TokenStream) -> TokenStream {
+        match self {
+            FieldOrChildren::Field(field) => field.generate_field_serialize(core),
+            FieldOrChildren::Child(child) => child.generate_field_serialize(core),
+        }
+    }
+
+    /// Returns the `name: "key"` argument of this field in `ast_node!`, `None` for children
+    pub(crate) fn generate_macro_field(&self) -> Option<TokenStream> {
+        match self {
+            FieldOrChildren::Field(field) => {
+                let field_name = &field.ident;
+                let key = &field.tree_sitter_type;
+                Some(quote! { #field_name: #key })
+            }
+            FieldOrChildren::Child(_) => None,
+        }
+    }
+
+    /// Returns the name of this field if it holds children, `None` for fields
+    pub(crate) fn child_ident(&self) -> Option<&Ident> {
+        match self {
+            FieldOrChildren::Field(_) => None,
+            FieldOrChildren::Child(child) => Some(&child.ident),
+        }
+    }
+
+    /// Returns the name of this field if it is lenient, `None` for other fields
+    pub(crate) fn lenient_ident(&self) -> Option<&Ident> {
+        let (kind, field_name) = match self {
+            FieldOrChildren::Field(field) => (&field.kind, &field.ident),
+            FieldOrChildren::Child(child) => (&child.kind, &child.ident),
+        };
+        match kind {
+            Kind::Lenient => Some(field_name),
+            _ => None,
+        }
+    }
+
+    /// Returns the signature and the body of the accessor of this field in the `AstView` of the struct
+    pub(crate) fn generate_view_accessor(&self, core: &TokenStream) -> (TokenStream, TokenStream) {
+        match self {
+            FieldOrChildren::Field(field) => {
+                generate_accessor(core, &field.ident, &field.field_name, &field.kind)
+            }
+            FieldOrChildren::Child(child) => {
+                generate_accessor(core, &child.ident, &child.field_name, &child.kind)
+            }
+        }
+    }
+
+    /// Name of the generated type stored in this field
+    pub(crate) fn field_type(&self) -> &TokenStream {
+        match self {
+            FieldOrChildren::Field(field) => &field.field_name,
+            FieldOrChildren::Child(child) => &child.field_name,
         }
     }
 }
 
+/// Returns the signature and the body of the accessor of a field in the `AstView` of its struct
+fn generate_accessor(
+    core: &TokenStream,
+    field_name: &Ident,
+    field_type: &TokenStream,
+    kind: &Kind,
+) -> (TokenStream, TokenStream) {
+    let view_type = quote! { #core::ast::AstView<'a, #field_type> };
+    let view = quote! { #core::ast::AstView::new(node.cast(self.nodes()), self.nodes()) };
+    match kind {
+        Kind::Base => (
+            quote! { fn #field_name(self) -> #view_type },
+            quote! {
+                let node = &self.node().#field_name;
+                #view
+            },
+        ),
+        Kind::Lenient => (
+            quote! { fn #field_name(self) -> Result<#view_type, #core::errors::MissingField> },
+            quote! {
+                match &self.node().#field_name {
+                    Ok(node) => Ok(#view),
+                    Err(error) => Err(*error),
+                }
+            },
+        ),
+        Kind::Vec => (
+            quote! {
+                fn #field_name(self) -> impl DoubleEndedIterator<Item = #view_type> + ExactSizeIterator + 'a
+            },
+            quote! { self.node().#field_name.iter().map(move |node| #view) },
+        ),
+        Kind::Option => (
+            quote! { fn #field_name(self) -> Option<#view_type> },
+            quote! { self.node().#field_name.as_ref().map(|node| #view) },
+        ),
+    }
+}
+
 pub(crate) enum Kind {
     Base,
+    /// Required field generated with the `lenient_fields` option,
+    /// holding a `MissingField` error if it could not be built
+    Lenient,
     Vec,
     Option,
 }
 pub(crate) struct Field {
     pub(crate) tree_sitter_type: String,
+    /// Rust name of the field
+    pub(crate) ident: Ident,
+    /// Tree-sitter id of the field
+    pub(crate) field_id: u16,
     pub(crate) kind: Kind,
     pub(crate) field_name: TokenStream,
+    /// Tree-sitter kinds allowed in this field
+    pub(crate) expected: Vec<String>,
 }
 
 impl Field {
-    fn generate_field(&self) -> TokenStream {
-        let field_name = format_ident!("{}", &sanitize_string(&self.tree_sitter_type));
+    fn generate_field(&self, core: &TokenStream) -> TokenStream {
+        let field_name = &self.ident;
         let pascal_name = &self.field_name;
         let field_type = match self.kind {
-            Kind::Base => quote! { auto_lsp::core::ast::AstNodeId<#pascal_name> },
-            Kind::Vec => quote! { Vec<auto_lsp::core::ast::AstNodeId<#pascal_name>> },
-            Kind::Option => quote! { Option<auto_lsp::core::ast::AstNodeId<#pascal_name>> },
+            Kind::Base => quote! { #core::ast::AstNodeId<#pascal_name> },
+            Kind::Lenient => quote! {
+                Result<#core::ast::AstNodeId<#pascal_name>, #core::errors::MissingField>
+            },
+            Kind::Vec => quote! { Vec<#core::ast::AstNodeId<#pascal_name>> },
+            Kind::Option => quote! { Option<#core::ast::AstNodeId<#pascal_name>> },
         };
 
         quote! {
@@ -64,19 +173,17 @@ impl Field {
     }
 
     fn generate_field_init(&self) -> TokenStream {
-        let field_name = format_ident!("{}", sanitize_string(&self.tree_sitter_type));
+        let field_name = &self.ident;
         match self.kind {
-            Kind::Base => quote! { let mut #field_name = Ok(None); },
+            Kind::Base | Kind::Lenient => quote! { let mut #field_name = Ok(None); },
             Kind::Vec => quote! { let mut #field_name = vec![]; },
             Kind::Option => quote! { let mut #field_name = Ok(None); },
         }
     }
 
     fn generate_field_collect(&self) -> TokenStream {
-        let field_name = format_ident!("{}", sanitize_string(&self.tree_sitter_type));
-
-        let lock = FIELD_ID_FOR_NAME.lock().unwrap();
-        let kind = lock.get(&self.tree_sitter_type).unwrap();
+        let field_name = &self.ident;
+        let kind = self.field_id;
         let pascal_name = &self.field_name;
 
         match self.kind {
@@ -86,85 +193,133 @@ impl Field {
             Kind::Vec => quote! {
                 on_vec_field_id::<#pascal_name, #kind>(&mut #field_name)
             },
-            Kind::Option => quote! {
-                on_field_id::<#pascal_name, #kind>(&mut #field_name)
+            // Like optional fields, lenient fields are left to `None` if the node fails to build
+            Kind::Option | Kind::Lenient => quote! {
+                on_optional_field_id::<#pascal_name, #kind>(&mut #field_name)
             },
         }
     }
 
-    fn generate_field_finalize(&self) -> TokenStream {
-        let field_name = format_ident!("{}", sanitize_string(&self.tree_sitter_type));
+    fn generate_field_finalize(&self, core: &TokenStream) -> TokenStream {
+        let field_name = &self.ident;
+        let key = &self.tree_sitter_type;
+        let expected = &self.expected;
         match self.kind {
             Kind::Base => quote! {
                  #field_name:  #field_name?.ok_or_else(|| {
-                    auto_lsp::core::errors::AstError::UnexpectedSymbol {
+                    #core::errors::AstError::MissingField {
                         range: node.range(),
-                        symbol: node.kind(),
-                        parent_name: stringify!(#field_name),
+                        parent: node.kind(),
+                        field: #key,
+                        expected: &[#(#expected),*],
                     }
                 })?
             },
+            Kind::Lenient => quote! {
+                #field_name: #field_name?.ok_or(#core::errors::MissingField {
+                    parent: node.kind(),
+                    field: #key,
+                    expected: &[#(#expected),*],
+                })
+            },
             Kind::Vec => quote! {  #field_name },
             Kind::Option => quote! {  #field_name: #field_name? },
         }
     }
+
+    fn generate_field_serialize(&self, core: &TokenStream) -> TokenStream {
+        let field_name = &self.ident;
+        let key = &self.tree_sitter_type;
+        quote! { (#key, #core::ast::NodeField::to_field_value(&self.#field_name)) }
+    }
 }
 
+/// Children that are not in a field, matched by kind
+///
+/// Named children are stored in `children`, anonymous tokens in a `<token>_token` field.
 pub(crate) struct Child {
+    /// Rust name of the field
+    pub(crate) ident: Ident,
+    /// Name of the field in errors and serialized nodes
+    pub(crate) name: String,
     pub(crate) kind: Kind,
     pub(crate) field_name: TokenStream,
+    /// Tree-sitter kinds allowed in the children
+    pub(crate) expected: Vec<String>,
 }
 
 impl Child {
-    fn generate_field(&self) -> TokenStream {
+    fn generate_field(&self, core: &TokenStream) -> TokenStream {
         let pascal_name = &self.field_name;
         let field_type = match self.kind {
-            Kind::Base => quote! { auto_lsp::core::ast::AstNodeId<#pascal_name> },
-            Kind::Vec => quote! { Vec<auto_lsp::core::ast::AstNodeId<#pascal_name>> },
-            Kind::Option => quote! { Option<auto_lsp::core::ast::AstNodeId<#pascal_name>> },
+            Kind::Base => quote! { #core::ast::AstNodeId<#pascal_name> },
+            Kind::Lenient => quote! {
+                Result<#core::ast::AstNodeId<#pascal_name>, #core::errors::MissingField>
+            },
+            Kind::Vec => quote! { Vec<#core::ast::AstNodeId<#pascal_name>> },
+            Kind::Option => quote! { Option<#core::ast::AstNodeId<#pascal_name>> },
         };
 
+        let field_name = &self.ident;
         quote! {
-            pub children: #field_type
+            pub #field_name: #field_type
         }
     }
 
     fn generate_field_init(&self) -> TokenStream {
+        let field_name = &self.ident;
         match self.kind {
-            Kind::Base => quote! { let mut children = Ok(None); },
-            Kind::Vec => quote! { let mut children = vec![]; },
-            Kind::Option => quote! { let mut children = Ok(None); },
+            Kind::Base | Kind::Lenient => quote! { let mut #field_name = Ok(None); },
+            Kind::Vec => quote! { let mut #field_name = vec![]; },
+            Kind::Option => quote! { let mut #field_name = Ok(None); },
         }
     }
 
     fn generate_field_collect(&self) -> TokenStream {
+        let field_name = &self.ident;
         match self.kind {
             Kind::Base => quote! {
-                on_children_id(&mut children)
+                on_children_id(&mut #field_name)
             },
             Kind::Vec => quote! {
-                on_vec_children_id(&mut children)
+                on_vec_children_id(&mut #field_name)
             },
-            Kind::Option => quote! {
-                on_children_id(&mut children)
+            Kind::Option | Kind::Lenient => quote! {
+                on_optional_children_id(&mut #field_name)
             },
         }
     }
 
-    fn generate_field_finalize(&self) -> TokenStream {
-        let name = &self.field_name;
+    fn generate_field_finalize(&self, core: &TokenStream) -> TokenStream {
+        let field_name = &self.ident;
+        let key = &self.name;
+        let expected = &self.expected;
         match self.kind {
             Kind::Base => quote! {
-                children: children?.ok_or_else(|| {
-                    auto_lsp::core::errors::AstError::UnexpectedSymbol {
+                #field_name: #field_name?.ok_or_else(|| {
+                    #core::errors::AstError::MissingField {
                         range: node.range(),
-                        symbol: node.kind(),
-                        parent_name: stringify!(#name),
+                        parent: node.kind(),
+                        field: #key,
+                        expected: &[#(#expected),*],
                     }
                 })?
             },
-            Kind::Vec => quote! { children },
-            Kind::Option => quote! { children: children? },
+            Kind::Lenient => quote! {
+                #field_name: #field_name?.ok_or(#core::errors::MissingField {
+                    parent: node.kind(),
+                    field: #key,
+                    expected: &[#(#expected),*],
+                })
+            },
+            Kind::Vec => quote! { #field_name },
+            Kind::Option => quote! { #field_name: #field_name? },
         }
     }
+
+    fn generate_field_serialize(&self, core: &TokenStream) -> TokenStream {
+        let field_name = &self.ident;
+        let key = &self.name;
+        quote! { (#key, #core::ast::NodeField::to_field_value(&self.#field_name)) }
+    }
 }
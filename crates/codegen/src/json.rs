@@ -1,6 +1,7 @@
 use crate::ir::{Child, Field, FieldOrChildren, Kind};
-use crate::utils::sanitize_string_to_pascal;
-use crate::{ANONYMOUS_TYPES, INLINE_MULTIPLE_RULES, NAMED_RULES, OPERATORS_RULES, OperatorList};
+use crate::utils::{sanitize_string, sanitize_string_to_pascal};
+use crate::{CodegenError, Generator, OperatorList};
+use proc_macro2::Ident;
 use quote::{format_ident, quote};
 use serde::Deserialize;
 use std::collections::BTreeMap;
@@ -48,69 +49,41 @@ pub(crate) struct FieldInfo {
 }
 
 impl FieldInfo {
-    fn field_gen_type(&self) -> Kind {
+    fn field_gen_type(&self, lenient_fields: bool) -> Kind {
         let optional = self.required.unwrap_or(false);
         let multiple = self.multiple.unwrap_or(false);
         match (optional, multiple) {
             (_, true) => Kind::Vec,
+            (true, false) if lenient_fields => Kind::Lenient,
             (true, false) => Kind::Base,
             (false, false) => Kind::Option,
         }
     }
 
-    pub(crate) fn field_code_gen(&self, field_name: &str) -> FieldOrChildren {
-        // If there's only one type, we can use it directly
-        let base_type = if self.types.len() == 1 {
-            if !NAMED_RULES
-                .lock()
-                .unwrap()
-                .contains(&sanitize_string_to_pascal(&self.types[0].kind))
-            {
-                ANONYMOUS_TYPES
-                    .lock()
-                    .unwrap()
-                    .insert(self.types[0].kind.clone());
-            }
-            format_ident!("{}", sanitize_string_to_pascal(&self.types[0].kind))
-
-        // If all types are unnamed, we generate an operator list
-        } else if self.types.iter().all(|t| !t.named) {
-            let mut lock = OPERATORS_RULES.lock().unwrap();
-
-            let rule: String = self.types.iter().map(|n| n.kind.clone()).collect();
-
-            let len = lock.len();
-            let op = lock.entry(rule.clone()).or_insert(OperatorList {
-                index: len,
-                operators: self.types.to_vec(),
-            });
-
-            format_ident!("Operators_{}", op.index)
-        // Types are mixed, so we generate an enum
-        } else {
-            let list: String = self
-                .types
-                .iter()
-                .map(|t| sanitize_string_to_pascal(&t.kind).to_string())
-                .collect::<Vec<_>>()
-                .join("_");
-
-            let variants = self.types.to_vec();
-
-            INLINE_MULTIPLE_RULES
-                .lock()
-                .unwrap()
-                .entry(list.clone())
-                .or_insert(variants.clone());
-
-            format_ident!("{}", list)
+    pub(crate) fn field_code_gen(
+        &self,
+        generator: &mut Generator,
+        field_name: &str,
+    ) -> Result<Option<FieldOrChildren>, CodegenError> {
+        // Fields whose types are all skipped are dropped
+        let Some(base_type) = base_type(generator, &self.types) else {
+            return Ok(None);
         };
+        let types = generator.kept_types(&self.types);
 
-        FieldOrChildren::Field(Field {
-            kind: self.field_gen_type(),
+        let field_id = *generator
+            .field_id_for_name
+            .get(field_name)
+            .ok_or_else(|| CodegenError::UnknownField(field_name.to_string()))?;
+
+        Ok(Some(FieldOrChildren::Field(Field {
+            kind: self.field_gen_type(generator.options.lenient_fields),
             tree_sitter_type: field_name.to_string(),
+            ident: format_ident!("{}", sanitize_string(field_name, &generator.tokens)),
+            field_id,
             field_name: quote! { #base_type },
-        })
+            expected: types.iter().map(|t| t.kind.clone()).collect(),
+        })))
     }
 }
 
@@ -123,68 +96,29 @@ pub(crate) struct ChildInfo {
 }
 
 impl ChildInfo {
-    fn child_gen_type(&self) -> Kind {
+    fn child_gen_type(&self, lenient_fields: bool) -> Kind {
         let optional = self.required.unwrap_or(false);
         let multiple = self.multiple.unwrap_or(false);
         match (optional, multiple) {
             (_, true) => Kind::Vec,
+            (true, false) if lenient_fields => Kind::Lenient,
             (true, false) => Kind::Base,
             (false, false) => Kind::Option,
         }
     }
 
-    pub(crate) fn child_code_gen(&self) -> FieldOrChildren {
-        // If there's only one type, we can use it directly
-        let base_type = if self.types.len() == 1 {
-            if !NAMED_RULES
-                .lock()
-                .unwrap()
-                .contains(&sanitize_string_to_pascal(&self.types[0].kind))
-            {
-                ANONYMOUS_TYPES
-                    .lock()
-                    .unwrap()
-                    .insert(self.types[0].kind.clone());
-            }
-            format_ident!("{}", sanitize_string_to_pascal(&self.types[0].kind))
-
-        // If all types are unnamed, we generate an operator list
-        } else if self.types.iter().all(|t| !t.named) {
-            let mut lock = OPERATORS_RULES.lock().unwrap();
-
-            let rule: String = self.types.iter().map(|n| n.kind.clone()).collect();
-
-            let len = lock.len();
-            let op = lock.entry(rule.clone()).or_insert(OperatorList {
-                index: len,
-                operators: self.types.to_vec(),
-            });
-
-            format_ident!("Operators_{}", op.index)
-        // Types are mixed, so we generate an enum
-        } else {
-            let list: String = self
-                .types
-                .iter()
-                .map(|t| sanitize_string_to_pascal(&t.kind).to_string())
-                .collect::<Vec<_>>()
-                .join("_");
-
-            let variants = self.types.to_vec();
-
-            INLINE_MULTIPLE_RULES
-                .lock()
-                .unwrap()
-                .entry(list.clone())
-                .or_insert(variants.clone());
-
-            format_ident!("{}", list)
-        };
+    pub(crate) fn child_code_gen(&self, generator: &mut Generator) -> Option<FieldOrChildren> {
+        // Children whose types are all skipped are dropped
+        let base_type = base_type(generator, &self.types)?;
+        let types = generator.kept_types(&self.types);
 
-        FieldOrChildren::Child(Child {
-            kind: self.child_gen_type(),
+        Some(FieldOrChildren::Child(Child {
+            ident: format_ident!("children"),
+            name: "children".to_string(),
+            kind: self.child_gen_type(generator.options.lenient_fields),
             field_name: quote! { #base_type },
-        })
+            expected: types.iter().map(|t| t.kind.clone()).collect(),
+        }))
     }
 }
 
@@ -195,3 +129,64 @@ pub(crate) struct TypeInfo {
     pub(crate) kind: String,
     pub(crate) named: bool,
 }
+
+impl Generator {
+    /// Returns the types that are not skipped by the config
+    pub(crate) fn kept_types(&self, types: &[TypeInfo]) -> Vec<TypeInfo> {
+        types
+            .iter()
+            .filter(|t| !self.is_skipped(&t.kind))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Returns the type of a field or children, `None` if all its types are skipped
+///
+/// A field with several types keeps an enum even if only one of them is left,
+/// so that the kind of the node is still checked.
+pub(crate) fn base_type(generator: &mut Generator, all_types: &[TypeInfo]) -> Option<Ident> {
+    let types = generator.kept_types(all_types);
+    if types.is_empty() {
+        return None;
+    }
+
+    // If there's only one type, we can use it directly
+    let type_name = if all_types.len() == 1 {
+        let type_name = sanitize_string_to_pascal(&types[0].kind, &generator.tokens);
+        if !generator.named_rules.contains(&type_name) {
+            generator.anonymous_types.insert(types[0].kind.clone());
+        }
+        type_name
+
+    // If all types are unnamed, we generate an operator list
+    } else if types.iter().all(|t| !t.named) {
+        let operators_rules = &mut generator.operators_rules;
+
+        let rule: String = types.iter().map(|n| n.kind.clone()).collect();
+
+        let len = operators_rules.len();
+        let op = operators_rules.entry(rule).or_insert(OperatorList {
+            index: len,
+            operators: types,
+        });
+
+        format!("Operators_{}", op.index)
+    // Types are mixed, so we generate an enum
+    } else {
+        let list: String = types
+            .iter()
+            .map(|t| sanitize_string_to_pascal(&t.kind, &generator.tokens).to_string())
+            .collect::<Vec<_>>()
+            .join("_");
+
+        generator
+            .inline_multiple_rules
+            .entry(list.clone())
+            .or_insert(types);
+
+        list
+    };
+
+    Some(generator.type_ident(&type_name))
+}
@@ -0,0 +1,84 @@
+use crate::Generator;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+impl Generator {
+    /// Registers a struct in the `NodeKind` enum, returning its discriminant
+    ///
+    /// `0` is reserved for error nodes, see `ErrorNode::KIND_ID` in the core crate.
+    pub(crate) fn register_node_kind(&mut self, struct_name: &Ident, struct_type: &str) -> u16 {
+        self.node_kinds
+            .push((struct_name.to_string(), struct_type.to_string()));
+        self.node_kinds.len() as u16
+    }
+
+    /// Generates the `NodeKind` enum from all registered structs
+    pub(crate) fn generate_node_kind(&mut self) -> TokenStream {
+        let kinds = std::mem::take(&mut self.node_kinds);
+        let core = &self.config_tokens.crate_path;
+        let visibility = &self.config_tokens.visibility;
+        let variants: Vec<_> = kinds
+            .iter()
+            .map(|(struct_name, _)| format_ident!("{}", struct_name))
+            .collect();
+        let discriminants = (1..=kinds.len() as u16).collect::<Vec<_>>();
+        let kind_names = kinds.iter().map(|(_, struct_type)| struct_type);
+        let count = kinds.len() + 1;
+
+        quote! {
+            /// Kind of a node, with a variant for each generated struct.
+            ///
+            /// Enums share the kind of the struct they wrap, and `ErrorNode` stands for the nodes that could not be built.
+            ///
+            /// Matching on a `NodeKind` is exhaustive, and its discriminant can index tables of `NodeKind::COUNT` entries.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+            #[repr(u16)]
+            #visibility enum NodeKind {
+                ErrorNode = #core::ast::ErrorNode::KIND_ID,
+                #(#variants = #discriminants),*
+            }
+
+            impl NodeKind {
+                /// Number of kinds, error nodes included
+                pub const COUNT: usize = #count;
+
+                /// Every kind, indexed by discriminant
+                pub const ALL: [NodeKind; #count] = [NodeKind::ErrorNode, #(NodeKind::#variants),*];
+
+                /// Returns the kind of the given node.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the node was not generated along with this enum.
+                pub fn of(node: &dyn #core::ast::AstNode) -> Self {
+                    match Self::try_from(node.get_kind_id()) {
+                        Ok(kind) => kind,
+                        Err(id) => panic!("Unknown node kind {id}"),
+                    }
+                }
+
+                /// Returns the tree-sitter kind of the nodes of this kind, `ERROR` for error nodes.
+                pub fn name(self) -> &'static str {
+                    match self {
+                        NodeKind::ErrorNode => #core::ast::ErrorNode::KIND_NAME,
+                        #(NodeKind::#variants => #kind_names),*
+                    }
+                }
+            }
+
+            impl TryFrom<u16> for NodeKind {
+                type Error = u16;
+
+                fn try_from(id: u16) -> Result<Self, u16> {
+                    Self::ALL.get(id as usize).copied().ok_or(id)
+                }
+            }
+
+            impl From<NodeKind> for u16 {
+                fn from(kind: NodeKind) -> u16 {
+                    kind as u16
+                }
+            }
+        }
+    }
+}
@@ -15,7 +15,7 @@
 //! - A [`node-types.json`](https://tree-sitter.github.io/tree-sitter/using-parsers/6-static-node-types.html),
 //! - A [`LanguageFn`](https://docs.rs/tree-sitter-language/0.1.5/tree_sitter_language/struct.LanguageFn.html)
 //! - A `HashMap<&str, &str>` to rename tokens (see [Custom Tokens](#custom-tokens))
-//! - And returns a **TokenStream**.
+//! - And returns a **TokenStream**, or a `CodegenError` if the node types do not match the language.
 //!
 //! How you choose to use the `TokenStream` is up to you.
 //!
@@ -41,6 +41,7 @@
 //!            &tree_sitter_python::LANGUAGE.into(),
 //!            None,
 //!        )
+//!        .unwrap()
 //!        .to_string(),
 //!    )
 //!    .unwrap();
@@ -49,10 +50,135 @@
 //!
 //! You can also invoke it from your own CLI or tool if needed.
 //!
+//! ## Multiple Grammars
+//!
+//! `generate` creates a new [`Generator`] on each call, which owns all the state collected from a grammar.
+//! Several grammars can be generated from the same build script:
+//!
+//! ```rust, ignore
+//! use auto_lsp_codegen::Generator;
+//!
+//! let python = Generator::new(&tree_sitter_python::LANGUAGE.into())
+//!     .generate(tree_sitter_python::NODE_TYPES)?;
+//! let javascript = Generator::new(&tree_sitter_javascript::LANGUAGE.into())
+//!     .with_tokens(HashMap::from([("`", "Backtick")]))
+//!     .generate(tree_sitter_javascript::NODE_TYPES)?;
+//! ```
+//!
+//! ## Configuration
+//!
+//! A [`CodegenConfig`] passed to [`Generator::with_config`] customizes the generated code:
+//!
+//! ```rust, ignore
+//! use auto_lsp_codegen::{CodegenConfig, Generator};
+//!
+//! let python = Generator::new(&tree_sitter_python::LANGUAGE.into())
+//!     .with_config(
+//!         CodegenConfig::new()
+//!             // Renames a type given its default name
+//!             .rename("CompoundStatement_SimpleStatement", "Statement")
+//!             // Skips a rule given its tree-sitter kind
+//!             .skip_rule("print_statement")
+//!             // Added to every struct and enum
+//!             .derive("Hash")
+//!             .attribute("#[non_exhaustive]")
+//!             // `pub` by default
+//!             .visibility("pub(crate)")
+//!             // `auto_lsp::core` by default
+//!             .crate_path("auto_lsp_core"),
+//!     )
+//!     .generate(tree_sitter_python::NODE_TYPES)?;
+//! ```
+//!
+//! Skipped rules are removed from the enums and fields they appear in, and fields left without types are removed as well.
+//! A skipped node is ignored among the children of a node, but reported as an unexpected child when found in a field that has other types.
+//!
+//! Setting the crate path to `auto_lsp_core` lets the generated code depend on the core crate only.
+//!
+//! ## Command Line
+//!
+//! With the `cli` feature, the crate provides an `auto-lsp-codegen` binary as an alternative to a build script.
+//!
+//! It takes a `node-types.json` (with an optional `grammar.json`), or the directory of a tree-sitter grammar,
+//! along with the grammar compiled as a shared library, and writes the generated code formatted with `prettyplease`:
+//!
+//! ```sh
+//! cargo install auto-lsp-codegen --features cli
+//! auto-lsp-codegen --grammar-dir tree-sitter-python --language libtree-sitter-python.so -o src/generated.rs
+//! ```
+//!
+//! The generated file starts with a stamp hashing the inputs, the language, the version of the generator and the generated code.
+//! The file is only regenerated when the stamp changes, and `--check` fails instead of regenerating it, to detect a stale `generated.rs` in CI.
+//!
+//! Pass `--serde` to implement `serde::Serialize` on the generated types (see [Serde](#serde)).
+//!
+//! The tokens and the config of the generator are given as flags, repeated for each token, rename, skipped rule, derive and attribute, and are part of the stamp:
+//!
+//! ```sh
+//! auto-lsp-codegen --grammar-dir tree-sitter-python --language libtree-sitter-python.so -o src/generated.rs \
+//!     --token '->=Arrow' \
+//!     --rename CompoundStatement_SimpleStatement=Statement \
+//!     --skip-rule print_statement \
+//!     --derive Hash \
+//!     --attribute '#[non_exhaustive]' \
+//!     --visibility 'pub(crate)' \
+//!     --crate-path auto_lsp_core
+//! ```
+//!
+//! ## Proc Macro
+//!
+//! With the `macros` feature, the `auto_lsp::generate_ast!` macro generates the AST in place, without a build script or a checked-in `generated.rs`,
+//! which suits small grammars such as JSON or configuration languages:
+//!
+//! ```rust, ignore
+//! mod ast {
+//!     auto_lsp::generate_ast!(
+//!         // Relative to the manifest of the crate
+//!         node_types: "tree-sitter-json/src/node-types.json",
+//!         language: tree_sitter_json::LANGUAGE,
+//!         lenient_fields: true,
+//!         derives: [Eq, Hash],
+//!     );
+//! }
+//! ```
+//!
+//! It takes the same options and config as the [`Generator`], and re-exports the language as `LANGUAGE`.
+//! Since the language can not be loaded while the macro expands, ids are read from the `parser.c` next to `node-types.json`.
+//! Both files are written by `tree-sitter generate` in the `src` directory of the grammar, which the crate has to contain,
+//! as the sources of a grammar crate can not be found reliably while the macro expands.
+//! The crate is rebuilt when one of the files read by the macro changes.
+//!
 //! ## How Codegen Works
 //!
 //! The generated code structure depends on the Tree-sitter grammar.
 //!
+//! ## Modules
+//!
+//! The generated items are split into the `structs`, `enums`, `supertypes`, `tokens` and `operators` modules,
+//! all re-exported by the generated file, so types are available as `generated::FunctionDefinition` as well as `generated::structs::FunctionDefinition`.
+//!
+//! [`Generator::generate`], [`generate`] and the proc macro return a single token stream, so the modules are inline in `generated.rs`.
+//! [`Generator::generate_files`] returns the content of each module separately, and `--split` writes them to their own files in a directory named after the generated file:
+//!
+//! ```sh
+//! auto-lsp-codegen --grammar-dir tree-sitter-python --language libtree-sitter-python.so -o src/generated.rs --split
+//! # src/generated.rs declares `pub mod structs;` and so on, written to src/generated/structs.rs
+//! ```
+//!
+//! Splitting the modules keeps each file small enough for editors, but the crate is still compiled as a whole.
+//!
+//! The implementations shared by all types are generated once as `macro_rules!`, and each type only invokes them with what is specific to it:
+//!
+//! ```rust, ignore
+//! ast_node!(
+//!     FunctionDefinition, "function_definition", 47u16, true, [146u16],
+//!     fields: [body: "body", name: "name", parameters: "parameters", return_type: "return_type", type_parameters: "type_parameters"],
+//!     children: [async_token, errors],
+//!     lenient: []
+//! );
+//! ast_leaf!(PassStatement, "pass_statement", 80u16, true, [128u16]);
+//! ```
+//!
 //! ## Structs for Rules
 //!
 //! Each rule in `node-types.json` becomes a dedicated Rust struct. For example, given the rule:
@@ -139,12 +265,60 @@
 //!
 //! The `AstNode::contains` method relies on this to check whether a node kind belongs to a specific struct or enum variant.
 //!
+//! A kind aliased in the grammar can have several ids, so `contains` matches all the ids of the kind, aliases included.
+//!
+//! ## Anonymous Tokens
+//!
+//! `node-types.json` only lists the anonymous tokens that are in a field, so a keyword like `optional('async')` is lost.
+//!
+//! When the `grammar.json` of the language is given to [`Generator::with_grammar`], anonymous tokens that are not in a field are captured as well:
+//!
+//! - Optional keywords become a `<keyword>_token: Option<AstNodeId<Token_Keyword>>` field
+//! - Choices of tokens become an `operator_token` field of an `Operators_N` enum, optional if the choice is
+//!
+//! ```rust, ignore
+//! let python = Generator::new(&tree_sitter_python::LANGUAGE.into())
+//!     .with_grammar(include_str!("./grammar.json"))
+//!     .generate(tree_sitter_python::NODE_TYPES)?;
+//! ```
+//!
+//! ```rust, ignore
+//! pub struct FunctionDefinition {
+//!     /* ... */
+//!     pub async_token: Option<AstNodeId<Token_Async>>,
+//! }
+//! ```
+//!
+//! Tokens that appear more than once in a rule, or in a repetition, are ignored since they can not be told apart.
+//!
 //! ## Vec and Option Fields
 //!
 //! `repeat` and `repeat1` in the grammar will generate a `Vec` field.
 //!
 //! `optional(...)` will generate an `Option<T>` field.
 //!
+//! ## Lenient Fields
+//!
+//! By default, a struct is only built if all its required fields are, otherwise `try_from` returns an `AstError`
+//! and the node is dropped, even if tree-sitter recovered from the error.
+//!
+//! With the `lenient_fields` option of [`generate_with_options`], required fields and children are generated as
+//! `Result<AstNodeId<T>, MissingField>`, so the struct is still built while the user is typing:
+//!
+//! ```rust, ignore
+//!pub struct Pair {
+//!    pub key: Result<AstNodeId<String>, MissingField>,
+//!    pub value: Result<AstNodeId<Value>, MissingField>,
+//!    /* ... */
+//!}
+//! ```
+//!
+//! `AstNode::has_all_required_fields` returns `false` if one of them is missing.
+//!
+//! A required child that fails to build is then replaced by an `ErrorNode`, like the children of optional and vector fields.
+//! Without this option, required fields can't point to an `ErrorNode`, so the failure is propagated to the closest
+//! optional or vector field, where the whole failed node becomes an `ErrorNode`.
+//!
 //! ## Token Naming
 //!
 //! Unnamed tokens are mapped to Rust enums using a built-in token map. For instance:
@@ -231,216 +405,560 @@
 //! ```
 //!
 //! Some super types might contain other super types, in which case, the generated enum will flatten the hierarchy.
+//!
+//! ## Enum Helpers
+//!
+//! Every enum has an `is_<variant>()` and an `as_<variant>() -> Option<&T>` method for each of its variants,
+//! including the variants of the enums it contains:
+//!
+//! ```rust, ignore
+//! // Instead of matching Expression::PrimaryExpression(PrimaryExpression::Integer(integer))
+//! if let Some(integer) = expression.as_integer() {
+//!     /* ... */
+//! }
+//! ```
+//!
+//! ## Visitor
+//!
+//! A `Visitor` trait is generated with a `visit_<node>` method for each type, along with a `walk_<node>` function
+//! that visits the children of a node in source order.
+//!
+//! ```rust, ignore
+//! pub trait Visitor {
+//!    fn enter(&mut self, nodes: &AstArena, node: &dyn AstNode) -> ControlFlow<()>;
+//!    fn leave(&mut self, nodes: &AstArena, node: &dyn AstNode);
+//!    fn visit_function_definition(&mut self, nodes: &AstArena, node: &FunctionDefinition) {
+//!        walk_function_definition(self, nodes, node)
+//!    }
+//!    /* ... */
+//! }
+//! ```
+//!
+//! `enter` and `leave` are called around every struct, returning `ControlFlow::Break` from `enter` skips the subtree.
+//!
+//! ## Views
+//!
+//! Every type has a `view` method returning an `auto_lsp::core::ast::AstView<'a, T>`, bundling a node with the AST it belongs to.
+//! Views of structs have an accessor for each field, returning `AstView<T>`, `Option<AstView<T>>` or `impl Iterator<Item = AstView<T>>`.
+//!
+//! The accessors of a struct are generated as a `<Struct>View` trait, which must be in scope:
+//!
+//! ```rust, ignore
+//! use generated::{FunctionDefinitionView, ParametersView};
+//!
+//! let parameters = function.view(ast).parameters().children();
+//! ```
+//!
+//! ## Node Kinds
+//!
+//! A `#[repr(u16)] enum NodeKind` is generated with a variant for each struct, and an `ErrorNode` variant for error nodes.
+//!
+//! `AstNode::get_kind_id` returns the discriminant of the kind of a node, enums returning the kind of their variant:
+//!
+//! ```rust, ignore
+//! match NodeKind::of(node) {
+//!    NodeKind::FunctionDefinition => { /* ... */ }
+//!    NodeKind::ClassDefinition => { /* ... */ }
+//!    _ => {}
+//! }
+//! ```
+//!
+//! ## Language Verification
+//!
+//! Node kind and field ids are hardcoded in the generated code, so an AST only works with the version of the grammar it was generated from.
+//!
+//! A `verify_language` function is generated to compare these ids with the ones of a `tree_sitter::Language`,
+//! returning the list of `Mismatch` found. It is also available as `AstNode::verify_language` on every generated type,
+//! which `configure_parser!` calls on the AST root in debug builds:
+//!
+//! ```rust, ignore
+//! verify_language(&tree_sitter_python::LANGUAGE.into()).expect("The AST is out of date");
+//! ```
+//!
+//! ## Serde
+//!
+//! With the `serde` feature of this crate, or the `serde` option, every struct and enum implements `serde::Serialize`,
+//! which requires the `serde` feature of `auto_lsp`.
+//!
+//! The feature only changes the default of [`GenerateOptions::serde`]: Cargo enables a feature for every build script
+//! of a workspace as soon as one of them does, so grammars built without the `serde` feature of `auto_lsp`
+//! should disable the option explicitly.
+//!
+//! A node is serialized on its own as `{id, kind, span, fields}`, where each field holds the IDs of the nodes it points to,
+//! and enums are serialized as their variant:
+//!
+//! ```rust, ignore
+//! let json = serde_json::to_string(ast.get::<FunctionDefinition>(id).unwrap())?;
+//! ```
+//!
+//! To resolve the fields to the nodes they point to, serialize the node along with its AST through `ParsedAst::to_json`,
+//! `auto_lsp::core::ast::to_json` or the `auto_lsp::core::ast::Serializable` wrapper.
+//! Nodes are then serialized as `{kind, range, text, fields}`:
+//!
+//! ```rust, ignore
+//! let json = get_ast(db, file).to_json(file.document(db))?;
+//! ```
 
+mod config;
+mod errors;
+mod grammar;
 mod ir;
 mod json;
+mod kind;
+mod macros;
+mod modules;
 mod output;
+mod serialize;
 mod supertypes;
+mod symbols;
 mod tests;
 mod utils;
+mod variant;
+mod verify;
+mod view;
+mod visitor;
 
+pub use crate::config::CodegenConfig;
+use crate::config::ConfigTokens;
+pub use crate::errors::CodegenError;
+use crate::grammar::{AnonymousChild, anonymous_children};
 use crate::json::{NodeType, TypeInfo};
-use crate::output::{generate_enum, generate_struct};
+use crate::modules::Modules;
 use crate::supertypes::{SuperType, generate_super_type};
+pub use crate::symbols::LanguageSymbols;
 use crate::utils::{sanitize_string, sanitize_string_to_pascal};
-use proc_macro2::TokenStream;
-use quote::{ToTokens, format_ident, quote};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
 use std::collections::{BTreeMap, BTreeSet, HashMap};
-use std::sync::{LazyLock, Mutex, RwLock};
 use utils::TOKENS;
 
-/// List of all named rules (nodes with `named: true`)
-pub(crate) static NAMED_RULES: LazyLock<Mutex<Vec<String>>> = LazyLock::new(Default::default);
-
 /// List of fields/children that are only composed of operators
 pub(crate) struct OperatorList {
     index: usize,
     operators: Vec<TypeInfo>,
 }
 
-pub(crate) static OPERATORS_RULES: LazyLock<Mutex<BTreeMap<String, OperatorList>>> =
-    LazyLock::new(Default::default);
-
-/// List of fields/children that are composed of multiple rules
-pub(crate) static INLINE_MULTIPLE_RULES: LazyLock<Mutex<BTreeMap<String, Vec<TypeInfo>>>> =
-    LazyLock::new(Default::default);
-
-/// List of anonymous rules (usually aliases created on the fly)
-pub(crate) static ANONYMOUS_TYPES: LazyLock<Mutex<BTreeSet<String>>> =
-    LazyLock::new(Default::default);
-
-/// Map of node kind to  named node id
-pub(crate) static NODE_ID_FOR_NAMED_NODE: LazyLock<Mutex<BTreeMap<String, u16>>> =
-    LazyLock::new(Default::default);
+/// Options of [`generate_with_options`]
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    /// Generates required fields and children as `Result<AstNodeId<T>, MissingField>`,
+    /// so a node is still built when one of them is missing (see [Lenient Fields](crate#lenient-fields)).
+    pub lenient_fields: bool,
+    /// Implements `serde::Serialize` on the generated types, which requires the `serde` feature of `auto_lsp`
+    /// (see [Serde](crate#serde)).
+    ///
+    /// Enabled by default with the `serde` feature of this crate.
+    pub serde: bool,
+}
 
-/// Map of node kind to unnamed node id
-pub(crate) static NODE_ID_FOR_UNNAMED_NODE: LazyLock<Mutex<BTreeMap<String, u16>>> =
-    LazyLock::new(Default::default);
+// Only derivable without the `serde` feature
+#[allow(clippy::derivable_impls)]
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            lenient_fields: false,
+            serde: cfg!(feature = "serde"),
+        }
+    }
+}
 
-/// Map of field name to field id
-pub(crate) static FIELD_ID_FOR_NAME: LazyLock<Mutex<BTreeMap<String, u16>>> =
-    LazyLock::new(Default::default);
+/// Code generated by [`Generator::generate_files`], with each module in its own file.
+///
+/// The root file declares the modules as `mod structs;`, so the module files are written
+/// in a directory named after the root file, such as `src/generated/structs.rs` for `src/generated.rs`.
+pub struct GeneratedFiles {
+    /// Content of the root file
+    pub root: TokenStream,
+    /// Name and content of each module file
+    pub modules: Vec<(&'static str, TokenStream)>,
+}
 
-/// List of super types
-pub(crate) static SUPER_TYPES: LazyLock<RwLock<BTreeMap<String, SuperType>>> =
-    LazyLock::new(Default::default);
+impl GeneratedFiles {
+    /// Names of the modules, in the order of [`GeneratedFiles::modules`]
+    pub const MODULES: [&'static str; 5] =
+        ["structs", "enums", "supertypes", "tokens", "operators"];
+}
 
-/// Generates the Rust code for a given Tree-sitter grammar
+/// Generates the Rust code of a tree-sitter grammar.
 ///
-/// # Arguments
-///
-/// * `source` - node-types.json
-/// * `language` - tree-sitter language fn
-/// * `tokens` - optional map of tokens to enum names (since tokens can't be valid rust identifiers)
-///
-/// # Returns
-/// A TokenStream containing the generated code
+/// A generator owns all the state collected while generating a grammar,
+/// and is consumed by [`Generator::generate`], so several grammars can be generated in the same process.
 ///
 /// # Example
 ///
 /// ```rust
-/// use auto_lsp_codegen::generate;
+/// use auto_lsp_codegen::Generator;
 ///
-/// let _result = generate(
-///        &tree_sitter_python::NODE_TYPES,
-///        &tree_sitter_python::LANGUAGE.into(),
-///        None,
-///    );
+/// let python = Generator::new(&tree_sitter_python::LANGUAGE.into())
+///     .generate(tree_sitter_python::NODE_TYPES)
+///     .unwrap();
+/// let html = Generator::new(&tree_sitter_html::LANGUAGE.into())
+///     .generate(tree_sitter_html::NODE_TYPES)
+///     .unwrap();
 /// ```
-///
-pub fn generate(
-    source: &str,
-    language: &tree_sitter::Language,
-    tokens: Option<HashMap<&'static str, &'static str>>,
-) -> TokenStream {
-    if let Some(tokens) = tokens {
-        // extend or overwrite the default tokens
+pub struct Generator {
+    symbols: LanguageSymbols,
+    options: GenerateOptions,
+    config: CodegenConfig,
+    /// Parsed derives, attributes, visibility and crate path of the config
+    config_tokens: ConfigTokens,
+    /// grammar.json of the language, if any
+    grammar: Option<String>,
+    /// Map of rules to their anonymous children found in the grammar
+    anonymous_children: BTreeMap<String, Vec<AnonymousChild>>,
+    /// Map of tokens to type names, the default tokens extended by the user ones
+    tokens: HashMap<String, String>,
+    /// List of all named rules (nodes with `named: true`)
+    named_rules: BTreeSet<String>,
+    /// List of fields/children that are only composed of operators
+    operators_rules: BTreeMap<String, OperatorList>,
+    /// List of fields/children that are composed of multiple rules
+    inline_multiple_rules: BTreeMap<String, Vec<TypeInfo>>,
+    /// List of anonymous rules (usually aliases created on the fly)
+    anonymous_types: BTreeSet<String>,
+    /// Map of node kind to named node ids, starting with the public id
+    node_id_for_named_node: BTreeMap<String, Vec<u16>>,
+    /// Map of node kind to unnamed node ids, starting with the public id
+    node_id_for_unnamed_node: BTreeMap<String, Vec<u16>>,
+    /// Map of field name to field id
+    field_id_for_name: BTreeMap<String, u16>,
+    /// List of super types
+    super_types: BTreeMap<String, SuperType>,
+    /// Map of enums to their variants, to generate `is_<variant>` and `as_<variant>` methods
+    enum_variants: BTreeMap<String, Vec<String>>,
+    /// List of types that have a `visit_<node>` method in the `Visitor` trait
+    visitor_types: Vec<String>,
+    /// List of structs, with their tree-sitter kind, that have a variant in the `NodeKind` enum
+    node_kinds: Vec<(String, String)>,
+}
 
-        let mut lock = TOKENS.write().unwrap();
-        for (k, v) in tokens {
-            lock.insert(k, v);
+impl Generator {
+    pub fn new(language: &tree_sitter::Language) -> Self {
+        Self::from_symbols(language.into())
+    }
+
+    /// Creates a generator from the symbols of a language, such as the ones read from its `parser.c`
+    pub fn from_symbols(symbols: LanguageSymbols) -> Self {
+        Self {
+            symbols,
+            options: GenerateOptions::default(),
+            config: CodegenConfig::default(),
+            config_tokens: ConfigTokens::default(),
+            grammar: None,
+            anonymous_children: BTreeMap::new(),
+            tokens: TOKENS.clone(),
+            named_rules: BTreeSet::new(),
+            operators_rules: BTreeMap::new(),
+            inline_multiple_rules: BTreeMap::new(),
+            anonymous_types: BTreeSet::new(),
+            node_id_for_named_node: BTreeMap::new(),
+            node_id_for_unnamed_node: BTreeMap::new(),
+            field_id_for_name: BTreeMap::new(),
+            super_types: BTreeMap::new(),
+            enum_variants: BTreeMap::new(),
+            visitor_types: vec![],
+            node_kinds: vec![],
         }
     }
 
-    let nodes: Vec<NodeType> = serde_json::from_str(source).expect("Invalid JSON");
-
-    let mut output = quote! {
-        // Auto-generated file. Do not edit manually.
-        #![allow(clippy::all)]
-        #![allow(unused)]
-        #![allow(dead_code)]
-        #![allow(non_camel_case_types)]
-        #![allow(non_snake_case)]
-
-    };
-    for node in &nodes {
-        if node.named {
-            // Push the node kind to the list of named rules
-            NAMED_RULES
-                .lock()
-                .unwrap()
-                .push(sanitize_string_to_pascal(&node.kind));
-            // Push the node kind to the list of ids for named nodes
-            NODE_ID_FOR_NAMED_NODE.lock().unwrap().insert(
-                node.kind.clone(),
-                language.id_for_node_kind(&node.kind, true),
-            );
-            // If the node has fields, we need to add them to the list of fields
-            if let Some(fields) = &node.fields {
-                fields.iter().for_each(|(field_name, _)| {
-                    let field_id = language.field_id_for_name(field_name);
-                    FIELD_ID_FOR_NAME
-                        .lock()
-                        .unwrap()
-                        .insert(field_name.clone(), field_id.unwrap().get());
-                });
+    /// Extends or overwrites the default tokens (see [Custom Tokens](crate#custom-tokens))
+    pub fn with_tokens(
+        mut self,
+        tokens: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.tokens.extend(
+            tokens
+                .into_iter()
+                .map(|(token, name)| (token.into(), name.into())),
+        );
+        self
+    }
+
+    pub fn with_options(mut self, options: GenerateOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the renames, skipped rules, derives and paths of the generated code (see [Configuration](crate#configuration))
+    pub fn with_config(mut self, config: CodegenConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the grammar.json of the language, to capture the anonymous tokens of each rule (see [Anonymous Tokens](crate#anonymous-tokens))
+    pub fn with_grammar(mut self, grammar: &str) -> Self {
+        self.grammar = Some(grammar.to_string());
+        self
+    }
+
+    /// Generates the Rust code for the given node-types.json
+    ///
+    /// Returns an error if the JSON or the grammar is invalid, if a node kind or field is not part of the language,
+    /// or if the config is invalid.
+    pub fn generate(mut self, source: &str) -> Result<TokenStream, CodegenError> {
+        let (mut output, modules, items) = self.generate_items(source)?;
+        output.extend(modules.generate(&self.config_tokens.visibility));
+        output.extend(items);
+        Ok(output)
+    }
+
+    /// Generates the Rust code for the given node-types.json, with each module in its own file (see [Modules](crate#modules))
+    ///
+    /// Returns the same errors as [`Generator::generate`].
+    pub fn generate_files(mut self, source: &str) -> Result<GeneratedFiles, CodegenError> {
+        let (mut root, modules, items) = self.generate_items(source)?;
+        let (declarations, modules) = modules.generate_files(&self.config_tokens.visibility);
+        root.extend(declarations);
+        root.extend(items);
+        Ok(GeneratedFiles { root, modules })
+    }
+
+    /// Generates the items of the root module before and after the modules, along with the items of each module
+    fn generate_items(
+        &mut self,
+        source: &str,
+    ) -> Result<(TokenStream, Modules, TokenStream), CodegenError> {
+        let nodes: Vec<NodeType> = serde_json::from_str(source)?;
+        self.config_tokens = self.config.tokens()?;
+        if let Some(grammar) = &self.grammar {
+            let grammar = serde_json::from_str(grammar).map_err(CodegenError::InvalidGrammar)?;
+            self.anonymous_children =
+                anonymous_children(&grammar).map_err(CodegenError::InvalidGrammar)?;
+        }
+        let core = &self.config_tokens.crate_path;
+        let visibility = &self.config_tokens.visibility;
+
+        let mut output = quote! {
+            // Auto-generated file. Do not edit manually.
+            #![allow(clippy::all)]
+            #![allow(unused)]
+            #![allow(dead_code)]
+            #![allow(non_camel_case_types)]
+            #![allow(non_snake_case)]
+
+            #visibility use #core::ast::ErrorNode;
+        };
+        output.extend(self.generate_macros());
+        let mut modules = Modules::default();
+        for node in &nodes {
+            if node.named {
+                // Push the node kind to the list of named rules
+                self.named_rules
+                    .insert(sanitize_string_to_pascal(&node.kind, &self.tokens));
+                // Push the node kind to the list of ids for named nodes
+                // Super types are hidden from the parse tree, so they have no id
+                let ids = match node.is_supertype() {
+                    true => vec![self.symbols.id_for_node_kind(&node.kind, true)],
+                    false => self.node_ids(&node.kind, true)?,
+                };
+                self.node_id_for_named_node.insert(node.kind.clone(), ids);
+                // If the node has fields, we need to add them to the list of fields
+                if let Some(fields) = &node.fields {
+                    for field_name in fields.keys() {
+                        let field_id = self
+                            .symbols
+                            .field_id_for_name(field_name)
+                            .ok_or_else(|| CodegenError::UnknownField(field_name.clone()))?;
+                        self.field_id_for_name.insert(field_name.clone(), field_id);
+                    }
+                }
+            } else {
+                // Push the node kind to the list of ids for unnamed nodes
+                let ids = self.node_ids(&node.kind, false)?;
+                self.node_id_for_unnamed_node.insert(node.kind.clone(), ids);
+            }
+            // If node is a supertype, add it to the list of super types
+            if node.is_supertype() {
+                self.super_types
+                    .insert(node.kind.clone(), generate_super_type(node));
             }
-        } else {
-            // Push the node kind to the list of ids for named nodes
-            NODE_ID_FOR_UNNAMED_NODE.lock().unwrap().insert(
-                node.kind.clone(),
-                language.id_for_node_kind(&node.kind, false),
-            );
         }
-        // If node is a supertype, add it to the list of super types
-        if node.is_supertype() {
-            SUPER_TYPES
-                .write()
-                .unwrap()
-                .insert(node.kind.clone(), generate_super_type(node));
+
+        // Super types may contains other super types
+        // in this case we need to add the nested super types to the `types` field of the current super type
+        let mut new_super_types = BTreeMap::new();
+
+        for (super_type_name, super_type) in self.super_types.iter() {
+            let mut new_super_type = SuperType::default();
+
+            // Iterate over the types of this super type
+            super_type.types.iter().enumerate().for_each(|(i, key)| {
+                if let Some(nested_super_type) = self.super_types.get(key) {
+                    // Some types are super types
+                    new_super_type.types.extend(nested_super_type.types.clone());
+                } else {
+                    // Otherwise, we just clone the type
+                    new_super_type.types.push(key.clone());
+                }
+                new_super_type.variants.push(super_type.variants[i].clone())
+            });
+            new_super_types.insert(super_type_name.clone(), new_super_type);
         }
-    }
 
-    // Super types may contains other super types
-    // in this case we need to add the nested super types to the `types` field of the current super type
-    let mut super_types_lock = SUPER_TYPES.write().unwrap();
-    let mut new_super_types = BTreeMap::new();
+        // Now we need to merge the new super types with the existing ones
+        self.super_types.extend(new_super_types);
 
-    for (super_type_name, super_type) in super_types_lock.iter() {
-        let mut new_super_type = SuperType::default();
+        // Generate the structs and enums for all rules
+        for node in &nodes {
+            let items = node.generate(self)?;
+            match (node.is_enum(), node.named) {
+                (true, _) => modules.enums.extend(items),
+                (false, true) => modules.structs.extend(items),
+                (false, false) => modules.tokens.extend(items),
+            }
+        }
 
-        // Iterate over the types of this super type
-        super_type.types.iter().enumerate().for_each(|(i, key)| {
-            if let Some(nested_super_type) = super_types_lock.get(key) {
-                // Some types are super types
-                new_super_type.types.extend(nested_super_type.types.clone());
-            } else {
-                // Otherwise, we just clone the type
-                new_super_type.types.push(key.clone());
+        // Generate the list of operators
+        for operators in std::mem::take(&mut self.operators_rules).values() {
+            modules.operators.extend(self.generate_enum(
+                &self.type_ident(&format!("Operators_{}", operators.index)),
+                &operators.operators,
+                None,
+            )?);
+        }
+
+        // Generate the list of inline multiple rules
+        for (id, values) in &std::mem::take(&mut self.inline_multiple_rules) {
+            modules.enums.extend(self.generate_enum(
+                &self.type_ident(&sanitize_string(id, &self.tokens)),
+                values,
+                None,
+            )?);
+        }
+
+        // Generate the list of anonymous types
+        for name in &std::mem::take(&mut self.anonymous_types) {
+            modules.tokens.extend(self.generate_struct(
+                &self.type_ident(&sanitize_string_to_pascal(name, &self.tokens)),
+                name,
+                true,
+                &[],
+            )?);
+        }
+
+        // Generate the list of super types
+        // We need to clone because generate_enum will also check if some variants are super types
+        for (super_type_name, super_type) in self.super_types.clone().iter() {
+            if self.is_skipped(super_type_name) {
+                continue;
             }
-            new_super_type.variants.push(super_type.variants[i].clone())
-        });
-        new_super_types.insert(super_type_name.clone(), new_super_type);
-    }
+            modules.supertypes.extend(self.generate_enum(
+                &self.type_ident(&sanitize_string_to_pascal(super_type_name, &self.tokens)),
+                &super_type.variants,
+                Some(super_type_name),
+            )?);
+        }
 
-    // Now we need to merge the new super types with the existing ones
-    new_super_types.into_iter().for_each(|(name, s)| {
-        super_types_lock.insert(name.clone(), s.clone());
-    });
+        // Generate the enum helpers once all enums are known
+        let mut items = self.generate_variant_helpers();
 
-    drop(super_types_lock);
+        // Generate the visitor trait once all types are known
+        items.extend(self.generate_visitor());
 
-    // Generate the structs and enums for all rules
-    for node in &nodes {
-        output.extend(node.to_token_stream());
+        // Generate the node kinds once all structs are known
+        items.extend(self.generate_node_kind());
+
+        items.extend(self.generate_verify_language());
+
+        Ok((output, modules, items))
     }
 
-    // Generate the list of operators
-    for operators in (*OPERATORS_RULES.lock().unwrap()).values() {
-        output.extend(generate_enum(
-            &format_ident!("Operators_{}", operators.index),
-            &operators.operators,
-        ));
+    /// Returns the identifier of a generated type given its default name, renamed by the config if needed
+    pub(crate) fn type_ident(&self, default_name: &str) -> Ident {
+        match self.config.renames.get(default_name) {
+            Some(name) => format_ident!("{}", name),
+            None => format_ident!("{}", default_name),
+        }
     }
 
-    // Generate the list of inline multiple rules
-    for (id, values) in &*INLINE_MULTIPLE_RULES.lock().unwrap() {
-        output.extend(generate_enum(
-            &format_ident!("{}", sanitize_string(id)),
-            values,
-        ));
+    /// Returns `true` if the rule of a tree-sitter kind is skipped by the config
+    pub(crate) fn is_skipped(&self, kind: &str) -> bool {
+        self.config.skipped_rules.contains(kind)
     }
 
-    // Generate the list of anonymous types
-    for name in ANONYMOUS_TYPES.lock().unwrap().iter() {
-        output.extend(generate_struct(
-            &format_ident!("{}", &sanitize_string_to_pascal(name)),
-            name,
-            &vec![],
-            &vec![],
-            &vec![],
-            &vec![],
-        ));
+    /// Returns all the ids of a node kind in the language, aliases included
+    pub(crate) fn node_ids(&self, kind: &str, named: bool) -> Result<Vec<u16>, CodegenError> {
+        match self.symbols.ids_for_node_kind(kind, named) {
+            [] => Err(CodegenError::UnknownNodeKind {
+                kind: kind.to_string(),
+                named,
+            }),
+            ids => Ok(ids.to_vec()),
+        }
     }
 
-    // Generate the list of super types
-    // We need to clone because generate_enum will also check if some variants are super types
-    for (super_type_name, super_type) in SUPER_TYPES.read().unwrap().iter() {
-        output.extend(generate_enum(
-            &format_ident!("{}", &sanitize_string_to_pascal(super_type_name)),
-            &super_type.variants,
-        ));
+    /// Returns the ids of a node kind already registered from the node types
+    pub(crate) fn registered_node_ids(
+        &self,
+        kind: &str,
+        named: bool,
+    ) -> Result<Vec<u16>, CodegenError> {
+        let ids = match named {
+            true => &self.node_id_for_named_node,
+            false => &self.node_id_for_unnamed_node,
+        };
+        ids.get(kind)
+            .cloned()
+            .ok_or_else(|| CodegenError::UnknownNodeKind {
+                kind: kind.to_string(),
+                named,
+            })
     }
+}
 
-    output
+/// Generates the Rust code for a given Tree-sitter grammar
+///
+/// # Arguments
+///
+/// * `source` - node-types.json
+/// * `language` - tree-sitter language fn
+/// * `tokens` - optional map of tokens to enum names (since tokens can't be valid rust identifiers)
+///
+/// # Returns
+/// A TokenStream containing the generated code, or a [`CodegenError`] if the node types do not match the language
+///
+/// # Example
+///
+/// ```rust
+/// use auto_lsp_codegen::generate;
+///
+/// let _result = generate(
+///        &tree_sitter_python::NODE_TYPES,
+///        &tree_sitter_python::LANGUAGE.into(),
+///        None,
+///    ).unwrap();
+/// ```
+///
+pub fn generate(
+    source: &str,
+    language: &tree_sitter::Language,
+    tokens: Option<HashMap<&'static str, &'static str>>,
+) -> Result<TokenStream, CodegenError> {
+    generate_with_options(source, language, tokens, &GenerateOptions::default())
+}
+
+/// Like [`generate`], with additional [`GenerateOptions`]
+///
+/// # Example
+///
+/// ```rust
+/// use auto_lsp_codegen::{generate_with_options, GenerateOptions};
+///
+/// let _result = generate_with_options(
+///        &tree_sitter_python::NODE_TYPES,
+///        &tree_sitter_python::LANGUAGE.into(),
+///        None,
+///        &GenerateOptions { lenient_fields: true, ..Default::default() },
+///    ).unwrap();
+/// ```
+pub fn generate_with_options(
+    source: &str,
+    language: &tree_sitter::Language,
+    tokens: Option<HashMap<&'static str, &'static str>>,
+    options: &GenerateOptions,
+) -> Result<TokenStream, CodegenError> {
+    Generator::new(language)
+        .with_tokens(tokens.unwrap_or_default())
+        .with_options(options.clone())
+        .generate(source)
 }
@@ -0,0 +1,256 @@
+use crate::Generator;
+use crate::config::ConfigTokens;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+impl Generator {
+    /// Generates the `macro_rules!` implementing the code shared by the generated types
+    ///
+    /// - `ast_node!` implements `AstNode`, `kind` and `view` for a struct, given the names of its fields
+    /// - `ast_leaf!` generates a struct without fields, along with all its implementations
+    /// - `ast_enum!` implements `AstNode`, `kind` and `view` for an enum, by delegating to its variants
+    ///
+    /// The config and the `serde` option are resolved here, so invocations only hold what differs between types.
+    pub(crate) fn generate_macros(&self) -> TokenStream {
+        let ConfigTokens {
+            derives,
+            attributes,
+            visibility,
+            crate_path: core,
+        } = &self.config_tokens;
+        let struct_serializable_fields = self.generate_serializable_fields(false);
+        let enum_serializable_fields = self.generate_serializable_fields(true);
+        let leaf_serialize = self.generate_struct_serialize(&quote! { $name }, &[]);
+        let enum_serialize = self.generate_serialize(&quote! { $name });
+
+        quote! {
+            macro_rules! ast_node {
+                (
+                    $name:ident, $kind:literal, $kind_id:literal, $named:literal, [$($id:literal),+],
+                    fields: [$($field:ident: $field_name:literal),*],
+                    children: [$($child:ident),*],
+                    lenient: [$($lenient:ident),*]
+                ) => {
+                    impl #core::ast::AstNode for $name {
+                        fn contains(node: &#core::tree_sitter::Node) -> bool {
+                            matches!(node.kind_id(), $($id)|+)
+                        }
+
+                        fn verify_language(language: &#core::tree_sitter::Language) -> Result<(), Vec<#core::errors::Mismatch>> {
+                            verify_language(language)
+                        }
+
+                        fn lower(&self) -> &dyn #core::ast::AstNode {
+                            self
+                        }
+
+                        fn get_id(&self) -> usize {
+                            self._id
+                        }
+
+                        fn get_parent_id(&self) -> Option<usize> {
+                            self._parent
+                        }
+
+                        // Children are collected field by field, then sorted by id to get them in source order
+                        fn get_children_ids(&self) -> #core::ast::ChildrenIds {
+                            let mut ids = #core::ast::ChildrenIds::new();
+                            $(#core::ast::NodeField::push_ids(&self.$field, &mut ids);)*
+                            $(#core::ast::NodeField::push_ids(&self.$child, &mut ids);)*
+                            ids.sort_unstable();
+                            ids
+                        }
+
+                        fn get_span(&self) -> #core::ast::Span {
+                            self._span
+                        }
+
+                        fn get_kind_name(&self) -> &'static str {
+                            $kind
+                        }
+
+                        fn get_kind_id(&self) -> u16 {
+                            $kind_id
+                        }
+
+                        fn is_named(&self) -> bool {
+                            $named
+                        }
+
+                        fn get_field_name(&self, id: usize) -> Option<&'static str> {
+                            $(
+                                if #core::ast::NodeField::holds(&self.$field, id) {
+                                    return Some($field_name);
+                                }
+                            )*
+                            None
+                        }
+
+                        fn relocate(&mut self, relocation: &#core::ast::Relocation) {
+                            self._parent = relocation.parent(self._id, self._parent);
+                            self._id = relocation.id(self._id);
+                            self._span = relocation.span(self._span);
+                            $(#core::ast::NodeField::relocate(&mut self.$field, relocation);)*
+                            $(#core::ast::NodeField::relocate(&mut self.$child, relocation);)*
+                        }
+
+                        fn is_missing(&self) -> bool {
+                            self._is_missing
+                        }
+
+                        // Only lenient fields can be missing
+                        fn has_all_required_fields(&self) -> bool {
+                            true $(&& self.$lenient.is_ok())*
+                        }
+
+                        #struct_serializable_fields
+                    }
+
+                    impl $name {
+                        #visibility fn kind(&self) -> NodeKind {
+                            NodeKind::$name
+                        }
+
+                        /// Returns a view of this node, to navigate its fields without passing the AST around.
+                        #visibility fn view<'a>(&'a self, nodes: &'a #core::ast::AstArena) -> #core::ast::AstView<'a, Self> {
+                            #core::ast::AstView::new(self, nodes)
+                        }
+                    }
+                };
+            }
+
+            macro_rules! ast_leaf {
+                ($name:ident, $kind:literal, $kind_id:literal, $named:literal, [$($id:literal),+]) => {
+                    #[derive(Debug, Clone, PartialEq #(, #derives)*)]
+                    #(#attributes)*
+                    #visibility struct $name {
+                        _span: #core::ast::Span,
+                        _id: usize,
+                        _parent: Option<usize>,
+                        _is_missing: bool,
+                    }
+
+                    ast_node!($name, $kind, $kind_id, $named, [$($id),+], fields: [], children: [], lenient: []);
+
+                    impl<'a> TryFrom<#core::ast::TryFromParams<'a>> for $name {
+                        type Error = #core::errors::AstError;
+
+                        fn try_from((node, db, builder, id, parent_id): #core::ast::TryFromParams) -> Result<Self, #core::errors::AstError> {
+                            Ok(Self {
+                                _span: #core::ast::Span::from(node),
+                                _id: id,
+                                _parent: parent_id,
+                                _is_missing: node.is_missing(),
+                            })
+                        }
+                    }
+
+                    #leaf_serialize
+                };
+            }
+
+            macro_rules! ast_enum {
+                ($name:ident, [$($variant:ident),+], [$($id:literal),+]) => {
+                    impl #core::ast::AstNode for $name {
+                        fn contains(node: &#core::tree_sitter::Node) -> bool {
+                            matches!(node.kind_id(), $($id)|+)
+                        }
+
+                        fn verify_language(language: &#core::tree_sitter::Language) -> Result<(), Vec<#core::errors::Mismatch>> {
+                            verify_language(language)
+                        }
+
+                        fn lower(&self) -> &dyn #core::ast::AstNode {
+                            match self {
+                                $(Self::$variant(node) => node.lower()),+
+                            }
+                        }
+
+                        fn get_id(&self) -> usize {
+                            match self {
+                                $(Self::$variant(node) => node.get_id()),+
+                            }
+                        }
+
+                        fn get_parent_id(&self) -> Option<usize> {
+                            match self {
+                                $(Self::$variant(node) => node.get_parent_id()),+
+                            }
+                        }
+
+                        fn get_children_ids(&self) -> #core::ast::ChildrenIds {
+                            match self {
+                                $(Self::$variant(node) => node.get_children_ids()),+
+                            }
+                        }
+
+                        fn get_span(&self) -> #core::ast::Span {
+                            match self {
+                                $(Self::$variant(node) => node.get_span()),+
+                            }
+                        }
+
+                        fn get_kind_name(&self) -> &'static str {
+                            match self {
+                                $(Self::$variant(node) => node.get_kind_name()),+
+                            }
+                        }
+
+                        fn get_kind_id(&self) -> u16 {
+                            match self {
+                                $(Self::$variant(node) => node.get_kind_id()),+
+                            }
+                        }
+
+                        fn is_named(&self) -> bool {
+                            match self {
+                                $(Self::$variant(node) => node.is_named()),+
+                            }
+                        }
+
+                        fn get_field_name(&self, id: usize) -> Option<&'static str> {
+                            match self {
+                                $(Self::$variant(node) => node.get_field_name(id)),+
+                            }
+                        }
+
+                        fn relocate(&mut self, relocation: &#core::ast::Relocation) {
+                            match self {
+                                $(Self::$variant(node) => node.relocate(relocation)),+
+                            }
+                        }
+
+                        fn is_missing(&self) -> bool {
+                            match self {
+                                $(Self::$variant(node) => node.is_missing()),+
+                            }
+                        }
+
+                        fn has_all_required_fields(&self) -> bool {
+                            match self {
+                                $(Self::$variant(node) => node.has_all_required_fields()),+
+                            }
+                        }
+
+                        #enum_serializable_fields
+                    }
+
+                    impl $name {
+                        #visibility fn kind(&self) -> NodeKind {
+                            match self {
+                                $(Self::$variant(node) => node.kind()),+
+                            }
+                        }
+
+                        /// Returns a view of this node, to navigate its fields without passing the AST around.
+                        #visibility fn view<'a>(&'a self, nodes: &'a #core::ast::AstArena) -> #core::ast::AstView<'a, Self> {
+                            #core::ast::AstView::new(self, nodes)
+                        }
+                    }
+
+                    #enum_serialize
+                };
+            }
+        }
+    }
+}
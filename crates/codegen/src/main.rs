@@ -0,0 +1,447 @@
+//! `auto-lsp-codegen` binary, see [Command Line](auto_lsp_codegen#command-line).
+
+use auto_lsp_codegen::{CodegenConfig, CodegenError, GenerateOptions, GeneratedFiles, Generator};
+use clap::Parser;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use thiserror::Error;
+use tree_sitter_language::LanguageFn;
+
+/// Generates the AST of a tree-sitter grammar
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    /// Path of the `node-types.json` of the grammar
+    #[arg(
+        long,
+        required_unless_present = "grammar_dir",
+        conflicts_with = "grammar_dir"
+    )]
+    node_types: Option<PathBuf>,
+
+    /// Path of the `grammar.json` of the grammar, to generate fields for anonymous tokens
+    #[arg(long, conflicts_with = "grammar_dir")]
+    grammar: Option<PathBuf>,
+
+    /// Directory of a tree-sitter grammar, reading `src/node-types.json` and `src/grammar.json`
+    #[arg(long)]
+    grammar_dir: Option<PathBuf>,
+
+    /// Path of the compiled language, such as `libtree-sitter-python.so`
+    #[arg(long)]
+    language: PathBuf,
+
+    /// Name of the language, used to find the `tree_sitter_<name>` symbol.
+    ///
+    /// Defaults to the name of the compiled language, without the `lib` and `tree-sitter-` prefixes.
+    #[arg(long)]
+    name: Option<String>,
+
+    /// Generates required fields as `Result<AstNodeId<T>, MissingField>`
+    #[arg(long)]
+    lenient_fields: bool,
+
+    /// Implements `serde::Serialize` on the generated types, requiring the `serde` feature of `auto_lsp`
+    #[arg(long)]
+    serde: bool,
+
+    /// Names the type of an anonymous token, such as `--token '->=Arrow'`
+    #[arg(
+        long = "token",
+        value_name = "TOKEN=NAME",
+        value_parser = parse_pair,
+        allow_hyphen_values = true
+    )]
+    tokens: Vec<(String, String)>,
+
+    /// Renames a generated type, such as `--rename CompoundStatement_SimpleStatement=Statement`
+    #[arg(long = "rename", value_name = "FROM=TO", value_parser = parse_pair)]
+    renames: Vec<(String, String)>,
+
+    /// Skips the rule of a tree-sitter kind, such as `--skip-rule print_statement`
+    #[arg(long = "skip-rule", value_name = "KIND")]
+    skip_rules: Vec<String>,
+
+    /// Adds a derive to every generated type, such as `--derive Hash`
+    #[arg(long = "derive", value_name = "DERIVE")]
+    derives: Vec<String>,
+
+    /// Adds an attribute to every generated type, such as `--attribute '#[non_exhaustive]'`
+    #[arg(long = "attribute", value_name = "ATTRIBUTE")]
+    attributes: Vec<String>,
+
+    /// Visibility of the generated types, `pub` by default
+    #[arg(long)]
+    visibility: Option<String>,
+
+    /// Path of the core crate in the generated code, `auto_lsp::core` by default
+    #[arg(long)]
+    crate_path: Option<String>,
+
+    /// Path of the generated file
+    #[arg(short, long)]
+    output: PathBuf,
+
+    /// Writes each module to its own file, in a directory named after the generated file,
+    /// such as `src/generated/structs.rs` for `src/generated.rs`
+    #[arg(long)]
+    split: bool,
+
+    /// Fails if the generated file is not up to date, instead of regenerating it
+    #[arg(long)]
+    check: bool,
+}
+
+#[derive(Debug, Error)]
+enum CliError {
+    #[error("Failed to read {path}: {error}")]
+    Read {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to write {path}: {error}")]
+    Write {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to load {symbol} from {path}: {error}")]
+    Language {
+        path: PathBuf,
+        symbol: String,
+        error: libloading::Error,
+    },
+    #[error(transparent)]
+    Codegen(#[from] CodegenError),
+    #[error("Failed to format the generated code: {0}")]
+    Format(#[from] syn::Error),
+    #[error("{0} is not up to date, run auto-lsp-codegen without --check to regenerate it")]
+    Stale(PathBuf),
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), CliError> {
+    let (node_types_path, grammar_path) = match &args.grammar_dir {
+        Some(dir) => {
+            let grammar = dir.join("src/grammar.json");
+            (
+                dir.join("src/node-types.json"),
+                grammar.exists().then_some(grammar),
+            )
+        }
+        None => (
+            args.node_types.clone().unwrap_or_default(),
+            args.grammar.clone(),
+        ),
+    };
+    let node_types = read(&node_types_path)?;
+    let grammar = grammar_path.as_deref().map(read).transpose()?;
+    let language = load_language(&args.language, args.name.as_deref())?;
+
+    // Ids are part of the generated code, so the language is hashed along with the JSON files
+    let mut input = Hasher::new();
+    input.write(env!("CARGO_PKG_VERSION").as_bytes());
+    input.write(&[
+        args.lenient_fields as u8,
+        args.serde as u8,
+        args.split as u8,
+    ]);
+    hash_config(&mut input, &args);
+    input.write(node_types.as_bytes());
+    input.write(grammar.as_deref().unwrap_or_default().as_bytes());
+    hash_language(&mut input, &language);
+    let input = input.finish();
+
+    let paths = output_paths(&args);
+    let stale = paths.iter().find(|path| {
+        !std::fs::read_to_string(path).is_ok_and(|current| is_up_to_date(&current, input))
+    });
+    let Some(stale) = stale else {
+        return Ok(());
+    };
+    if args.check {
+        return Err(CliError::Stale(stale.clone()));
+    }
+
+    let mut generator = Generator::new(&language)
+        .with_tokens(args.tokens.clone())
+        .with_options(GenerateOptions {
+            lenient_fields: args.lenient_fields,
+            serde: args.serde,
+        })
+        .with_config(config(&args));
+    if let Some(grammar) = &grammar {
+        generator = generator.with_grammar(grammar);
+    }
+    let files = match args.split {
+        true => {
+            let GeneratedFiles { root, modules } = generator.generate_files(&node_types)?;
+            let dir = args.output.with_extension("");
+            std::fs::create_dir_all(&dir).map_err(|error| CliError::Write { path: dir, error })?;
+            std::iter::once(root)
+                .chain(modules.into_iter().map(|(_, code)| code))
+                .collect()
+        }
+        false => vec![generator.generate(&node_types)?],
+    };
+
+    for (path, code) in paths.into_iter().zip(files) {
+        let code = prettyplease::unparse(&syn::parse2(code)?);
+        let output = format!("{}{code}", stamp(input, &code));
+        std::fs::write(&path, output).map_err(|error| CliError::Write { path, error })?;
+    }
+    Ok(())
+}
+
+/// Returns the paths of the generated files, starting with the output
+fn output_paths(args: &Args) -> Vec<PathBuf> {
+    let mut paths = vec![args.output.clone()];
+    if args.split {
+        let dir = args.output.with_extension("");
+        paths.extend(
+            GeneratedFiles::MODULES
+                .iter()
+                .map(|module| dir.join(format!("{module}.rs"))),
+        );
+    }
+    paths
+}
+
+/// Parses a `KEY=VALUE` argument
+fn parse_pair(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) => Ok((key.to_string(), value.to_string())),
+        None => Err(format!("expected KEY=VALUE, got `{arg}`")),
+    }
+}
+
+/// Returns the config of the generator given by the arguments
+fn config(args: &Args) -> CodegenConfig {
+    let mut config = CodegenConfig::new();
+    for (from, to) in &args.renames {
+        config = config.rename(from, to);
+    }
+    for kind in &args.skip_rules {
+        config = config.skip_rule(kind);
+    }
+    for derive in &args.derives {
+        config = config.derive(derive);
+    }
+    for attribute in &args.attributes {
+        config = config.attribute(attribute);
+    }
+    if let Some(visibility) = &args.visibility {
+        config = config.visibility(visibility);
+    }
+    if let Some(crate_path) = &args.crate_path {
+        config = config.crate_path(crate_path);
+    }
+    config
+}
+
+/// Hashes the tokens and the config given by the arguments, in the order they are applied
+fn hash_config(hasher: &mut Hasher, args: &Args) {
+    let pairs = [("token", &args.tokens), ("rename", &args.renames)];
+    for (option, pairs) in pairs {
+        for (key, value) in pairs {
+            hasher.write(option.as_bytes());
+            hasher.write(key.as_bytes());
+            hasher.write(value.as_bytes());
+        }
+    }
+    let lists = [
+        ("skip-rule", &args.skip_rules),
+        ("derive", &args.derives),
+        ("attribute", &args.attributes),
+    ];
+    for (option, values) in lists {
+        for value in values {
+            hasher.write(option.as_bytes());
+            hasher.write(value.as_bytes());
+        }
+    }
+    let values = [
+        ("visibility", &args.visibility),
+        ("crate-path", &args.crate_path),
+    ];
+    for (option, value) in values {
+        if let Some(value) = value {
+            hasher.write(option.as_bytes());
+            hasher.write(value.as_bytes());
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, CliError> {
+    std::fs::read_to_string(path).map_err(|error| CliError::Read {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Loads the `tree_sitter_<name>` function of a compiled language
+fn load_language(path: &Path, name: Option<&str>) -> Result<tree_sitter::Language, CliError> {
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let stem = stem.strip_prefix("lib").unwrap_or(&stem);
+            let stem = stem
+                .strip_prefix("tree-sitter-")
+                .or_else(|| stem.strip_prefix("tree_sitter_"))
+                .unwrap_or(stem);
+            stem.replace('-', "_")
+        }
+    };
+    let symbol = format!("tree_sitter_{name}");
+    let error = |error| CliError::Language {
+        path: path.to_path_buf(),
+        symbol: symbol.clone(),
+        error,
+    };
+
+    // SAFETY: the library is expected to be a tree-sitter grammar, exporting `symbol` as a `TSLanguage` constructor.
+    // It is leaked so the language stays valid until the process exits.
+    unsafe {
+        let library = Box::leak(Box::new(libloading::Library::new(path).map_err(error)?));
+        let language_fn = library
+            .get::<unsafe extern "C" fn() -> *const ()>(symbol.as_bytes())
+            .map_err(error)?;
+        Ok(LanguageFn::from_raw(*language_fn).into())
+    }
+}
+
+/// Hashes the node kinds and fields of a language, along with their ids
+fn hash_language(hasher: &mut Hasher, language: &tree_sitter::Language) {
+    for id in 0..language.node_kind_count() as u16 {
+        hasher.write(language.node_kind_for_id(id).unwrap_or_default().as_bytes());
+        hasher.write(&[language.node_kind_is_named(id) as u8]);
+    }
+    for id in 1..=language.field_count() as u16 {
+        hasher.write(
+            language
+                .field_name_for_id(id)
+                .unwrap_or_default()
+                .as_bytes(),
+        );
+    }
+}
+
+/// Returns the header of a generated file, holding the hashes of its inputs and of its code
+fn stamp(input: u64, code: &str) -> String {
+    let mut output = Hasher::new();
+    output.write(code.as_bytes());
+    format!(
+        "// @generated by auto-lsp-codegen {}, do not edit manually.\n// stamp: {input:016x}-{:016x}\n\n",
+        env!("CARGO_PKG_VERSION"),
+        output.finish()
+    )
+}
+
+/// Returns `true` if a generated file was generated from the same inputs, and was not edited since
+fn is_up_to_date(current: &str, input: u64) -> bool {
+    let mut lines = current.splitn(4, '\n');
+    let (Some(_), Some(stamp_line), Some(_), Some(code)) =
+        (lines.next(), lines.next(), lines.next(), lines.next())
+    else {
+        return false;
+    };
+    stamp(input, code).lines().nth(1) == Some(stamp_line)
+}
+
+/// FNV-1a, whose output does not change across Rust versions unlike `DefaultHasher`
+struct Hasher(u64);
+
+impl Hasher {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // Length prefix, so consecutive inputs can not be shifted into each other
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stamp_round_trip() {
+        let code = "pub struct Module {}\n";
+        let file = format!("{}{code}", stamp(1, code));
+
+        assert!(is_up_to_date(&file, 1));
+        // Inputs changed
+        assert!(!is_up_to_date(&file, 2));
+        // Generated code edited
+        assert!(!is_up_to_date(&format!("{file}// edit\n"), 1));
+        // Not generated by the binary
+        assert!(!is_up_to_date(code, 1));
+    }
+
+    #[test]
+    fn config_args() {
+        let args = Args::parse_from([
+            "auto-lsp-codegen",
+            "--grammar-dir",
+            "tree-sitter-python",
+            "--language",
+            "libtree-sitter-python.so",
+            "-o",
+            "generated.rs",
+            "--token",
+            "->=Arrow",
+            "--rename",
+            "CompoundStatement_SimpleStatement=Statement",
+            "--skip-rule",
+            "print_statement",
+        ]);
+        assert_eq!(args.tokens, [("->".to_string(), "Arrow".to_string())]);
+        assert_eq!(
+            args.renames,
+            [(
+                "CompoundStatement_SimpleStatement".to_string(),
+                "Statement".to_string()
+            )]
+        );
+        assert_eq!(args.skip_rules, ["print_statement"]);
+        assert!(parse_pair("Arrow").is_err());
+
+        // Any option changes the stamp
+        let hash = |args: &Args| {
+            let mut hasher = Hasher::new();
+            hash_config(&mut hasher, args);
+            hasher.finish()
+        };
+        let mut derived = Args::parse_from([
+            "auto-lsp-codegen",
+            "--grammar-dir",
+            "tree-sitter-python",
+            "--language",
+            "libtree-sitter-python.so",
+            "-o",
+            "generated.rs",
+        ]);
+        let default = hash(&derived);
+        derived.derives.push("Hash".to_string());
+        assert_ne!(hash(&derived), default);
+        assert_ne!(hash(&args), default);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::GeneratedFiles;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generated items, sorted by module
+///
+/// Each module imports the items of the root module, which re-exports the items of each module,
+/// so generated types keep the same paths whatever module they are in.
+#[derive(Default)]
+pub(crate) struct Modules {
+    /// Structs of named rules
+    pub(crate) structs: TokenStream,
+    /// Enums of named rules, and enums of fields and children with several types
+    pub(crate) enums: TokenStream,
+    pub(crate) supertypes: TokenStream,
+    /// Structs of anonymous tokens
+    pub(crate) tokens: TokenStream,
+    /// Enums of operators
+    pub(crate) operators: TokenStream,
+}
+
+impl Modules {
+    /// Generates the modules inline
+    pub(crate) fn generate(self, visibility: &TokenStream) -> TokenStream {
+        self.into_iter()
+            .map(|(name, items)| {
+                let name = format_ident!("{}", name);
+                quote! {
+                    #visibility mod #name {
+                        use super::*;
+
+                        #items
+                    }
+
+                    #visibility use #name::*;
+                }
+            })
+            .collect()
+    }
+
+    /// Generates the declarations of the modules, along with the content of each module file
+    pub(crate) fn generate_files(
+        self,
+        visibility: &TokenStream,
+    ) -> (TokenStream, Vec<(&'static str, TokenStream)>) {
+        self.into_iter()
+            .map(|(name, items)| {
+                let ident = format_ident!("{}", name);
+                let declaration = quote! {
+                    #visibility mod #ident;
+                    #visibility use #ident::*;
+                };
+                let file = quote! {
+                    use super::*;
+
+                    #items
+                };
+                (declaration, (name, file))
+            })
+            .unzip()
+    }
+
+    fn into_iter(self) -> impl Iterator<Item = (&'static str, TokenStream)> {
+        GeneratedFiles::MODULES.into_iter().zip([
+            self.structs,
+            self.enums,
+            self.supertypes,
+            self.tokens,
+            self.operators,
+        ])
+    }
+}
@@ -1,370 +1,319 @@
+use crate::config::ConfigTokens;
+use crate::grammar::AnonymousKind;
+use crate::ir::FieldOrChildren;
 use crate::json::{NodeType, TypeInfo};
 use crate::utils::sanitize_string_to_pascal;
-use crate::{sanitize_string, SUPER_TYPES};
-use crate::{NODE_ID_FOR_NAMED_NODE, NODE_ID_FOR_UNNAMED_NODE};
+use crate::view::generate_view_accessors;
+use crate::{CodegenError, Generator, sanitize_string};
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote, ToTokens};
+use quote::{ToTokens, quote};
 
-impl ToTokens for NodeType {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(if self.is_struct() {
-            self.create_struct().to_token_stream()
+impl NodeType {
+    pub(crate) fn generate(&self, generator: &mut Generator) -> Result<TokenStream, CodegenError> {
+        let tokens = &generator.tokens;
+        Ok(if generator.is_skipped(&self.kind) {
+            TokenStream::new()
+        } else if self.is_struct() {
+            self.create_struct(generator)?
         } else if self.is_enum() {
-            self.create_enum().to_token_stream()
+            self.create_enum(generator)?
         } else if self.is_token() {
-            generate_struct(
-                &format_ident!("Token_{}", &sanitize_string(&self.kind)),
+            generator.generate_struct(
+                &generator.type_ident(&format!("Token_{}", sanitize_string(&self.kind, tokens))),
                 &self.kind,
-                &vec![],
-                &vec![],
-                &vec![],
-                &vec![],
-            )
+                false,
+                &[],
+            )?
         } else if !self.is_supertype() {
-            generate_struct(
-                &format_ident!("{}", &sanitize_string_to_pascal(&self.kind)),
+            generator.generate_struct(
+                &generator.type_ident(&sanitize_string_to_pascal(&self.kind, tokens)),
                 &self.kind,
-                &vec![],
-                &vec![],
-                &vec![],
-                &vec![],
-            )
+                self.named,
+                &[],
+            )?
         } else {
             TokenStream::new()
-        });
+        })
     }
-}
 
-impl NodeType {
-    fn create_struct(&self) -> impl ToTokens {
+    fn create_struct(&self, generator: &mut Generator) -> Result<TokenStream, CodegenError> {
         let mut _fields = vec![];
 
         if let Some(fields) = self.fields.as_ref() {
-            fields.iter().for_each(|(name, info)| {
-                _fields.push(info.field_code_gen(name));
-            });
+            for (name, info) in fields {
+                // Fields whose types are all skipped are dropped
+                _fields.extend(info.field_code_gen(generator, name)?);
+            }
         }
 
         if let Some(children) = self.children.as_ref() {
-            _fields.push(children.child_code_gen());
+            _fields.extend(children.child_code_gen(generator));
         }
 
-        let (struct_fields, struct_fields_init, struct_fields_collect, struct_fields_finalize) =
-            _fields
-                .iter()
-                .map(|field| {
-                    (
-                        field.generate_field(),
-                        field.generate_field_init(),
-                        field.generate_field_collect(),
-                        field.generate_field_finalize(),
-                    )
-                })
-                .fold(
-                    (vec![], vec![], vec![], vec![]),
-                    |(mut fields, mut inits, mut collects, mut finalizes),
-                     (field, init, collect, finalize)| {
-                        fields.push(field);
-                        inits.push(init);
-                        collects.push(collect);
-                        finalizes.push(finalize);
-                        (fields, inits, collects, finalizes)
-                    },
-                );
+        // Anonymous tokens found in the grammar
+        if let Some(children) = generator.anonymous_children.remove(&self.kind) {
+            let mut operators = 0;
+            for child in &children {
+                _fields.extend(child.child_code_gen(generator, operators));
+                if matches!(child.kind, AnonymousKind::Operator) {
+                    operators += 1;
+                }
+            }
+        }
 
-        generate_struct(
-            &format_ident!("{}", sanitize_string_to_pascal(&self.kind)),
+        generator.generate_struct(
+            &generator.type_ident(&sanitize_string_to_pascal(&self.kind, &generator.tokens)),
             &self.kind,
-            &struct_fields,
-            &struct_fields_init,
-            &struct_fields_collect,
-            &struct_fields_finalize,
+            true,
+            &_fields,
         )
     }
 
-    fn create_enum(&self) -> impl ToTokens {
-        generate_enum(
-            &format_ident!("{}", sanitize_string_to_pascal(&self.kind)),
+    fn create_enum(&self, generator: &mut Generator) -> Result<TokenStream, CodegenError> {
+        generator.generate_enum(
+            &generator.type_ident(&sanitize_string_to_pascal(&self.kind, &generator.tokens)),
             self.subtypes.as_ref().unwrap(),
+            Some(&self.kind),
         )
     }
 }
 
-pub(crate) fn generate_struct(
-    struct_name: &Ident,
-    struct_type: &String,
-    struct_fields: &Vec<TokenStream>,
-    struct_fields_init: &Vec<TokenStream>,
-    struct_fields_collect: &Vec<TokenStream>,
-    struct_fields_finalize: &Vec<TokenStream>,
-) -> TokenStream {
-    let of_type = match NODE_ID_FOR_NAMED_NODE.lock().unwrap().get(struct_type) {
-        Some(id) => {
-            quote! {
-                fn contains(node: &auto_lsp::tree_sitter::Node) -> bool {
-                    matches!(node.kind_id(), #id)
-                }
-            }
-        }
-        None => {
-            if let Some(id) = NODE_ID_FOR_UNNAMED_NODE.lock().unwrap().get(struct_type) {
-                quote! {
-                    fn contains(node: &auto_lsp::tree_sitter::Node) -> bool {
-                        matches!(node.kind_id(), #id)
-                    }
-                }
-            } else {
-                quote! {
-                    fn contains(node: &auto_lsp::tree_sitter::Node) -> bool {
-                        matches!(node.kind(), #struct_type)
-                    }
-                }
-            }
-        }
-    };
-
-    let struct_fields = if struct_fields.is_empty() {
-        quote! { _range: auto_lsp::tree_sitter::Range, _id: usize, _parent: Option<usize>, _is_missing: bool, }
-    } else {
-        quote! {
-            #(#struct_fields),*,
-             _range: auto_lsp::tree_sitter::Range,
-            _id: usize,
-            _parent: Option<usize>,
-            _is_missing: bool
-        }
-    };
-
-    let struct_fields_finalize = if struct_fields_finalize.is_empty() {
-        quote! { Ok(Self { _range: node.range(), _id: id, _parent: parent_id, _is_missing: node.is_missing() }) }
-    } else {
-        quote! {
-           Ok(Self {
-                #(#struct_fields_finalize),*,
-                 _range: node.range(),
-                _id: id,
-                _parent: parent_id,
-                _is_missing: node.is_missing()
-            })
-        }
-    };
-
-    let init_builder = if struct_fields_collect.is_empty() {
-        quote! {}
-    } else {
-        quote! {
-          builder
-            .builder(db, &node, Some(id), |b| {
-                b #(.#struct_fields_collect)?*
+impl Generator {
+    pub(crate) fn generate_struct(
+        &mut self,
+        struct_name: &Ident,
+        struct_type: &String,
+        named: bool,
+        fields: &[FieldOrChildren],
+    ) -> Result<TokenStream, CodegenError> {
+        let ConfigTokens {
+            derives,
+            attributes,
+            visibility,
+            crate_path: core,
+        } = self.config_tokens.clone();
+
+        // Aliases created in the grammar are not part of the node types, so they are found in the language
+        // Kinds of the other namedness are only used when the language does not know the requested one
+        let ids = self
+            .registered_node_ids(struct_type, named)
+            .or_else(|_| self.node_ids(struct_type, named))
+            .or_else(|_| self.registered_node_ids(struct_type, !named))?;
+        let contains = quote! { [#(#ids),*] };
+
+        let kind_id = self.register_node_kind(struct_name, struct_type);
+        let field_types: Vec<_> = fields.iter().map(|f| f.field_type().clone()).collect();
+        let walk = self.generate_struct_walk(struct_name, &field_types);
+
+        if fields.is_empty() {
+            return Ok(quote! {
+                ast_leaf!(#struct_name, #struct_type, #kind_id, #named, #contains);
+
+                #walk
             });
         }
-    };
-
-    quote! {
-        #[derive(Debug, Clone, PartialEq)]
-        pub struct #struct_name {
-            #struct_fields
-        }
 
-        impl auto_lsp::core::ast::AstNode for #struct_name {
-            #of_type
-
-            fn lower(&self) -> &dyn auto_lsp::core::ast::AstNode {
-                self
-            }
-
-            fn get_id(&self) -> usize {
-                self._id
-            }
-
-            fn get_parent_id(&self) -> Option<usize> {
-                self._parent
+        let struct_fields: Vec<_> = fields.iter().map(|f| f.generate_field(&core)).collect();
+        let struct_fields_init: Vec<_> = fields.iter().map(|f| f.generate_field_init()).collect();
+        let struct_fields_collect: Vec<_> =
+            fields.iter().map(|f| f.generate_field_collect()).collect();
+        let struct_fields_finalize: Vec<_> = fields
+            .iter()
+            .map(|f| f.generate_field_finalize(&core))
+            .collect();
+        let struct_fields_serialize: Vec<_> = fields
+            .iter()
+            .map(|f| f.generate_field_serialize(&core))
+            .collect();
+        let macro_fields = fields.iter().filter_map(|f| f.generate_macro_field());
+        let macro_children = fields.iter().filter_map(|f| f.child_ident());
+        let macro_lenient = fields.iter().filter_map(|f| f.lenient_ident());
+
+        let accessors = generate_view_accessors(&core, &visibility, struct_name, fields);
+        let serialize = self.generate_struct_serialize(struct_name, &struct_fields_serialize);
+
+        Ok(quote! {
+            #[derive(Debug, Clone, PartialEq #(, #derives)*)]
+            #(#attributes)*
+            #visibility struct #struct_name {
+                #(#struct_fields),*,
+                /// Children that could not be built
+                pub errors: Vec<#core::ast::AstNodeId<#core::ast::ErrorNode>>,
+                _span: #core::ast::Span,
+                _id: usize,
+                _parent: Option<usize>,
+                _is_missing: bool
             }
 
-            fn get_range(&self) -> &auto_lsp::tree_sitter::Range {
-                &self._range
-            }
+            ast_node!(
+                #struct_name, #struct_type, #kind_id, #named, #contains,
+                fields: [#(#macro_fields),*],
+                children: [#(#macro_children,)* errors],
+                lenient: [#(#macro_lenient),*]
+            );
 
-            fn is_missing(&self) -> bool {
-                self._is_missing
+            impl<'a>
+                TryFrom<#core::ast::TryFromParams<'a>> for #struct_name {
+                type Error = #core::errors::AstError;
+
+                fn try_from((node, db, builder, id, parent_id): #core::ast::TryFromParams) -> Result<Self, #core::errors::AstError> {
+                    #(#struct_fields_init);*;
+                    let errors = builder
+                        .builder(db, &node, Some(id), |b| {
+                            b #(.#struct_fields_collect)?*
+                        });
+                    Ok(Self {
+                        #(#struct_fields_finalize),*,
+                        errors,
+                        _span: #core::ast::Span::from(node),
+                        _id: id,
+                        _parent: parent_id,
+                        _is_missing: node.is_missing()
+                    })
+                }
             }
-        }
 
-        impl<'a>
-            TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for #struct_name {
-            type Error = auto_lsp::core::errors::AstError;
+            #walk
 
-            fn try_from((node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams) -> Result<Self, auto_lsp::core::errors::AstError> {
-                #(#struct_fields_init);*;
-                #init_builder
-                #struct_fields_finalize
-            }
-        }
-    }
-}
+            #accessors
 
-pub(crate) fn generate_enum(variant_name: &Ident, variants: &Vec<TypeInfo>) -> TokenStream {
-    let super_types = SUPER_TYPES.read().unwrap();
-    let mut r_variants = vec![];
-    let mut r_types = vec![];
-
-    let mut super_types_variants: Vec<_> = vec![];
-    let mut super_types_types: Vec<Vec<_>> = vec![];
-
-    for value in variants {
-        let variant_name = format_ident!("{}", &sanitize_string_to_pascal(&value.kind));
-        if !value.named {
-            r_variants
-                .push(format_ident!("Token_{}", sanitize_string(&value.kind)).to_token_stream());
-
-            let type_name = if value.named {
-                *NODE_ID_FOR_NAMED_NODE
-                    .lock()
-                    .unwrap()
-                    .get(&value.kind)
-                    .unwrap()
-            } else {
-                *NODE_ID_FOR_UNNAMED_NODE
-                    .lock()
-                    .unwrap()
-                    .get(&value.kind)
-                    .unwrap()
-            };
-
-            r_types.push(type_name);
-        } else if let Some(supertype) = super_types.get(&value.kind) {
-            super_types_variants.push(variant_name.to_token_stream());
-            super_types_types.push(
-                supertype
-                    .types
-                    .iter()
-                    .map(|t| {
-                        if let Ok(named_nodes) = NODE_ID_FOR_NAMED_NODE.lock() {
-                            if let Some(node_id) = named_nodes.get(t) {
-                                return *node_id;
-                            }
-                        }
-
-                        if let Ok(unnamed_nodes) = NODE_ID_FOR_UNNAMED_NODE.lock() {
-                            if let Some(node_id) = unnamed_nodes.get(t) {
-                                return *node_id;
-                            }
-                        }
-
-                        panic!("Node ID not found for type: {}", t);
-                    })
-                    .collect(),
-            );
-        } else {
-            r_variants.push(variant_name.to_token_stream());
-            r_types.push(if value.named {
-                *NODE_ID_FOR_NAMED_NODE
-                    .lock()
-                    .unwrap()
-                    .get(&value.kind)
-                    .unwrap()
-            } else {
-                *NODE_ID_FOR_UNNAMED_NODE
-                    .lock()
-                    .unwrap()
-                    .get(&value.kind)
-                    .unwrap()
-            });
-        }
+            #serialize
+        })
     }
 
-    let pattern_matching = match (r_types.is_empty(), super_types_types.is_empty()) {
-        (false, false) => quote! {
-            #(#r_types => Ok(Self::#r_variants(#r_variants::try_from((node, db, builder, id, parent_id))?))),*,
-            /// Super types
-            #(#(#super_types_types)|* => Ok(Self::#super_types_variants(#super_types_variants::try_from((node, db, builder, id, parent_id))?))),*,
-            _ => Err(auto_lsp::core::errors::AstError::UnexpectedSymbol {
-                range: node.range(),
-                symbol: node.kind(),
-                parent_name: stringify!(#variant_name),
-            })
-        },
-        (true, false) => quote! {
-            /// Super types
-            #(#(#super_types_types)|* => Ok(Self::#super_types_variants(#super_types_variants::try_from((node, db, builder, id, parent_id))?))),*,
-            _ => Err(auto_lsp::core::errors::AstError::UnexpectedSymbol {
-                range: node.range(),
-                symbol: node.kind(),
-                parent_name: stringify!(#variant_name),
-            })
-        },
-        (false, true) => quote! {
-            #(#r_types => Ok(Self::#r_variants(#r_variants::try_from((node, db, builder, id, parent_id))?))),*,
-            _ => Err(auto_lsp::core::errors::AstError::UnexpectedSymbol {
-                range: node.range(),
-                symbol: node.kind(),
-                parent_name: stringify!(#variant_name),
-            })
-        },
-        _ => quote! {
-            _ => Err(auto_lsp::core::errors::AstError::UnexpectedSymbol {
-                range: node.range(),
-                symbol: node.kind(),
-                parent_name: stringify!(#variant_name),
-            })
-        },
-    };
-
-    r_variants.extend(super_types_variants);
-    r_types.extend(super_types_types.into_iter().flatten());
-
-    quote! {
-        #[derive(Debug, Clone, PartialEq)]
-        pub enum #variant_name {
-            #(#r_variants(#r_variants)),*
+    /// Generates an enum with a variant for each type
+    ///
+    /// `supertype` is the tree-sitter kind of the supertype the enum stands for, if any.
+    pub(crate) fn generate_enum(
+        &mut self,
+        variant_name: &Ident,
+        variants: &[TypeInfo],
+        supertype: Option<&str>,
+    ) -> Result<TokenStream, CodegenError> {
+        let ConfigTokens {
+            derives,
+            attributes,
+            visibility,
+            crate_path: core,
+        } = self.config_tokens.clone();
+        let super_types = &self.super_types;
+        let mut r_variants = vec![];
+        let mut r_types: Vec<Vec<_>> = vec![];
+
+        let mut super_types_variants: Vec<_> = vec![];
+        let mut super_types_types: Vec<Vec<_>> = vec![];
+
+        // Skipped rules are removed from the variants
+        let variants: Vec<_> = variants
+            .iter()
+            .filter(|value| !self.is_skipped(&value.kind))
+            .collect();
+        if variants.is_empty() {
+            return Err(CodegenError::EmptyEnum(variant_name.to_string()));
         }
 
-        impl auto_lsp::core::ast::AstNode for #variant_name {
-            fn contains(node: &auto_lsp::tree_sitter::Node) -> bool {
-                matches!(node.kind_id(), #(#r_types)|*)
-            }
+        for value in &variants {
+            let variant_name =
+                self.type_ident(&sanitize_string_to_pascal(&value.kind, &self.tokens));
+            if !value.named {
+                r_variants.push(
+                    self.type_ident(&format!(
+                        "Token_{}",
+                        sanitize_string(&value.kind, &self.tokens)
+                    ))
+                    .to_token_stream(),
+                );
 
-            fn lower(&self) -> &dyn auto_lsp::core::ast::AstNode {
-                match self {
-                    #(Self::#r_variants(node) => node.lower()),*
-                }
+                r_types.push(self.registered_node_ids(&value.kind, false)?);
+            } else if let Some(supertype) = super_types.get(&value.kind) {
+                super_types_variants.push(variant_name.to_token_stream());
+                super_types_types.push(
+                    supertype
+                        .types
+                        .iter()
+                        .filter(|t| !self.is_skipped(t))
+                        .map(|t| {
+                            self.registered_node_ids(t, true)
+                                .or_else(|_| self.registered_node_ids(t, false))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                );
+            } else {
+                r_variants.push(variant_name.to_token_stream());
+                r_types.push(self.registered_node_ids(&value.kind, value.named)?);
             }
+        }
 
-            fn get_id(&self) -> usize {
-                match self {
-                    #(Self::#r_variants(node) => node.get_id()),*
-                }
+        let expected = variants.iter().map(|value| &value.kind);
+        let unexpected = match supertype {
+            Some(supertype) => quote! {
+                _ => Err(#core::errors::AstError::InvalidSupertypeVariant {
+                    range: node.range(),
+                    supertype: #supertype,
+                    found: node.kind(),
+                    expected: &[#(#expected),*],
+                })
+            },
+            None => quote! {
+                _ => Err(#core::errors::AstError::UnexpectedChild {
+                    range: node.range(),
+                    parent: node.parent().map_or("", |parent| parent.kind()),
+                    found: node.kind(),
+                    expected: &[#(#expected),*],
+                })
+            },
+        };
+
+        let pattern_matching = match (r_types.is_empty(), super_types_types.is_empty()) {
+            (false, false) => quote! {
+                #(#(#r_types)|* => Ok(Self::#r_variants(#r_variants::try_from((node, db, builder, id, parent_id))?))),*,
+                /// Super types
+                #(#(#super_types_types)|* => Ok(Self::#super_types_variants(#super_types_variants::try_from((node, db, builder, id, parent_id))?))),*,
+                #unexpected
+            },
+            (true, false) => quote! {
+                /// Super types
+                #(#(#super_types_types)|* => Ok(Self::#super_types_variants(#super_types_variants::try_from((node, db, builder, id, parent_id))?))),*,
+                #unexpected
+            },
+            (false, true) => quote! {
+                #(#(#r_types)|* => Ok(Self::#r_variants(#r_variants::try_from((node, db, builder, id, parent_id))?))),*,
+                #unexpected
+            },
+            _ => quote! {
+                #unexpected
+            },
+        };
+
+        r_variants.extend(super_types_variants);
+        let r_types = r_types.into_iter().chain(super_types_types).flatten();
+
+        self.register_enum_variants(variant_name, &r_variants);
+        let walk = self.generate_enum_walk(variant_name, &r_variants);
+        Ok(quote! {
+            #[derive(Debug, Clone, PartialEq #(, #derives)*)]
+            #(#attributes)*
+            #visibility enum #variant_name {
+                #(#r_variants(#r_variants)),*
             }
 
-            fn get_parent_id(&self) -> Option<usize> {
-                match self {
-                    #(Self::#r_variants(node) => node.get_parent_id()),*
-                }
-            }
+            ast_enum!(#variant_name, [#(#r_variants),*], [#(#r_types),*]);
 
-            fn get_range(&self) -> &auto_lsp::tree_sitter::Range {
-                match self {
-                    #(Self::#r_variants(node) => node.get_range()),*
-                }
-            }
+           impl<'a>
+                TryFrom<#core::ast::TryFromParams<'a>> for #variant_name {
+                type Error = #core::errors::AstError;
 
-            fn is_missing(&self) -> bool {
-                match self {
-                    #(Self::#r_variants(node) => node.is_missing()),*
+                fn try_from((node, db, builder, id, parent_id): #core::ast::TryFromParams) -> Result<Self, #core::errors::AstError> {
+                    match node.kind_id() {
+                        #pattern_matching
+                    }
                 }
             }
-        }
 
-       impl<'a>
-            TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for #variant_name {
-            type Error = auto_lsp::core::errors::AstError;
-
-            fn try_from((node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams) -> Result<Self, auto_lsp::core::errors::AstError> {
-                match node.kind_id() {
-                    #pattern_matching
-                }
-            }
-        }
+            #walk
+        })
     }
-    .to_token_stream()
 }
@@ -0,0 +1,90 @@
+use crate::Generator;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+
+impl Generator {
+    /// Generates the `AstNode::serializable_fields` method, when the `serde` option is enabled
+    ///
+    /// Enums share the id of their variant, so they are serialized as their variant.
+    pub(crate) fn generate_serializable_fields(&self, delegate: bool) -> TokenStream {
+        if !self.options.serde {
+            return TokenStream::new();
+        }
+        let core = &self.config_tokens.crate_path;
+
+        let fields = match delegate {
+            true => quote! {
+                match self {
+                    $(Self::$variant(node) => node.serializable_fields()),+
+                }
+            },
+            false => quote! { Some(#core::ast::SerializeFields::fields(self)) },
+        };
+
+        quote! {
+            fn serializable_fields(&self) -> Option<Vec<(&'static str, #core::ast::FieldValue<'_>)>> {
+                #fields
+            }
+        }
+    }
+
+    /// Generates the `serde::Serialize` implementation of a type, when the `serde` option is enabled
+    ///
+    /// Nodes are serialized without their descendants by `serialize_node`, as `{id, kind, span, fields}`,
+    /// `fields` being a map of each field name to the IDs it points to.
+    pub(crate) fn generate_serialize(&self, name: &impl ToTokens) -> TokenStream {
+        if !self.options.serde {
+            return TokenStream::new();
+        }
+        let core = &self.config_tokens.crate_path;
+
+        quote! {
+            impl #core::serde::Serialize for #name {
+                fn serialize<S: #core::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    #core::ast::serialize_node(self, serializer)
+                }
+            }
+        }
+    }
+
+    /// Generates the `SerializeFields` and `Serialize` implementations of a struct, when the `serde` option is enabled
+    ///
+    /// The struct is serialized as `{kind, range, text, fields}` by `Serializable`,
+    /// `fields` being a map of each field name to the serialized nodes it points to,
+    /// plus the error nodes of the struct if any.
+    pub(crate) fn generate_struct_serialize(
+        &self,
+        struct_name: &impl ToTokens,
+        fields_serialize: &[TokenStream],
+    ) -> TokenStream {
+        if !self.options.serde {
+            return TokenStream::new();
+        }
+        let core = &self.config_tokens.crate_path;
+
+        // Error nodes are only serialized when there are some, leaves have none
+        let fields = if fields_serialize.is_empty() {
+            quote! { Vec::new() }
+        } else {
+            quote! {
+                let mut fields = vec![#(#fields_serialize),*];
+                if !self.errors.is_empty() {
+                    fields.push(("errors", #core::ast::NodeField::to_field_value(&self.errors)));
+                }
+                fields
+            }
+        };
+
+        let serialize = self.generate_serialize(struct_name);
+
+        quote! {
+            impl #core::ast::SerializeFields for #struct_name {
+                fn fields(&self) -> Vec<(&'static str, #core::ast::FieldValue<'_>)> {
+                    #fields
+                }
+            }
+
+            #serialize
+        }
+    }
+}
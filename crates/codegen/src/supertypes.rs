@@ -1,5 +1,5 @@
-use crate::json::TypeInfo;
 use crate::NodeType;
+use crate::json::TypeInfo;
 
 #[derive(Clone, Default)]
 pub(crate) struct SuperType {
@@ -0,0 +1,410 @@
+use crate::CodegenError;
+use std::collections::HashMap;
+
+/// Node kinds and fields of a language, used to resolve the ids hardcoded in the generated code
+///
+/// They are read either from a [`tree_sitter::Language`], or from the `parser.c` of a grammar
+/// when the language can not be loaded, such as in a proc macro.
+#[derive(Debug, Clone, Default)]
+pub struct LanguageSymbols {
+    /// Map of node kinds, with whether they are named, to their symbol ids
+    ///
+    /// The public id, returned by `ts_language_symbol_for_name`, comes first.
+    /// Aliases can give the same kind to several symbols, which keep their own id.
+    ids: HashMap<(String, bool), Vec<u16>>,
+    /// Map of field names to their id
+    fields: HashMap<String, u16>,
+}
+
+impl From<&tree_sitter::Language> for LanguageSymbols {
+    fn from(language: &tree_sitter::Language) -> Self {
+        let mut symbols = Self::default();
+        for id in 0..language.node_kind_count() as u16 {
+            let Some(kind) = language.node_kind_for_id(id) else {
+                continue;
+            };
+            // Supertypes are hidden, and reported as unnamed by `node_kind_is_named`
+            let supertype = language.node_kind_is_supertype(id);
+            let named = language.node_kind_is_named(id) || supertype;
+            if language.node_kind_is_visible(id) || supertype {
+                symbols.insert(kind.to_string(), named, id, || {
+                    language.id_for_node_kind(kind, named)
+                });
+            }
+        }
+        for id in 1..=language.field_count() as u16 {
+            if let Some(field) = language.field_name_for_id(id) {
+                symbols.fields.insert(field.to_string(), id);
+            }
+        }
+        symbols
+    }
+}
+
+impl LanguageSymbols {
+    /// Reads the symbols of a language from the `parser.c` generated by `tree-sitter generate`
+    ///
+    /// Mirrors `ts_language_symbol_for_name`: the first visible symbol (or supertype) of a kind is mapped to its public id.
+    pub fn from_parser(source: &str) -> Result<Self, CodegenError> {
+        // Aliases and builtin symbols are part of the same enum
+        let mut identifiers = HashMap::from([("ts_builtin_sym_end", 0u16)]);
+        for (name, value) in enum_entries(source, "enum ts_symbol_identifiers {")? {
+            identifiers.insert(name, parse_id(value)?);
+        }
+        let symbol = |name: &str| {
+            identifiers
+                .get(name)
+                .copied()
+                .ok_or_else(|| invalid(format!("unknown symbol {name}")))
+        };
+
+        let mut names = vec![];
+        for (key, value) in array_entries(source, "ts_symbol_names[] = {")? {
+            names.push((symbol(key)?, parse_string(&value)?));
+        }
+        let mut public_ids = HashMap::new();
+        for (key, value) in array_entries(source, "ts_symbol_map[] = {")? {
+            public_ids.insert(symbol(key)?, symbol(&value)?);
+        }
+        let mut metadata = HashMap::new();
+        for (key, value) in array_entries(source, "ts_symbol_metadata[] = {")? {
+            let flag = |flag: &str| value.contains(&format!(".{flag} = true"));
+            metadata.insert(
+                symbol(key)?,
+                (flag("visible") || flag("supertype"), flag("named")),
+            );
+        }
+
+        let mut symbols = Self::default();
+        names.sort_by_key(|(id, _)| *id);
+        for (id, kind) in names {
+            let Some(&(visible, named)) = metadata.get(&id) else {
+                return Err(invalid(format!("missing metadata of symbol {id}")));
+            };
+            if visible {
+                symbols.insert(kind, named, id, || {
+                    public_ids.get(&id).copied().unwrap_or(id)
+                });
+            }
+        }
+
+        // Grammars without fields have no field identifiers
+        if source.contains("enum ts_field_identifiers {") {
+            let mut field_ids = HashMap::new();
+            for (name, value) in enum_entries(source, "enum ts_field_identifiers {")? {
+                field_ids.insert(name, parse_id(value)?);
+            }
+            for (key, value) in array_entries(source, "ts_field_names[] = {")? {
+                if let Some(&id) = field_ids.get(key) {
+                    symbols.fields.insert(parse_string(&value)?, id);
+                }
+            }
+        }
+        Ok(symbols)
+    }
+
+    /// Registers the id of a symbol, `public_id` being called for the first symbol of the kind
+    fn insert(&mut self, kind: String, named: bool, id: u16, public_id: impl FnOnce() -> u16) {
+        let ids = self
+            .ids
+            .entry((kind, named))
+            .or_insert_with(|| vec![public_id()]);
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    /// Returns the public id of a node kind, or `0` if the kind is not part of the language
+    pub(crate) fn id_for_node_kind(&self, kind: &str, named: bool) -> u16 {
+        self.ids_for_node_kind(kind, named)
+            .first()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns all the ids of a node kind, starting with its public id
+    ///
+    /// Returns an empty slice if the kind is not part of the language.
+    pub(crate) fn ids_for_node_kind(&self, kind: &str, named: bool) -> &[u16] {
+        if named && kind == "ERROR" {
+            return &[u16::MAX];
+        }
+        self.ids
+            .get(&(kind.to_string(), named))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the id of a field, if it is part of the language
+    pub(crate) fn field_id_for_name(&self, field: &str) -> Option<u16> {
+        self.fields.get(field).copied()
+    }
+}
+
+fn invalid(error: String) -> CodegenError {
+    CodegenError::InvalidParser(error)
+}
+
+/// Returns the lines of a C block starting at `start` and ending with `};`
+fn block<'a>(source: &'a str, start: &str) -> Result<impl Iterator<Item = &'a str>, CodegenError> {
+    let begin = source
+        .find(start)
+        .ok_or_else(|| invalid(format!("missing {start}")))?;
+    Ok(source[begin + start.len()..]
+        .lines()
+        .skip(1)
+        .take_while(|line| !line.starts_with("};"))
+        .map(str::trim))
+}
+
+/// Returns the `name = value` entries of a C enum
+fn enum_entries<'a>(source: &'a str, start: &str) -> Result<Vec<(&'a str, &'a str)>, CodegenError> {
+    block(source, start)?
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.trim_end_matches(',')
+                .split_once(" = ")
+                .ok_or_else(|| invalid(format!("invalid enum entry {line}")))
+        })
+        .collect()
+}
+
+/// Returns the `[key] = value` entries of a C array, where values may span several lines
+fn array_entries<'a>(source: &'a str, start: &str) -> Result<Vec<(&'a str, String)>, CodegenError> {
+    let mut entries: Vec<(&str, String)> = vec![];
+    for line in block(source, start)? {
+        if let Some(entry) = line.strip_prefix('[') {
+            let (key, value) = entry
+                .split_once("] = ")
+                .ok_or_else(|| invalid(format!("invalid array entry {line}")))?;
+            entries.push((key, value.trim_end_matches(',').to_string()));
+        } else if let Some((_, value)) = entries.last_mut() {
+            value.push_str(line);
+        }
+    }
+    Ok(entries)
+}
+
+fn parse_id(value: &str) -> Result<u16, CodegenError> {
+    value
+        .parse()
+        .map_err(|_| invalid(format!("invalid symbol id {value}")))
+}
+
+/// Unescapes a C string literal, as written by `tree-sitter generate`
+fn parse_string(value: &str) -> Result<String, CodegenError> {
+    let content = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(|| invalid(format!("invalid string {value}")))?;
+    let mut result = String::new();
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        result.push(match chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('f') => '\x0c',
+            Some('v') => '\x0b',
+            Some('b') => '\x08',
+            Some('a') => '\x07',
+            Some('0') => '\0',
+            Some(c @ ('"' | '\\' | '?' | '\'')) => c,
+            _ => return Err(invalid(format!("invalid escape in {value}"))),
+        });
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARSER: &str = r#"
+enum ts_symbol_identifiers {
+  sym_identifier = 1,
+  anon_sym_DQUOTE = 2,
+  anon_sym_BSLASH = 3,
+  sym__expression = 4,
+  sym_call = 5,
+  sym_identifier2 = 6,
+  alias_sym_name = 7,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym_identifier] = "identifier",
+  [anon_sym_DQUOTE] = "\"",
+  [anon_sym_BSLASH] = "\\",
+  [sym__expression] = "_expression",
+  [sym_call] = "call",
+  [sym_identifier2] = "identifier",
+  [alias_sym_name] = "name",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym_identifier] = sym_identifier,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [anon_sym_BSLASH] = anon_sym_BSLASH,
+  [sym__expression] = sym__expression,
+  [sym_call] = sym_call,
+  [sym_identifier2] = sym_identifier,
+  [alias_sym_name] = alias_sym_name,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BSLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__expression] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_identifier2] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_name] = {
+    .visible = true,
+    .named = true,
+  },
+};
+
+enum ts_field_identifiers {
+  field_arguments = 1,
+  field_function = 2,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_arguments] = "arguments",
+  [field_function] = "function",
+};
+"#;
+
+    #[test]
+    fn parser_symbols() {
+        let symbols = LanguageSymbols::from_parser(PARSER).unwrap();
+
+        assert_eq!(symbols.id_for_node_kind("identifier", true), 1);
+        // Both symbols are named `identifier`
+        assert_eq!(symbols.ids_for_node_kind("identifier", true), [1, 6]);
+        assert_eq!(symbols.id_for_node_kind("\"", false), 2);
+        assert_eq!(symbols.id_for_node_kind("\\", false), 3);
+        // Supertypes are hidden but still have an id
+        assert_eq!(symbols.id_for_node_kind("_expression", true), 4);
+        assert_eq!(symbols.id_for_node_kind("name", true), 7);
+        assert_eq!(symbols.id_for_node_kind("ERROR", true), u16::MAX);
+        assert_eq!(symbols.id_for_node_kind("call", false), 0);
+        assert_eq!(symbols.id_for_node_kind("end", true), 0);
+
+        assert_eq!(symbols.field_id_for_name("arguments"), Some(1));
+        assert_eq!(symbols.field_id_for_name("function"), Some(2));
+        assert_eq!(symbols.field_id_for_name("body"), None);
+    }
+
+    #[test]
+    fn language_symbols() {
+        let language: tree_sitter::Language = tree_sitter_python::LANGUAGE.into();
+        let symbols = LanguageSymbols::from(&language);
+
+        for id in 0..language.node_kind_count() as u16 {
+            let kind = language.node_kind_for_id(id).unwrap();
+            let named = language.node_kind_is_named(id);
+            if language.node_kind_is_visible(id) {
+                assert_eq!(
+                    symbols.id_for_node_kind(kind, named),
+                    language.id_for_node_kind(kind, named)
+                );
+            }
+        }
+        // Supertypes are hidden but still have an id
+        assert_eq!(
+            symbols.id_for_node_kind("expression", true),
+            language.id_for_node_kind("expression", true)
+        );
+        assert_eq!(
+            symbols.field_id_for_name("body"),
+            language.field_id_for_name("body").map(|id| id.get())
+        );
+    }
+
+    /// Returns the `parser.c` of a dependency, found with `cargo metadata`
+    fn dependency_parser(package: &str) -> String {
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let output = std::process::Command::new(cargo)
+            .args(["metadata", "--format-version", "1"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .unwrap();
+        let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let manifest = metadata["packages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|dependency| dependency["name"] == package)
+            .and_then(|dependency| dependency["manifest_path"].as_str())
+            .unwrap();
+        let dir = std::path::Path::new(manifest).parent().unwrap();
+        std::fs::read_to_string(dir.join("src/parser.c")).unwrap()
+    }
+
+    /// Checks the symbols read from the `parser.c` of a grammar against the ones of the compiled language
+    fn assert_parser_matches(package: &str, language: tree_sitter::Language) {
+        let parser = LanguageSymbols::from_parser(&dependency_parser(package)).unwrap();
+        let symbols = LanguageSymbols::from(&language);
+
+        for id in 0..language.node_kind_count() as u16 {
+            let kind = language.node_kind_for_id(id).unwrap();
+            let named = language.node_kind_is_named(id);
+            if language.node_kind_is_visible(id) {
+                assert_eq!(
+                    parser.ids_for_node_kind(kind, named),
+                    symbols.ids_for_node_kind(kind, named),
+                    "{kind}"
+                );
+            }
+        }
+        for id in 1..=language.field_count() as u16 {
+            let field = language.field_name_for_id(id).unwrap();
+            assert_eq!(
+                parser.field_id_for_name(field),
+                symbols.field_id_for_name(field),
+                "{field}"
+            );
+        }
+    }
+
+    #[test]
+    fn python_parser_matches_language() {
+        assert_parser_matches("tree-sitter-python", tree_sitter_python::LANGUAGE.into());
+    }
+
+    #[test]
+    fn html_parser_matches_language() {
+        assert_parser_matches("tree-sitter-html", tree_sitter_html::LANGUAGE.into());
+    }
+}
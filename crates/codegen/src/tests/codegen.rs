@@ -4,7 +4,8 @@ fn gen_python() {
         tree_sitter_python::NODE_TYPES,
         &tree_sitter_python::LANGUAGE.into(),
         None,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -13,7 +14,8 @@ fn gen_html() {
         tree_sitter_html::NODE_TYPES,
         &tree_sitter_html::LANGUAGE.into(),
         None,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -22,7 +24,8 @@ fn gen_javascript() {
         tree_sitter_javascript::NODE_TYPES,
         &tree_sitter_javascript::LANGUAGE.into(),
         Some(std::collections::HashMap::from([("`", "Backtick")])),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -31,7 +34,8 @@ fn gen_c() {
         tree_sitter_c::NODE_TYPES,
         &tree_sitter_c::LANGUAGE.into(),
         Some(std::collections::HashMap::from([("\n", "Newline")])),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -40,7 +44,8 @@ fn gen_c_sharp() {
         tree_sitter_c_sharp::NODE_TYPES,
         &tree_sitter_c_sharp::LANGUAGE.into(),
         None,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -61,5 +66,218 @@ fn gen_haskell() {
             ("⟦", "LeftDoubleBracket"),
             ("⟧", "RightDoubleBracket"),
         ])),
+    )
+    .unwrap();
+}
+
+#[test]
+fn gen_multiple_grammars() {
+    let python = crate::generate(
+        tree_sitter_python::NODE_TYPES,
+        &tree_sitter_python::LANGUAGE.into(),
+        None,
+    )
+    .unwrap()
+    .to_string();
+    let _html = crate::generate(
+        tree_sitter_html::NODE_TYPES,
+        &tree_sitter_html::LANGUAGE.into(),
+        None,
+    )
+    .unwrap();
+
+    // Generating another grammar does not leak into the next one
+    let again = crate::generate(
+        tree_sitter_python::NODE_TYPES,
+        &tree_sitter_python::LANGUAGE.into(),
+        None,
+    )
+    .unwrap()
+    .to_string();
+    assert_eq!(python, again);
+}
+
+#[test]
+fn gen_invalid_json() {
+    let result = crate::generate("{", &tree_sitter_python::LANGUAGE.into(), None);
+    assert!(matches!(
+        result,
+        Err(crate::CodegenError::InvalidNodeTypes(_))
+    ));
+}
+
+#[test]
+fn gen_wrong_language() {
+    let result = crate::generate(
+        tree_sitter_python::NODE_TYPES,
+        &tree_sitter_html::LANGUAGE.into(),
+        None,
     );
+    assert!(matches!(
+        result,
+        Err(crate::CodegenError::UnknownNodeKind { .. } | crate::CodegenError::UnknownField(_))
+    ));
+}
+
+#[test]
+fn gen_with_config() {
+    let result = crate::Generator::new(&tree_sitter_python::LANGUAGE.into())
+        .with_config(
+            crate::CodegenConfig::new()
+                .rename("CompoundStatement_SimpleStatement", "Statement")
+                .skip_rule("print_statement")
+                .derive("Hash")
+                .attribute("#[non_exhaustive]")
+                .visibility("pub(crate)")
+                .crate_path("auto_lsp_core"),
+        )
+        .generate(tree_sitter_python::NODE_TYPES)
+        .unwrap()
+        .to_string();
+
+    assert!(result.contains("pub (crate) enum Statement {"));
+    assert!(result.contains("pub (crate) fn kind (& self) -> NodeKind"));
+    assert!(!result.contains("pub fn kind"));
+    assert!(result.contains("pub (crate) fn view < 'a >"));
+    assert!(!result.contains("pub fn view"));
+    assert!(!result.contains("CompoundStatement_SimpleStatement"));
+    assert!(result.contains("fn visit_statement"));
+    assert!(!result.contains("PrintStatement"));
+    assert!(result.contains("# [derive (Debug , Clone , PartialEq , Hash)] # [non_exhaustive]"));
+    assert!(result.contains("auto_lsp_core :: tree_sitter :: Node"));
+    assert!(!result.contains("auto_lsp ::"));
+}
+
+#[test]
+fn gen_invalid_config() {
+    let result = crate::Generator::new(&tree_sitter_python::LANGUAGE.into())
+        .with_config(crate::CodegenConfig::new().attribute("#[unclosed("))
+        .generate(tree_sitter_python::NODE_TYPES);
+    assert!(matches!(
+        result,
+        Err(crate::CodegenError::InvalidConfig {
+            option: "attribute",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn gen_skipped_variants() {
+    // Skipping all the subtypes of the `pattern` supertype leaves an empty enum
+    let config = [
+        "attribute",
+        "identifier",
+        "list_pattern",
+        "list_splat_pattern",
+        "subscript",
+        "tuple_pattern",
+    ]
+    .into_iter()
+    .fold(crate::CodegenConfig::new(), |config, kind| {
+        config.skip_rule(kind)
+    });
+    let result = crate::Generator::new(&tree_sitter_python::LANGUAGE.into())
+        .with_config(config)
+        .generate(tree_sitter_python::NODE_TYPES);
+    assert!(matches!(result, Err(crate::CodegenError::EmptyEnum(_))));
+}
+
+#[test]
+fn gen_invalid_grammar() {
+    let result = crate::Generator::new(&tree_sitter_python::LANGUAGE.into())
+        .with_grammar("{")
+        .generate(tree_sitter_python::NODE_TYPES);
+    assert!(matches!(
+        result,
+        Err(crate::CodegenError::InvalidGrammar(_))
+    ));
+}
+
+#[test]
+fn gen_modules() {
+    let result = crate::generate(
+        tree_sitter_python::NODE_TYPES,
+        &tree_sitter_python::LANGUAGE.into(),
+        None,
+    )
+    .unwrap()
+    .to_string();
+
+    for module in ["structs", "enums", "supertypes", "tokens", "operators"] {
+        assert!(result.contains(&format!("pub mod {module} {{ use super :: * ;")));
+        assert!(result.contains(&format!("pub use {module} :: * ;")));
+    }
+    // Structs without fields are generated by a single macro invocation
+    assert!(result.contains("ast_leaf ! (PassStatement , \"pass_statement\""));
+    assert!(result.contains("ast_node ! (FunctionDefinition , \"function_definition\""));
+}
+
+#[test]
+fn gen_module_files() {
+    let language = tree_sitter_python::LANGUAGE.into();
+    let files = crate::Generator::new(&language)
+        .generate_files(tree_sitter_python::NODE_TYPES)
+        .unwrap();
+    let root = files.root.to_string();
+
+    assert_eq!(files.modules.len(), crate::GeneratedFiles::MODULES.len());
+    for (module, code) in &files.modules {
+        assert!(root.contains(&format!("pub mod {module} ;")));
+        assert!(root.contains(&format!("pub use {module} :: * ;")));
+        assert!(code.to_string().starts_with("use super :: * ;"));
+    }
+    let (_, structs) = &files.modules[0];
+    assert!(
+        structs
+            .to_string()
+            .contains("ast_node ! (FunctionDefinition")
+    );
+    // Macros are defined in the root file, before the modules using them
+    assert!(root.contains("macro_rules ! ast_node"));
+    assert!(!root.contains("ast_node ! (FunctionDefinition"));
+}
+
+#[test]
+fn gen_serde() {
+    let generate = |serde| {
+        crate::Generator::new(&tree_sitter_python::LANGUAGE.into())
+            .with_options(crate::GenerateOptions {
+                serde,
+                ..Default::default()
+            })
+            .generate(tree_sitter_python::NODE_TYPES)
+            .unwrap()
+            .to_string()
+    };
+
+    // The feature only changes the default, the option can always be set explicitly
+    let serde = generate(true);
+    assert!(serde.contains("SerializeFields for FunctionDefinition"));
+    assert!(serde.contains("serde :: Serialize for FunctionDefinition"));
+    assert!(!generate(false).contains("SerializeFields"));
+    assert!(!generate(false).contains("Serialize for"));
+    assert_eq!(
+        crate::GenerateOptions::default().serde,
+        cfg!(feature = "serde")
+    );
+}
+
+#[test]
+fn gen_verify_aliases() {
+    let language: tree_sitter::Language = tree_sitter_python::LANGUAGE.into();
+    let result = crate::generate(tree_sitter_python::NODE_TYPES, &language, None)
+        .unwrap()
+        .to_string();
+
+    // `block` is also the alias of other rules, every id of the kind is checked
+    let ids: Vec<_> = (0..language.node_kind_count() as u16)
+        .filter(|&id| {
+            language.node_kind_for_id(id) == Some("block") && language.node_kind_is_named(id)
+        })
+        .collect();
+    assert!(ids.len() > 1);
+    for id in ids {
+        assert!(result.contains(&format!("(\"block\" , true , {id}u16)")));
+    }
 }
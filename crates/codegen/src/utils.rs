@@ -1,10 +1,7 @@
-use std::{
-    collections::HashMap,
-    sync::{LazyLock, RwLock},
-};
+use std::{collections::HashMap, sync::LazyLock};
 
-pub(crate) fn sanitize_string(string: &str) -> String {
-    if let Some(v) = TOKENS.read().unwrap().get(string) {
+pub(crate) fn sanitize_string(string: &str, tokens: &HashMap<String, String>) -> String {
+    if let Some(v) = tokens.get(string) {
         v.to_string()
     } else if let Some(v) = RUST_KEYWORDS.get(string) {
         v.to_string()
@@ -13,7 +10,7 @@ pub(crate) fn sanitize_string(string: &str) -> String {
         for c in string.chars() {
             if c == '_' {
                 result.push(c);
-            } else if let Some(v) = TOKENS.read().unwrap().get(c.to_string().as_str()) {
+            } else if let Some(v) = tokens.get(c.to_string().as_str()) {
                 result.push_str(v);
             } else {
                 result.push(c);
@@ -23,8 +20,8 @@ pub(crate) fn sanitize_string(string: &str) -> String {
     }
 }
 
-pub(crate) fn sanitize_string_to_pascal(string: &str) -> String {
-    if let Some(v) = TOKENS.read().unwrap().get(string) {
+pub(crate) fn sanitize_string_to_pascal(string: &str, tokens: &HashMap<String, String>) -> String {
+    if let Some(v) = tokens.get(string) {
         v.to_string()
     } else if let Some(v) = RUST_KEYWORDS.get(string) {
         v.to_string()
@@ -33,7 +30,7 @@ pub(crate) fn sanitize_string_to_pascal(string: &str) -> String {
         for c in string.chars() {
             if c == '_' {
                 result.push(c);
-            } else if let Some(v) = TOKENS.read().unwrap().get(c.to_string().as_str()) {
+            } else if let Some(v) = tokens.get(c.to_string().as_str()) {
                 result.push_str(v);
             } else {
                 result.push(c);
@@ -57,100 +54,121 @@ pub(crate) fn sanitize_string_to_pascal(string: &str) -> String {
     }
 }
 
-pub(crate) static TOKENS: LazyLock<RwLock<HashMap<&'static str, &'static str>>> =
-    LazyLock::new(|| {
-        RwLock::new(HashMap::from([
-            ("{", "LeftCurly"),
-            ("}", "RightCurly"),
-            ("(", "LeftParen"),
-            (")", "RightParen"),
-            ("[", "LeftBracket"),
-            ("]", "RightBracket"),
-            (",", "Comma"),
-            (":", "Colon"),
-            (";", "Semicolon"),
-            (".", "Dot"),
-            ("'", "Quote"),
-            ("\"", "DoubleQuote"),
-            ("@", "At"),
-            ("!", "Bang"),
-            ("#", "Hash"),
-            ("$", "Dollar"),
-            ("%", "Percent"),
-            ("^", "Caret"),
-            ("&", "Ampersand"),
-            ("*", "Star"),
-            ("-", "Minus"),
-            ("_", "Underscore"),
-            ("+", "Plus"),
-            ("=", "Equal"),
-            (">", "Greater"),
-            ("<", "Less"),
-            ("|", "Pipe"),
-            ("~", "Tilde"),
-            ("/", "Slash"),
-            ("\\", "Backslash"),
-            ("//", "SlashSlash"),
-            ("//=>", "SlashSlashEqual"),
-            ("//=", "SlashSlashEqual"),
-            ("/=", "SlashEqual"),
-            ("/>", "SlashGreater"),
-            ("/?", "SlashQuestion"),
-            ("/??", "SlashNullish"),
-            ("/*", "SlashStar"),
-            ("*/", "StarSlash"),
-            ("+++", "PlusPlusPlus"),
-            ("!!", "BangBang"),
-            ("!!=", "BangBangEqual"),
-            ("!!?", "BangBangQuestion"),
-            ("!!??", "BangBangNullish"),
-            ("!!???", "BangBangNullishQuestion"),
-            ("?", "Question"),
-            ("->", "Arrow"),
-            ("=>", "FatArrow"),
-            ("++", "PlusPlus"),
-            ("--", "MinusMinus"),
-            ("&&", "And"),
-            ("||", "Or"),
-            ("==", "EqualEqual"),
-            ("!=", "NotEqual"),
-            (">=", "GreaterEqual"),
-            ("<=", "LessEqual"),
-            ("===", "TripleEqual"),
-            ("!==", "NotTripleEqual"),
-            ("<<", "ShiftLeft"),
-            (">>", "ShiftRight"),
-            (">>>", "ShiftRightUnsigned"),
-            ("+=", "PlusEqual"),
-            ("-=", "MinusEqual"),
-            ("*=", "StarEqual"),
-            ("%=", "PercentEqual"),
-            ("&=", "AmpersandEqual"),
-            ("|=", "PipeEqual"),
-            ("^=", "CaretEqual"),
-            ("&&=", "AndEqual"),
-            ("||=", "OrEqual"),
-            ("??=", "NullishEqual"),
-            ("??", "Nullish"),
-            ("???", "NullishQuestion"),
-            ("**", "StarStar"),
-            ("**=", "StarStarEqual"),
-            ("<>", "LessGreater"),
-            ("<=>", "LessGreaterEqual"),
-            ("<!", "LessBang"),
-            ("</", "LessSlash"),
-            ("0", "Zero"),
-            ("1", "One"),
-            ("2", "Two"),
-            ("3", "Three"),
-            ("4", "Four"),
-            ("5", "Five"),
-            ("6", "Six"),
-            ("7", "Seven"),
-            ("8", "Eight"),
-            ("9", "Nine"),
-        ]))
-    });
+/// Converts a generated type name (e.g. `Token_LeftCurly`) to snake case (`token_left_curly`).
+pub(crate) fn pascal_to_snake(string: &str) -> String {
+    let mut result = String::new();
+    let mut previous: Option<char> = None;
+    for c in string.chars() {
+        if c.is_ascii_uppercase() {
+            if previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit()) {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+        previous = Some(c);
+    }
+    result
+}
+
+/// Default map of tokens to type names
+pub(crate) static TOKENS: LazyLock<HashMap<String, String>> = LazyLock::new(|| {
+    [
+        ("{", "LeftCurly"),
+        ("}", "RightCurly"),
+        ("(", "LeftParen"),
+        (")", "RightParen"),
+        ("[", "LeftBracket"),
+        ("]", "RightBracket"),
+        (",", "Comma"),
+        (":", "Colon"),
+        (";", "Semicolon"),
+        (".", "Dot"),
+        ("'", "Quote"),
+        ("\"", "DoubleQuote"),
+        ("@", "At"),
+        ("!", "Bang"),
+        ("#", "Hash"),
+        ("$", "Dollar"),
+        ("%", "Percent"),
+        ("^", "Caret"),
+        ("&", "Ampersand"),
+        ("*", "Star"),
+        ("-", "Minus"),
+        ("_", "Underscore"),
+        ("+", "Plus"),
+        ("=", "Equal"),
+        (">", "Greater"),
+        ("<", "Less"),
+        ("|", "Pipe"),
+        ("~", "Tilde"),
+        ("/", "Slash"),
+        ("\\", "Backslash"),
+        ("//", "SlashSlash"),
+        ("//=>", "SlashSlashEqual"),
+        ("//=", "SlashSlashEqual"),
+        ("/=", "SlashEqual"),
+        ("/>", "SlashGreater"),
+        ("/?", "SlashQuestion"),
+        ("/??", "SlashNullish"),
+        ("/*", "SlashStar"),
+        ("*/", "StarSlash"),
+        ("+++", "PlusPlusPlus"),
+        ("!!", "BangBang"),
+        ("!!=", "BangBangEqual"),
+        ("!!?", "BangBangQuestion"),
+        ("!!??", "BangBangNullish"),
+        ("!!???", "BangBangNullishQuestion"),
+        ("?", "Question"),
+        ("->", "Arrow"),
+        ("=>", "FatArrow"),
+        ("++", "PlusPlus"),
+        ("--", "MinusMinus"),
+        ("&&", "And"),
+        ("||", "Or"),
+        ("==", "EqualEqual"),
+        ("!=", "NotEqual"),
+        (">=", "GreaterEqual"),
+        ("<=", "LessEqual"),
+        ("===", "TripleEqual"),
+        ("!==", "NotTripleEqual"),
+        ("<<", "ShiftLeft"),
+        (">>", "ShiftRight"),
+        (">>>", "ShiftRightUnsigned"),
+        ("+=", "PlusEqual"),
+        ("-=", "MinusEqual"),
+        ("*=", "StarEqual"),
+        ("%=", "PercentEqual"),
+        ("&=", "AmpersandEqual"),
+        ("|=", "PipeEqual"),
+        ("^=", "CaretEqual"),
+        ("&&=", "AndEqual"),
+        ("||=", "OrEqual"),
+        ("??=", "NullishEqual"),
+        ("??", "Nullish"),
+        ("???", "NullishQuestion"),
+        ("**", "StarStar"),
+        ("**=", "StarStarEqual"),
+        ("<>", "LessGreater"),
+        ("<=>", "LessGreaterEqual"),
+        ("<!", "LessBang"),
+        ("</", "LessSlash"),
+        ("0", "Zero"),
+        ("1", "One"),
+        ("2", "Two"),
+        ("3", "Three"),
+        ("4", "Four"),
+        ("5", "Five"),
+        ("6", "Six"),
+        ("7", "Seven"),
+        ("8", "Eight"),
+        ("9", "Nine"),
+    ]
+    .into_iter()
+    .map(|(token, name)| (token.to_string(), name.to_string()))
+    .collect()
+});
 
 pub static RUST_KEYWORDS: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "abstract" => "Abstract",
@@ -219,58 +237,79 @@ mod tests {
 
     #[test]
     fn sanitize() {
-        assert_eq!(sanitize_string("fn"), "Fn");
+        assert_eq!(sanitize_string("fn", &TOKENS), "Fn");
         assert_eq!(
-            sanitize_string("namespace_0_async_$"),
+            sanitize_string("namespace_0_async_$", &TOKENS),
             "namespace_Zero_async_Dollar"
         );
-        assert_eq!(sanitize_string("let"), "Let");
-        assert_eq!(sanitize_string("match"), "Match");
+        assert_eq!(sanitize_string("let", &TOKENS), "Let");
+        assert_eq!(sanitize_string("match", &TOKENS), "Match");
         // Test special tokens
-        assert_eq!(sanitize_string("=>"), "FatArrow");
-        assert_eq!(sanitize_string("??"), "Nullish");
+        assert_eq!(sanitize_string("=>", &TOKENS), "FatArrow");
+        assert_eq!(sanitize_string("??", &TOKENS), "Nullish");
         // Test mixed content
         assert_eq!(
-            sanitize_string("if_=>_??"),
+            sanitize_string("if_=>_??", &TOKENS),
             "if_EqualGreater_QuestionQuestion"
         );
         // Test empty string
-        assert_eq!(sanitize_string(""), "");
+        assert_eq!(sanitize_string("", &TOKENS), "");
         // Test numbers
-        assert_eq!(sanitize_string("123"), "OneTwoThree");
+        assert_eq!(sanitize_string("123", &TOKENS), "OneTwoThree");
         // Test special characters
-        assert_eq!(sanitize_string("!@#"), "BangAtHash");
+        assert_eq!(sanitize_string("!@#", &TOKENS), "BangAtHash");
     }
 
     #[test]
     fn sanitize_to_pascal() {
-        assert_eq!(sanitize_string("fn"), "Fn");
+        assert_eq!(sanitize_string("fn", &TOKENS), "Fn");
         assert_eq!(
-            sanitize_string_to_pascal("namespace_0_async_$"),
+            sanitize_string_to_pascal("namespace_0_async_$", &TOKENS),
             "NamespaceZeroAsyncDollar"
         );
         // Test Rust keywords
-        assert_eq!(sanitize_string_to_pascal("let"), "Let");
-        assert_eq!(sanitize_string_to_pascal("match"), "Match");
+        assert_eq!(sanitize_string_to_pascal("let", &TOKENS), "Let");
+        assert_eq!(sanitize_string_to_pascal("match", &TOKENS), "Match");
         // Test special tokens
-        assert_eq!(sanitize_string_to_pascal("=>"), "FatArrow");
-        assert_eq!(sanitize_string_to_pascal("??"), "Nullish");
+        assert_eq!(sanitize_string_to_pascal("=>", &TOKENS), "FatArrow");
+        assert_eq!(sanitize_string_to_pascal("??", &TOKENS), "Nullish");
         // Test mixed content with underscores
         assert_eq!(
-            sanitize_string_to_pascal("if_=>_??"),
+            sanitize_string_to_pascal("if_=>_??", &TOKENS),
             "IfEqualGreaterQuestionQuestion"
         );
         // Test empty string
-        assert_eq!(sanitize_string_to_pascal(""), "");
+        assert_eq!(sanitize_string_to_pascal("", &TOKENS), "");
         // Test with spaces
-        assert_eq!(sanitize_string_to_pascal("hello world"), "HelloWorld");
+        assert_eq!(
+            sanitize_string_to_pascal("hello world", &TOKENS),
+            "HelloWorld"
+        );
         // Test with mixed case
-        assert_eq!(sanitize_string_to_pascal("hello_World"), "HelloWorld");
+        assert_eq!(
+            sanitize_string_to_pascal("hello_World", &TOKENS),
+            "HelloWorld"
+        );
         // Test with multiple underscores
-        assert_eq!(sanitize_string_to_pascal("hello__world"), "HelloWorld");
+        assert_eq!(
+            sanitize_string_to_pascal("hello__world", &TOKENS),
+            "HelloWorld"
+        );
 
         // Test numbers
-        assert_eq!(sanitize_string("0"), "Zero");
-        assert_eq!(sanitize_string("123"), "OneTwoThree");
+        assert_eq!(sanitize_string("0", &TOKENS), "Zero");
+        assert_eq!(sanitize_string("123", &TOKENS), "OneTwoThree");
+    }
+
+    #[test]
+    fn pascal_to_snake_case() {
+        assert_eq!(pascal_to_snake("Module"), "module");
+        assert_eq!(pascal_to_snake("FunctionDefinition"), "function_definition");
+        assert_eq!(pascal_to_snake("Token_LeftCurly"), "token_left_curly");
+        assert_eq!(pascal_to_snake("Operators_0"), "operators_0");
+        assert_eq!(
+            pascal_to_snake("SimpleStatement_CompoundStatement"),
+            "simple_statement_compound_statement"
+        );
     }
 }
@@ -0,0 +1,104 @@
+use crate::Generator;
+use crate::utils::pascal_to_snake;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+
+impl Generator {
+    /// Registers the variants of an enum, to generate its `is_<variant>` and `as_<variant>` methods
+    pub(crate) fn register_enum_variants(&mut self, enum_name: &Ident, variants: &[TokenStream]) {
+        self.enum_variants.insert(
+            enum_name.to_string(),
+            variants.iter().map(|v| v.to_string()).collect(),
+        );
+    }
+
+    /// Generates the `is_<variant>` and `as_<variant>` methods of all registered enums
+    ///
+    /// Variants that are enums themselves (usually supertypes) are flattened,
+    /// so the methods also cover the variants of nested enums.
+    pub(crate) fn generate_variant_helpers(&mut self) -> TokenStream {
+        let enums = std::mem::take(&mut self.enum_variants);
+        enums
+            .iter()
+            .map(|(enum_name, variants)| generate_helpers(&enums, enum_name, variants))
+            .collect()
+    }
+}
+
+fn generate_helpers(
+    enums: &BTreeMap<String, Vec<String>>,
+    enum_name: &str,
+    variants: &[String],
+) -> TokenStream {
+    let methods = reachable_types(enums, variants)
+        .into_iter()
+        .map(|(type_name, through)| {
+            let snake_name = pascal_to_snake(type_name);
+            let is_method = format_ident!("is_{}", snake_name);
+            let as_method = format_ident!("as_{}", snake_name);
+
+            let arms = through.iter().map(|variant| {
+                let variant_name = format_ident!("{}", variant);
+                match *variant == type_name {
+                    true => quote! { Self::#variant_name(node) => Some(node) },
+                    false => quote! { Self::#variant_name(node) => node.#as_method() },
+                }
+            });
+            let fallback = match through.len() < variants.len() {
+                true => quote! { _ => None },
+                false => quote! {},
+            };
+
+            // `is_missing` and `is_named` would shadow the `AstNode` methods
+            let is_fn = match is_method.to_string().as_str() {
+                "is_missing" | "is_named" => quote! {},
+                _ => quote! {
+                    pub fn #is_method(&self) -> bool {
+                        self.#as_method().is_some()
+                    }
+                },
+            };
+
+            let type_name = format_ident!("{}", type_name);
+            quote! {
+                #is_fn
+
+                pub fn #as_method(&self) -> Option<&#type_name> {
+                    match self {
+                        #(#arms,)*
+                        #fallback
+                    }
+                }
+            }
+        });
+
+    let enum_name = format_ident!("{}", enum_name);
+    quote! {
+        impl #enum_name {
+            #(#methods)*
+        }
+    }
+}
+
+/// Returns the types reachable from the variants of an enum, along with the variants they are reached through
+///
+/// A type may be reached through several variants when nested enums share it.
+fn reachable_types<'a>(
+    enums: &'a BTreeMap<String, Vec<String>>,
+    variants: &'a [String],
+) -> BTreeMap<&'a str, Vec<&'a str>> {
+    let mut types: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for variant in variants {
+        types.entry(variant).or_default().push(variant);
+        if let Some(nested_variants) = enums.get(variant) {
+            for nested_type in reachable_types(enums, nested_variants).into_keys() {
+                let through = types.entry(nested_type).or_default();
+                if !through.contains(&variant.as_str()) {
+                    through.push(variant);
+                }
+            }
+        }
+    }
+    types
+}
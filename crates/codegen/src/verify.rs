@@ -0,0 +1,46 @@
+use crate::Generator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+impl Generator {
+    /// Generates the `verify_language` function, which checks the ids hardcoded in the generated code
+    pub(crate) fn generate_verify_language(&self) -> TokenStream {
+        let core = &self.config_tokens.crate_path;
+        let visibility = &self.config_tokens.visibility;
+        // Every id of a kind is checked, aliases included.
+        // Supertypes are hidden from the parse tree, so the language does not report them as named,
+        // and those the language does not know have the id 0, which is not checked.
+        let named = self.node_id_for_named_node.iter().flat_map(|(kind, ids)| {
+            let named = !self.super_types.contains_key(kind);
+            ids.iter()
+                .filter(|id| **id != 0)
+                .map(move |id| (kind, named, id))
+        });
+        let unnamed = self
+            .node_id_for_unnamed_node
+            .iter()
+            .flat_map(|(kind, ids)| ids.iter().map(move |id| (kind, false, id)));
+        let node_kinds = named
+            .chain(unnamed)
+            .map(|(kind, named, id)| quote! { (#kind, #named, #id) });
+        let fields = self
+            .field_id_for_name
+            .iter()
+            .map(|(field, id)| quote! { (#field, #id) });
+
+        quote! {
+            /// Checks that the node kind and field ids of this AST match the given language.
+            ///
+            /// Fails when the AST was generated from another version of the grammar.
+            #visibility fn verify_language(
+                language: &#core::tree_sitter::Language,
+            ) -> Result<(), Vec<#core::errors::Mismatch>> {
+                #core::errors::Mismatch::check(
+                    language,
+                    &[#(#node_kinds),*],
+                    &[#(#fields),*],
+                )
+            }
+        }
+    }
+}
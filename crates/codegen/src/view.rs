@@ -0,0 +1,32 @@
+use crate::ir::FieldOrChildren;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Generates the field accessors of the `AstView` of a struct
+///
+/// `AstView` is defined in the core crate, so the accessors are generated as a `<Struct>View` trait.
+/// The `view` method of each type is generated by `ast_node!` and `ast_enum!`.
+pub(crate) fn generate_view_accessors(
+    core: &TokenStream,
+    visibility: &TokenStream,
+    type_name: &Ident,
+    fields: &[FieldOrChildren],
+) -> TokenStream {
+    let trait_name = format_ident!("{}View", type_name);
+    let (signatures, bodies): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .map(|f| f.generate_view_accessor(core))
+        .unzip();
+    let doc = format!(" Accessors of the fields of an `AstView<{type_name}>`");
+
+    quote! {
+        #[doc = #doc]
+        #visibility trait #trait_name<'a> {
+            #(#signatures;)*
+        }
+
+        impl<'a> #trait_name<'a> for #core::ast::AstView<'a, #type_name> {
+            #(#signatures { #bodies })*
+        }
+    }
+}
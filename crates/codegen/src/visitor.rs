@@ -0,0 +1,140 @@
+use crate::Generator;
+use crate::utils::pascal_to_snake;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+fn visit_method(type_name: &str) -> Ident {
+    format_ident!("visit_{}", pascal_to_snake(type_name))
+}
+
+fn walk_function(type_name: &str) -> Ident {
+    format_ident!("walk_{}", pascal_to_snake(type_name))
+}
+
+impl Generator {
+    /// Registers a type in the `Visitor` trait
+    fn register_visitor_type(&mut self, type_name: &Ident) {
+        self.visitor_types.push(type_name.to_string());
+    }
+
+    /// Generates the `walk_<node>` function of a struct
+    ///
+    /// Children are visited in source order, each one being dispatched to the `visit_<node>` method of its field type.
+    pub(crate) fn generate_struct_walk(
+        &mut self,
+        struct_name: &Ident,
+        field_types: &[TokenStream],
+    ) -> TokenStream {
+        self.register_visitor_type(struct_name);
+        let core = &self.config_tokens.crate_path;
+        let visibility = &self.config_tokens.visibility;
+        let walk = walk_function(&struct_name.to_string());
+
+        // Fields sharing the same type are dispatched once
+        let mut types: Vec<String> = field_types.iter().map(|t| t.to_string()).collect();
+        types.sort();
+        types.dedup();
+        // Children that could not be built
+        if !types.is_empty() {
+            types.push("ErrorNode".to_string());
+        }
+
+        let children = if types.is_empty() {
+            quote! {}
+        } else {
+            let field_types = types.iter().map(|t| format_ident!("{}", t));
+            let visit_methods = types.iter().map(|t| visit_method(t));
+            quote! {
+                for id in #core::ast::AstNode::get_children_ids(node) {
+                    let child = &nodes[id];
+                    #(if let Some(child) = child.downcast_ref::<#field_types>() {
+                        visitor.#visit_methods(nodes, child);
+                        continue;
+                    })*
+                }
+            }
+        };
+
+        quote! {
+            #visibility fn #walk<V: Visitor + ?Sized>(visitor: &mut V, nodes: &#core::ast::AstArena, node: &#struct_name) {
+                if visitor.enter(nodes, node).is_break() {
+                    return;
+                }
+                #children
+                visitor.leave(nodes, node);
+            }
+        }
+    }
+
+    /// Generates the `walk_<node>` function of an enum
+    ///
+    /// Enums share the id of their variant, so walking an enum only dispatches to the variant's `visit_<node>` method.
+    pub(crate) fn generate_enum_walk(
+        &mut self,
+        enum_name: &Ident,
+        variants: &[TokenStream],
+    ) -> TokenStream {
+        self.register_visitor_type(enum_name);
+        let core = &self.config_tokens.crate_path;
+        let visibility = &self.config_tokens.visibility;
+        let walk = walk_function(&enum_name.to_string());
+        let visit_methods = variants.iter().map(|v| visit_method(&v.to_string()));
+
+        quote! {
+            #visibility fn #walk<V: Visitor + ?Sized>(visitor: &mut V, nodes: &#core::ast::AstArena, node: &#enum_name) {
+                match node {
+                    #(#enum_name::#variants(node) => visitor.#visit_methods(nodes, node)),*
+                }
+            }
+        }
+    }
+
+    /// Generates the `Visitor` trait from all registered `visit_<node>` methods
+    pub(crate) fn generate_visitor(&mut self) -> TokenStream {
+        let types = std::mem::take(&mut self.visitor_types);
+        let core = &self.config_tokens.crate_path;
+        let visibility = &self.config_tokens.visibility;
+        let methods = types.iter().map(|type_name| {
+            let visit = visit_method(type_name);
+            let walk = walk_function(type_name);
+            let type_name = format_ident!("{}", type_name);
+            quote! {
+                fn #visit(&mut self, nodes: &#core::ast::AstArena, node: &#type_name) {
+                    #walk(self, nodes, node)
+                }
+            }
+        });
+
+        quote! {
+            #visibility fn walk_error_node<V: Visitor + ?Sized>(visitor: &mut V, nodes: &#core::ast::AstArena, node: &ErrorNode) {
+                if visitor.enter(nodes, node).is_break() {
+                    return;
+                }
+                visitor.leave(nodes, node);
+            }
+
+            /// A visitor over the typed AST.
+            ///
+            /// Each node type has a `visit_<node>` method which, by default, calls the matching `walk_<node>` function
+            /// to visit its children in source order.
+            ///
+            /// Override a `visit_<node>` method to act on a specific node kind, and call `walk_<node>` to keep descending.
+            ///
+            /// [`Visitor::enter`] and [`Visitor::leave`] are called around every struct node,
+            /// returning [`std::ops::ControlFlow::Break`] from `enter` skips the node's subtree (and its `leave` call).
+            #visibility trait Visitor {
+                fn enter(&mut self, nodes: &#core::ast::AstArena, node: &dyn #core::ast::AstNode) -> std::ops::ControlFlow<()> {
+                    std::ops::ControlFlow::Continue(())
+                }
+
+                fn leave(&mut self, nodes: &#core::ast::AstArena, node: &dyn #core::ast::AstNode) {}
+
+                #(#methods)*
+
+                fn visit_error_node(&mut self, nodes: &#core::ast::AstArena, node: &ErrorNode) {
+                    walk_error_node(self, nodes, node)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,509 @@
+use crate::ast::{AstNode, AstNodeId, Comment, Relocation, Span};
+use crate::errors::AstError;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Marks an ID whose node has not been stored yet.
+const VACANT: u32 = u32::MAX;
+
+/// Marks a node stored in the columns of the arena itself, and not in a shared arena.
+const OWNED: u32 = 0;
+
+/// Location of a node in the arena.
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    /// Arena storing the node, [`OWNED`] or 1 + the index of a shared arena
+    arena: u32,
+    /// Index of the column storing the node
+    column: u32,
+    /// Index of the node in its column
+    index: u32,
+}
+
+impl Slot {
+    fn is_vacant(&self) -> bool {
+        self.column == VACANT
+    }
+}
+
+/// A contiguous list of nodes of the same type.
+trait Column: Send + Sync {
+    fn get(&self, index: usize) -> &dyn AstNode;
+
+    /// Removes the last node of the column.
+    fn pop(&mut self);
+
+    /// Creates an empty column of the same type.
+    fn empty(&self) -> Box<dyn Column>;
+
+    /// Copies a node of this column at the end of `target`, returning its index.
+    ///
+    /// `target` must be a column of the same type.
+    fn copy_to(&self, index: usize, target: &mut dyn Column, relocation: &Relocation) -> usize;
+
+    /// Copies a node of this column at the end of `target` as is, returning its index.
+    ///
+    /// `target` must be a column of the same type.
+    fn clone_to(&self, index: usize, target: &mut dyn Column) -> usize;
+
+    fn len(&self) -> usize;
+
+    fn shrink_to_fit(&mut self);
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: AstNode + Clone> Column for Vec<T> {
+    fn get(&self, index: usize) -> &dyn AstNode {
+        &self[index]
+    }
+
+    fn pop(&mut self) {
+        Vec::pop(self);
+    }
+
+    fn empty(&self) -> Box<dyn Column> {
+        Box::new(Vec::<T>::new())
+    }
+
+    fn copy_to(&self, index: usize, target: &mut dyn Column, relocation: &Relocation) -> usize {
+        let target = target
+            .as_any_mut()
+            .downcast_mut::<Vec<T>>()
+            .expect("Columns should have the same type");
+        let mut node = self[index].clone();
+        node.relocate(relocation);
+        target.push(node);
+        target.len() - 1
+    }
+
+    fn clone_to(&self, index: usize, target: &mut dyn Column) -> usize {
+        let target = target
+            .as_any_mut()
+            .downcast_mut::<Vec<T>>()
+            .expect("Columns should have the same type");
+        target.push(self[index].clone());
+        target.len() - 1
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        Vec::shrink_to_fit(self);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Storage of the nodes of an AST.
+///
+/// Nodes are grouped by type, each type having its own contiguous column,
+/// so building an AST only allocates once per column growth instead of once per node.
+///
+/// Nodes are indexed by ID, see [`AstArena::get`] and the [`std::ops::Index`] implementation.
+///
+/// The arena also keeps the IDs of the nodes of each type, see [`AstArena::ids_of`].
+///
+/// When rebuilding an AST, the subtrees of the previous AST whose IDs and spans did not change
+/// are shared with the previous arena instead of being copied.
+#[derive(Default)]
+pub struct AstArena {
+    /// Location of each node, indexed by ID
+    slots: Vec<Slot>,
+    columns: Vec<Box<dyn Column>>,
+    /// Index of the column of each node type
+    types: HashMap<TypeId, u32>,
+    /// Sorted IDs of the nodes of each type, by stored type and innermost struct
+    ids_by_type: HashMap<TypeId, Vec<u32>>,
+    /// Comments found while building the nodes, in source order
+    comments: Vec<Comment>,
+    /// Errors accumulated while building the nodes, with the ID of the node whose children were being built
+    errors: Vec<(usize, AstError)>,
+    /// Previous arenas storing the shared nodes
+    shared: Vec<Arc<AstArena>>,
+    /// Number of nodes reused from the previous AST
+    reused: usize,
+}
+
+impl AstArena {
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns the node with the given ID.
+    ///
+    /// Returns `None` if the ID is out of bounds or if the node has not been stored yet.
+    pub fn get(&self, id: usize) -> Option<&dyn AstNode> {
+        let slot = self.slots.get(id)?;
+        match slot.is_vacant() {
+            true => None,
+            false => Some(self.column(*slot).get(slot.index as usize)),
+        }
+    }
+
+    /// Returns the number of nodes reused from the previous AST, shared or copied.
+    pub fn reused_len(&self) -> usize {
+        self.reused
+    }
+
+    /// Returns a typed [`AstNodeId`] to the node with the given ID.
+    ///
+    /// Returns `None` if the ID is out of bounds or if the node is not stored as a `T`.
+    pub fn typed_id<T: AstNode>(&self, id: usize) -> Option<AstNodeId<T>> {
+        match self.get(id)?.is::<T>() {
+            true => Some(AstNodeId::new(id)),
+            false => None,
+        }
+    }
+
+    /// Returns the IDs of the nodes of type `T`, sorted.
+    ///
+    /// Like [`AstArena::typed_id`], `T` is the type nodes are stored with,
+    /// but can also be the innermost struct returned by [`AstNode::lower`].
+    pub fn ids_of<T: AstNode>(&self) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator {
+        self.ids_by_type
+            .get(&TypeId::of::<T>())
+            .map_or(&[][..], |ids| ids.as_slice())
+            .iter()
+            .map(|id| *id as usize)
+    }
+
+    /// Returns the index of the first node for which `pred` is false, like [`slice::partition_point`].
+    ///
+    /// Nodes are sorted by ID, so a predicate on their start byte is always partitioned.
+    pub fn partition_point(&self, pred: impl Fn(&dyn AstNode) -> bool) -> usize {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = (low + high) / 2;
+            match pred(&self[mid]) {
+                true => low = mid + 1,
+                false => high = mid,
+            }
+        }
+        low
+    }
+
+    /// Returns the comments found while building the nodes, in source order.
+    ///
+    /// Comments are the named tree-sitter `extras` that are children of a built node.
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    pub(crate) fn push_comment(&mut self, comment: Comment) {
+        self.comments.push(comment);
+    }
+
+    /// Copies the comments of another arena located within the given span, moving them with the given [`Relocation`].
+    pub(crate) fn copy_comments(&mut self, other: &AstArena, span: Span, relocation: &Relocation) {
+        let start = other
+            .comments
+            .partition_point(|comment| comment.span.start_byte() < span.start_byte());
+        self.comments.extend(
+            other.comments[start..]
+                .iter()
+                .take_while(|comment| comment.span.end_byte() <= span.end_byte())
+                .map(|comment| Comment {
+                    span: relocation.span(comment.span),
+                    ..*comment
+                }),
+        );
+    }
+
+    /// Returns the errors accumulated while building the nodes, in the order they were accumulated.
+    ///
+    /// Lexer errors are not included, only the [`AstError`]s of the children that could not be built.
+    pub fn errors(&self) -> impl Iterator<Item = &AstError> {
+        self.errors.iter().map(|(_, error)| error)
+    }
+
+    /// Records an error accumulated while building the children of the node with the given ID.
+    pub(crate) fn push_error(&mut self, parent: usize, error: AstError) {
+        self.errors.push((parent, error));
+    }
+
+    /// Returns the errors recorded for the nodes within the given range of IDs, with the ID of their node.
+    pub(crate) fn errors_in(
+        &self,
+        ids: std::ops::Range<usize>,
+    ) -> impl Iterator<Item = (usize, &AstError)> {
+        self.errors
+            .iter()
+            .filter(move |(id, _)| ids.contains(id))
+            .map(|(id, error)| (*id, error))
+    }
+
+    /// Returns an iterator over the nodes, sorted by ID.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &dyn AstNode> + ExactSizeIterator {
+        (0..self.len()).map(|id| &self[id])
+    }
+
+    /// Stores a node with the given ID.
+    ///
+    /// Nodes can be inserted in any order, but every ID up to the last one must be filled
+    /// before reading the arena.
+    pub fn insert<T: AstNode + Clone>(&mut self, id: usize, node: T) {
+        let column = self.column_of(TypeId::of::<Vec<T>>(), || Box::new(Vec::<T>::new()));
+        let nodes = self.columns[column as usize]
+            .as_any_mut()
+            .downcast_mut::<Vec<T>>()
+            .expect("Column should match the node type");
+        nodes.push(node);
+        let index = nodes.len() as u32 - 1;
+        self.set_slot(
+            id,
+            Slot {
+                arena: OWNED,
+                column,
+                index,
+            },
+        );
+        self.index_type(id);
+    }
+
+    /// Copies a node of another arena, moving it with the given [`Relocation`].
+    pub(crate) fn insert_copy(&mut self, other: &AstArena, old_id: usize, relocation: &Relocation) {
+        let slot = other.slots[old_id];
+        let source = other.column(slot);
+        let column = self.column_of(source.as_any().type_id(), || source.empty());
+        let index = source.copy_to(
+            slot.index as usize,
+            self.columns[column as usize].as_mut(),
+            relocation,
+        );
+        let id = relocation.id(old_id);
+        self.set_slot(
+            id,
+            Slot {
+                arena: OWNED,
+                column,
+                index: index as u32,
+            },
+        );
+        self.index_type(id);
+        self.reused += 1;
+    }
+
+    /// Shares the nodes with the given IDs with another arena, without copying them.
+    ///
+    /// The nodes must keep their ID, parent and span in this arena.
+    pub(crate) fn insert_shared(&mut self, other: &Arc<AstArena>, ids: std::ops::Range<usize>) {
+        // Index in `self.shared` of each arena of `other`, `other` itself first
+        let mut arenas = vec![None; other.shared.len() + 1];
+        for id in ids {
+            let slot = other.slots[id];
+            if slot.is_vacant() {
+                continue;
+            }
+            let arena = match &mut arenas[slot.arena as usize] {
+                Some(arena) => *arena,
+                entry => {
+                    let source = match slot.arena {
+                        OWNED => other,
+                        arena => &other.shared[arena as usize - 1],
+                    };
+                    let arena = match self
+                        .shared
+                        .iter()
+                        .position(|shared| Arc::ptr_eq(shared, source))
+                    {
+                        Some(index) => index as u32 + 1,
+                        None => {
+                            self.shared.push(source.clone());
+                            self.shared.len() as u32
+                        }
+                    };
+                    *entry = Some(arena);
+                    arena
+                }
+            };
+            self.set_slot(id, Slot { arena, ..slot });
+            self.index_type(id);
+            self.reused += 1;
+        }
+    }
+
+    /// Removes the nodes whose ID is greater than or equal to `id`.
+    ///
+    /// These nodes must be the last ones stored in their column.
+    ///
+    /// The errors recorded for the removed nodes have already been accumulated,
+    /// so they are moved to `parent`, which keeps them if it is built.
+    pub(crate) fn truncate(&mut self, id: usize, parent: Option<usize>) {
+        if let Some(parent) = parent {
+            for (node, _) in self.errors.iter_mut().filter(|(node, _)| *node >= id) {
+                *node = parent;
+            }
+        }
+        if id >= self.slots.len() {
+            return;
+        }
+        for slot in self.slots.drain(id..) {
+            if !slot.is_vacant() && slot.arena == OWNED {
+                self.columns[slot.column as usize].pop();
+            }
+        }
+        for ids in self.ids_by_type.values_mut() {
+            ids.truncate(ids.partition_point(|other| (*other as usize) < id));
+        }
+    }
+
+    /// Copies the nodes of the shared arenas that are mostly unused, then releases the spare capacity of every column.
+    ///
+    /// A shared arena is kept alive by this arena, so it is only kept if at least half of its nodes are still used.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.unshare_sparse_arenas();
+        self.slots.shrink_to_fit();
+        self.columns
+            .iter_mut()
+            .for_each(|column| column.shrink_to_fit());
+        self.ids_by_type
+            .values_mut()
+            .for_each(|ids| ids.shrink_to_fit());
+    }
+
+    /// Copies the nodes of the shared arenas that less than half of their nodes are shared from.
+    fn unshare_sparse_arenas(&mut self) {
+        let mut used = vec![0; self.shared.len() + 1];
+        for slot in &self.slots {
+            used[slot.arena as usize] += 1;
+        }
+        // New index of each shared arena, `None` if its nodes are copied
+        let mut kept = 0;
+        let arenas: Vec<_> = self
+            .shared
+            .iter()
+            .enumerate()
+            .map(|(index, arena)| {
+                let len: usize = arena.columns.iter().map(|column| column.len()).sum();
+                (used[index + 1] * 2 >= len).then(|| {
+                    kept += 1;
+                    kept
+                })
+            })
+            .collect();
+        if arenas.iter().all(Option::is_some) {
+            return;
+        }
+
+        for id in 0..self.slots.len() {
+            let slot = self.slots[id];
+            if slot.arena == OWNED {
+                continue;
+            }
+            self.slots[id] = match arenas[slot.arena as usize - 1] {
+                Some(arena) => Slot { arena, ..slot },
+                None => {
+                    let source = self.column(slot);
+                    let source_type = source.as_any().type_id();
+                    let empty = source.empty();
+                    let column = self.column_of(source_type, || empty);
+                    let source =
+                        &self.shared[slot.arena as usize - 1].columns[slot.column as usize];
+                    let index = source
+                        .clone_to(slot.index as usize, self.columns[column as usize].as_mut());
+                    Slot {
+                        arena: OWNED,
+                        column,
+                        index: index as u32,
+                    }
+                }
+            };
+        }
+        let mut index = 0;
+        self.shared.retain(|_| {
+            index += 1;
+            arenas[index - 1].is_some()
+        });
+    }
+
+    /// Returns the column storing the node of a slot.
+    fn column(&self, slot: Slot) -> &dyn Column {
+        let columns = match slot.arena {
+            OWNED => &self.columns,
+            arena => &self.shared[arena as usize - 1].columns,
+        };
+        columns[slot.column as usize].as_ref()
+    }
+
+    fn column_of(&mut self, type_id: TypeId, empty: impl FnOnce() -> Box<dyn Column>) -> u32 {
+        let columns = &mut self.columns;
+        *self.types.entry(type_id).or_insert_with(|| {
+            columns.push(empty());
+            columns.len() as u32 - 1
+        })
+    }
+
+    /// Adds the node with the given ID to the IDs of its stored type and of its innermost struct.
+    fn index_type(&mut self, id: usize) {
+        let node = &self[id];
+        let stored = node.as_any().type_id();
+        let innermost = node.lower().as_any().type_id();
+        for type_id in [Some(stored), (innermost != stored).then_some(innermost)]
+            .into_iter()
+            .flatten()
+        {
+            // Children are stored before their parent, so IDs are not always stored in order
+            let ids = self.ids_by_type.entry(type_id).or_default();
+            let position = ids.partition_point(|other| (*other as usize) < id);
+            ids.insert(position, id as u32);
+        }
+    }
+
+    fn set_slot(&mut self, id: usize, slot: Slot) {
+        if id >= self.slots.len() {
+            self.slots.resize(
+                id + 1,
+                Slot {
+                    arena: OWNED,
+                    column: VACANT,
+                    index: VACANT,
+                },
+            );
+        }
+        self.slots[id] = slot;
+    }
+}
+
+impl std::ops::Index<usize> for AstArena {
+    type Output = dyn AstNode;
+
+    fn index(&self, id: usize) -> &Self::Output {
+        match self.get(id) {
+            Some(node) => node,
+            None => panic!("No node with id {id}"),
+        }
+    }
+}
+
+impl PartialEq for AstArena {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+            && self.comments == other.comments
+            && self.errors == other.errors
+    }
+}
+
+impl Eq for AstArena {}
+
+impl std::fmt::Debug for AstArena {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -1,8 +1,11 @@
-use crate::ast::AstNodeId;
+use crate::ast::incremental::PreviousAst;
+use crate::ast::{AstArena, AstNodeId, Comment, ErrorNode, Span};
+use crate::document::Document;
 use crate::errors::ParseErrorAccumulator;
 use crate::{ast::AstNode, errors::AstError};
 use salsa::Accumulator;
 use std::ops::ControlFlow;
+use std::sync::Arc;
 use tree_sitter::{Node, TreeCursor};
 
 /// Parameters for [`TryFrom`] implementations of AST nodes.
@@ -16,16 +19,37 @@ pub type TryFromParams<'from> = (
 
 /// A builder for creating AST nodes during the parsing process.
 ///
-/// This struct is responsible for assigning unique IDs to nodes and storing them.
-/// The ID counter is incremented every time a node is created, which may result in a gap
-/// between the number of created IDs and the final number of successfully built nodes.
+/// This struct is responsible for assigning unique IDs to nodes and storing them in an [`AstArena`].
+///
+/// IDs are assigned in pre-order: a node always gets its ID before its children,
+/// so the descendants of a node occupy a contiguous range of IDs right after it.
+///
+/// When a node fails to build, its ID and the nodes already built for its subtree are discarded,
+/// so the final list of IDs has no gaps.
+///
+/// A builder created with [`Builder::incremental`] reuses the subtrees of the previous AST
+/// that were not affected by the last edits, see [`Document::changed_ranges`].
 #[derive(Default)]
 pub struct Builder {
     id_ctr: usize,
-    nodes: Vec<Box<dyn AstNode>>,
+    nodes: AstArena,
+    previous: Option<PreviousAst>,
 }
 
 impl Builder {
+    /// Creates a builder that reuses the unchanged subtrees of the previous AST of the document.
+    ///
+    /// `previous` must be the nodes built before the last [`Document::update`].
+    ///
+    /// Reused subtrees are shared with the previous arena, or copied with their IDs and spans shifted to the new document,
+    /// and the errors accumulated while building them are accumulated again.
+    pub fn incremental(previous: Arc<AstArena>, document: &Document) -> Self {
+        Self {
+            previous: Some(PreviousAst::new(previous, document)),
+            ..Default::default()
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.nodes.len()
     }
@@ -34,7 +58,11 @@ impl Builder {
         self.nodes.is_empty()
     }
 
-    pub fn take_nodes(self) -> Vec<Box<dyn AstNode>> {
+    /// Returns the nodes built so far with the root node, which is built last,
+    /// and releases the spare capacity of the arena.
+    pub fn take_nodes<T: AstNode + Clone>(mut self, root: T) -> AstArena {
+        self.nodes.insert(0, root);
+        self.nodes.shrink_to_fit();
         self.nodes
     }
 
@@ -46,43 +74,110 @@ impl Builder {
     /// Creates a new AST node of type `T` from the current cursor position.
     ///
     /// The node is built using the [`AstNode`] try_from implementation for `T`.
-    fn create<'db, T: AstNode + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>>(
+    fn create<
+        'db,
+        T: AstNode + Clone + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
+    >(
         &mut self,
         db: &'db dyn salsa::Database,
         cursor: &TreeCursor,
         parent_id: Option<usize>,
     ) -> Result<AstNodeId<T>, AstError> {
         let node = cursor.node();
+        if let Some(id) = self.reuse::<T>(db, &node, parent_id) {
+            return Ok(id);
+        }
+
         // Gets the next ID for the new node
         let id = self.next_id();
-        let result = T::try_from((&node, db, self, id, parent_id)).map(Box::new)?;
-        // Stores the node
-        self.nodes.push(result);
-        Ok(AstNodeId::new(id))
+        match T::try_from((&node, db, self, id, parent_id)) {
+            Ok(result) => {
+                // Stores the node
+                self.nodes.insert(id, result);
+                Ok(AstNodeId::new(id))
+            }
+            Err(e) => {
+                // Drops the subtree built so far and releases its IDs
+                self.nodes.truncate(id, parent_id);
+                self.id_ctr = id - 1;
+                Err(e)
+            }
+        }
+    }
+
+    /// Reuses the subtree of a previous node matching `node`, if any.
+    ///
+    /// The subtree is shared with the previous AST when it keeps its IDs, parent and spans,
+    /// and copied otherwise.
+    ///
+    /// The errors recorded for the subtree are accumulated again, as if it had been built.
+    fn reuse<T: AstNode>(
+        &mut self,
+        db: &dyn salsa::Database,
+        node: &Node,
+        parent_id: Option<usize>,
+    ) -> Option<AstNodeId<T>> {
+        let previous = self.previous.as_ref()?;
+        let old_id = previous.find::<T>(node)?;
+
+        let new_id = self.id_ctr + 1;
+        let relocation = previous.relocation(old_id, new_id, parent_id);
+        let subtree = previous.subtree(old_id);
+        self.id_ctr += subtree.len();
+        self.nodes.copy_comments(
+            &previous.nodes,
+            previous.nodes[old_id].get_span(),
+            &relocation,
+        );
+        for (old_id, error) in previous.nodes.errors_in(subtree.clone()) {
+            let error = error.clone().with_range(relocation.range(error.range()));
+            ParseErrorAccumulator::accumulate(error.clone().into(), db);
+            self.nodes.push_error(relocation.id(old_id), error);
+        }
+        let root = &previous.nodes[old_id];
+        match relocation.is_identity(root.get_parent_id(), root.get_span()) {
+            true => self.nodes.insert_shared(&previous.nodes, subtree),
+            false => {
+                for old_id in subtree {
+                    self.nodes.insert_copy(&previous.nodes, old_id, &relocation);
+                }
+            }
+        }
+        Some(AstNodeId::new(new_id))
     }
 
     /// Starts a [`TreeWalk`] traversal using the given closure.
+    ///
+    /// Returns the [`ErrorNode`]s built for the children that could not be built, see [`TreeWalk`].
     pub fn builder<'cursor, F>(
         &'cursor mut self,
         db: &'cursor dyn salsa::Database,
         node: &'cursor Node<'cursor>,
         parent: Option<usize>,
         mut f: F,
-    ) where
+    ) -> Vec<AstNodeId<ErrorNode>>
+    where
         F: for<'cb> FnMut(
             &'cb mut TreeWalk<'cursor>,
         ) -> ControlFlow<(), &'cb mut TreeWalk<'cursor>>,
     {
-        TreeWalk::new(self, db, node, parent).walk(&mut f);
+        let mut walk = TreeWalk::new(self, db, node, parent);
+        walk.walk(&mut f);
+        walk.errors
     }
 }
 
 /// A struct that walks through the current tree and calls the provided closure while traversing the tree.
+///
+/// An [`ErrorNode`] is built in place of:
+/// - Each tree-sitter `ERROR` child not matched by the closure.
+/// - Each child of a vector or optional field that failed to build.
 pub struct TreeWalk<'cursor> {
     db: &'cursor dyn salsa::Database,
     builder: &'cursor mut Builder, // Builder instance
     cursor: TreeCursor<'cursor>,   // cursor (initialized at the beginning with the root node)
     parent: Option<usize>,
+    errors: Vec<AstNodeId<ErrorNode>>,
 }
 
 impl<'cursor> TreeWalk<'cursor> {
@@ -98,6 +193,7 @@ impl<'cursor> TreeWalk<'cursor> {
             db,
             cursor,
             parent,
+            errors: vec![],
         }
     }
 
@@ -110,19 +206,66 @@ impl<'cursor> TreeWalk<'cursor> {
         F: FnMut(&mut Self) -> ControlFlow<(), &mut Self>,
     {
         if self.cursor.goto_first_child() {
-            let _ = f(self);
+            self.record_comment();
+            if f(self).is_continue() {
+                self.record_syntax_error();
+            }
 
             while self.cursor.goto_next_sibling() {
-                let _ = f(self);
+                self.record_comment();
+                if f(self).is_continue() {
+                    self.record_syntax_error();
+                }
             }
         }
     }
+
+    /// Builds an [`ErrorNode`] if the current node is a tree-sitter `ERROR`.
+    ///
+    /// Syntax errors are already reported by the lexer, so nothing is accumulated.
+    fn record_syntax_error(&mut self) {
+        if self.cursor.node().is_error() {
+            self.record_error_node();
+        }
+    }
+
+    /// Builds an [`ErrorNode`] for the current node.
+    fn record_error_node(&mut self) {
+        if let Ok(id) = self.builder.create(self.db, &self.cursor, self.parent) {
+            self.errors.push(id);
+        }
+    }
+
+    /// Accumulates the error of a child that failed to build, and builds an [`ErrorNode`] in its place.
+    ///
+    /// The error is recorded for the parent, so it is accumulated again if the parent is reused.
+    fn recover(&mut self, error: AstError) {
+        ParseErrorAccumulator::accumulate(error.clone().into(), self.db);
+        if let Some(parent) = self.parent {
+            self.builder.nodes.push_error(parent, error);
+        }
+        self.record_error_node();
+    }
+
+    /// Records the current node in the [`AstArena`] comments if it is a named `extra`.
+    fn record_comment(&mut self) {
+        let node = self.cursor.node();
+        if node.is_extra() && node.is_named() {
+            self.builder.nodes.push_comment(Comment {
+                span: Span::from(&node),
+                kind: node.kind(),
+            });
+        }
+    }
     /// Attempts to create an AST node of type `T` if the current cursor points to a field with the given ID.
     ///
     /// If the field ID matches, the node is built and stored in `result`. Parsing then stops at this node.
     /// Returns [`ControlFlow::Break`] if a match is found and parsed, or `Continue` otherwise.
+    ///
+    /// Used for required fields, which can't hold an [`ErrorNode`]: if the node fails to build,
+    /// the error is stored in `result` and the parent fails as well.
     pub fn on_field_id<
-        T: AstNode + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
+        T: AstNode + Clone + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
         const FIELD_ID: u16,
     >(
         &mut self,
@@ -140,11 +283,35 @@ impl<'cursor> TreeWalk<'cursor> {
         ControlFlow::Continue(self)
     }
 
+    /// Like [`Self::on_field_id`], but for an optional field.
+    ///
+    /// If the node fails to build, the error is accumulated via [`ParseErrorAccumulator`],
+    /// the node is replaced by an [`ErrorNode`] and `result` is left to `None`.
+    pub fn on_optional_field_id<
+        T: AstNode + Clone + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
+        const FIELD_ID: u16,
+    >(
+        &mut self,
+        result: &mut Result<Option<AstNodeId<T>>, AstError>,
+    ) -> ControlFlow<(), &mut Self> {
+        if let Some(field) = self.cursor.field_id()
+            && field == std::num::NonZero::new(FIELD_ID).expect("FIELD_ID should be non-zero")
+        {
+            match self.builder.create(self.db, &self.cursor, self.parent) {
+                Ok(node) => *result = Ok(Some(node)),
+                Err(e) => self.recover(e),
+            };
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(self)
+    }
+
     /// Like [`Self::on_field_id`], but collects all matching nodes into a vector.
     ///
-    /// Parsing errors are accumulated into the database via [`ParseErrorAccumulator`] instead of propagating them.
+    /// Parsing errors are accumulated into the database via [`ParseErrorAccumulator`] instead of propagating them,
+    /// and the failed node is replaced by an [`ErrorNode`].
     pub fn on_vec_field_id<
-        T: AstNode + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
+        T: AstNode + Clone + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
         const FIELD_ID: u16,
     >(
         &mut self,
@@ -156,7 +323,7 @@ impl<'cursor> TreeWalk<'cursor> {
                     Ok(node) => result.push(node),
                     // Instead of propagating the error, we accumulate it in the database.
                     // This way we have a cheap "fault tolerant" parser.
-                    Err(e) => ParseErrorAccumulator::accumulate(e.into(), self.db),
+                    Err(e) => self.recover(e),
                 };
                 return ControlFlow::Break(());
             }
@@ -167,8 +334,10 @@ impl<'cursor> TreeWalk<'cursor> {
     /// Attempts to create an AST node of type `T` if the current cursor matches the `T::contains` predicate.
     ///
     /// Useful for children without a specific field ID. The result is stored in `result`.
+    ///
+    /// Like [`Self::on_field_id`], the error of a node that fails to build is stored in `result`.
     pub fn on_children_id<
-        T: AstNode + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
+        T: AstNode + Clone + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
     >(
         &mut self,
         result: &mut Result<Option<AstNodeId<T>>, AstError>,
@@ -184,11 +353,32 @@ impl<'cursor> TreeWalk<'cursor> {
         ControlFlow::Continue(self)
     }
 
+    /// Like [`Self::on_children_id`], but for optional children.
+    ///
+    /// If the node fails to build, the error is accumulated via [`ParseErrorAccumulator`],
+    /// the node is replaced by an [`ErrorNode`] and `result` is left to `None`.
+    pub fn on_optional_children_id<
+        T: AstNode + Clone + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
+    >(
+        &mut self,
+        result: &mut Result<Option<AstNodeId<T>>, AstError>,
+    ) -> ControlFlow<(), &mut Self> {
+        if T::contains(&self.cursor.node()) {
+            match self.builder.create(self.db, &self.cursor, self.parent) {
+                Ok(node) => *result = Ok(Some(node)),
+                Err(e) => self.recover(e),
+            };
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(self)
+    }
+
     /// Like [`Self::on_children_id`], but collects all matching nodes into a vector.
     ///
-    /// Errors are accumulated rather than returned, so the walk can continue through the tree.
+    /// Errors are accumulated rather than returned, so the walk can continue through the tree,
+    /// and the failed node is replaced by an [`ErrorNode`].
     pub fn on_vec_children_id<
-        T: AstNode + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
+        T: AstNode + Clone + for<'from> TryFrom<TryFromParams<'from>, Error = AstError>,
     >(
         &mut self,
         result: &mut Vec<AstNodeId<T>>,
@@ -198,7 +388,7 @@ impl<'cursor> TreeWalk<'cursor> {
                 Ok(node) => result.push(node),
                 // Instead of propagating the error, we accumulate it in the database.
                 // This way we have a cheap "fault tolerant" parser.
-                Err(e) => ParseErrorAccumulator::accumulate(e.into(), self.db),
+                Err(e) => self.recover(e),
             };
             return ControlFlow::Break(());
         }
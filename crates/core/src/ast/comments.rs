@@ -0,0 +1,163 @@
+use crate::ast::{AstArena, Span};
+use crate::document::Document;
+use crate::errors::PositionError;
+use std::collections::HashMap;
+
+/// A comment, or any other named tree-sitter `extra`, found while building the AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comment {
+    pub span: Span,
+    /// Tree-sitter kind of the comment node
+    pub kind: &'static str,
+}
+
+impl Comment {
+    /// Returns the UTF-8 text of this comment.
+    pub fn get_text<'a>(&self, source_code: &'a [u8]) -> Result<&'a str, PositionError> {
+        self.span.get_text(source_code)
+    }
+}
+
+/// Rules used to attach comments to nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentRules {
+    /// Tree-sitter kinds of the extras treated as comments
+    pub kinds: &'static [&'static str],
+    /// Maximum number of blank lines between a leading comment and the next comment or node
+    pub max_blank_lines: usize,
+    /// Attach a comment to the nodes ending right before it on the same line
+    pub trailing: bool,
+}
+
+impl Default for CommentRules {
+    fn default() -> Self {
+        Self {
+            kinds: &["comment"],
+            max_blank_lines: 0,
+            trailing: true,
+        }
+    }
+}
+
+/// Side table of the leading and trailing comments of the nodes of an AST.
+///
+/// Comments are attached to node boundaries rather than to IDs,
+/// so all the nodes starting (or ending) at the same byte share the same comments.
+///
+/// - A comment placed right after a node on the same line is a trailing comment of the nodes ending there.
+/// - Other comments are grouped with the comments that follow them,
+///   and the group becomes the leading comments of the nodes starting right after it.
+///
+/// Groups are broken by more than [`CommentRules::max_blank_lines`] blank lines, or by any other text.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Comments {
+    comments: Vec<Comment>,
+    /// Indexes of the leading comments of the nodes starting at a given byte
+    leading: HashMap<usize, std::ops::Range<usize>>,
+    /// Index of the trailing comment of the nodes ending at a given byte
+    trailing: HashMap<usize, usize>,
+}
+
+impl Comments {
+    /// Attaches the comments recorded in the arena to its nodes.
+    pub fn new(nodes: &AstArena, document: &Document, rules: &CommentRules) -> Self {
+        let comments: Vec<Comment> = nodes
+            .comments()
+            .iter()
+            .filter(|comment| rules.kinds.contains(&comment.kind))
+            .copied()
+            .collect();
+        if comments.is_empty() {
+            return Self::default();
+        }
+
+        // Node starts are sorted since IDs are assigned in pre-order, ends need to be sorted
+        let starts_with = |byte: usize| {
+            let id = nodes.partition_point(|node| node.get_span().start_byte() < byte);
+            nodes
+                .get(id)
+                .is_some_and(|node| node.get_span().start_byte() == byte)
+        };
+        let mut ends: Vec<usize> = nodes
+            .iter()
+            .map(|node| node.get_span().end_byte())
+            .collect();
+        ends.sort_unstable();
+        let ends_with = |byte: usize| ends.binary_search(&byte).is_ok();
+
+        let source = document.as_bytes();
+        let mut leading = HashMap::new();
+        let mut trailing = HashMap::new();
+        // First comment of the current leading group
+        let mut group_start: Option<usize> = None;
+
+        for (index, comment) in comments.iter().enumerate() {
+            let start = comment.span.start_byte();
+            if rules.trailing && group_start.is_none() {
+                let before = source[..start]
+                    .iter()
+                    .rposition(|byte| !matches!(byte, b' ' | b'\t'));
+                if let Some(before) = before
+                    && !matches!(source[before], b'\n' | b'\r')
+                    && ends_with(before + 1)
+                {
+                    trailing.insert(before + 1, index);
+                    continue;
+                }
+            }
+
+            let group = *group_start.get_or_insert(index);
+            let end = comment.span.end_byte();
+            let after = source[end..]
+                .iter()
+                .position(|byte| !byte.is_ascii_whitespace())
+                .map(|offset| end + offset);
+            let Some(after) = after else {
+                break;
+            };
+
+            let line_breaks = source[end..after].iter().filter(|b| **b == b'\n').count();
+            let next_comment = comments
+                .get(index + 1)
+                .is_some_and(|next| next.span.start_byte() == after);
+
+            if line_breaks.saturating_sub(1) > rules.max_blank_lines {
+                // Too far from what follows
+                group_start = None;
+            } else if next_comment {
+                // The group continues with the next comment
+            } else {
+                if starts_with(after) {
+                    leading.insert(after, group..index + 1);
+                }
+                group_start = None;
+            }
+        }
+
+        Self {
+            comments,
+            leading,
+            trailing,
+        }
+    }
+
+    /// Returns all the comments, in source order.
+    pub fn all(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// Returns the comments placed right before the given span.
+    pub fn leading(&self, span: Span) -> &[Comment] {
+        match self.leading.get(&span.start_byte()) {
+            Some(range) => &self.comments[range.clone()],
+            None => &[],
+        }
+    }
+
+    /// Returns the comment placed right after the given span, on the same line.
+    pub fn trailing(&self, span: Span) -> Option<&Comment> {
+        self.trailing
+            .get(&span.end_byte())
+            .map(|index| &self.comments[*index])
+    }
+}
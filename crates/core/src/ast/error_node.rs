@@ -0,0 +1,113 @@
+use crate::ast::{AstNode, ChildrenIds, Relocation, Span, TryFromParams};
+use crate::errors::AstError;
+use tree_sitter::Node;
+
+/// A node of the AST standing for a part of the source code that could not be built.
+///
+/// Error nodes are stored as children of the node being built, in place of:
+/// - The tree-sitter `ERROR` nodes, found where the grammar expects a child.
+/// - The children of a vector or optional field that failed to build, the error itself being reported
+///   through [`crate::errors::ParseErrorAccumulator`].
+///
+/// This way, the parent node is still built and the error node keeps the range of the broken code.
+///
+/// Required fields are typed, so they can't point to an error node: when a required child fails to build,
+/// its parent fails as well, up to the closest vector or optional field, which holds an error node for the failed node.
+/// Generating the AST with lenient fields avoids this, see `auto_lsp_codegen::GenerateOptions::lenient_fields`.
+///
+/// Generated structs list their error nodes in their `errors` field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorNode {
+    /// Tree-sitter kind of the node it stands for, `ERROR` for syntax errors
+    ///
+    /// [`AstNode::get_kind_name`] returns [`ErrorNode::KIND_NAME`] whatever this kind.
+    pub kind: &'static str,
+    /// Tree-sitter kinds of the children of the node
+    pub child_kinds: Vec<&'static str>,
+    _span: Span,
+    _id: usize,
+    _parent: Option<usize>,
+}
+
+impl ErrorNode {
+    /// Kind of every error node, see [`AstNode::get_kind_id`]
+    pub const KIND_ID: u16 = 0;
+
+    /// Kind name of every error node, see [`AstNode::get_kind_name`]
+    pub const KIND_NAME: &'static str = "ERROR";
+}
+
+impl AstNode for ErrorNode {
+    fn contains(node: &Node) -> bool {
+        node.is_error()
+    }
+
+    fn lower(&self) -> &dyn AstNode {
+        self
+    }
+
+    fn get_id(&self) -> usize {
+        self._id
+    }
+
+    fn get_parent_id(&self) -> Option<usize> {
+        self._parent
+    }
+
+    fn get_children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn get_span(&self) -> Span {
+        self._span
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        Self::KIND_NAME
+    }
+
+    fn get_kind_id(&self) -> u16 {
+        Self::KIND_ID
+    }
+
+    fn is_named(&self) -> bool {
+        true
+    }
+
+    fn get_field_name(&self, _id: usize) -> Option<&'static str> {
+        None
+    }
+
+    fn relocate(&mut self, relocation: &Relocation) {
+        self._parent = relocation.parent(self._id, self._parent);
+        self._id = relocation.id(self._id);
+        self._span = relocation.span(self._span);
+    }
+
+    fn is_missing(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "serde")]
+    fn serializable_fields(&self) -> Option<Vec<(&'static str, crate::ast::FieldValue<'_>)>> {
+        Some(crate::ast::SerializeFields::fields(self))
+    }
+}
+
+impl<'a> TryFrom<TryFromParams<'a>> for ErrorNode {
+    type Error = AstError;
+
+    fn try_from((node, _, _, id, parent_id): TryFromParams) -> Result<Self, AstError> {
+        let mut cursor = node.walk();
+        Ok(Self {
+            kind: node.kind(),
+            child_kinds: node
+                .children(&mut cursor)
+                .map(|child| child.kind())
+                .collect(),
+            _span: Span::from(node),
+            _id: id,
+            _parent: parent_id,
+        })
+    }
+}
@@ -0,0 +1,314 @@
+use crate::ast::{AstArena, AstNode, Span};
+use crate::document::Document;
+use std::any::TypeId;
+use std::sync::Arc;
+use tree_sitter::{InputEdit, Point, Range};
+
+/// Returns the exclusive end index of the subtree of each node.
+///
+/// IDs must be assigned in pre-order.
+pub fn subtree_ends(nodes: &AstArena) -> Vec<usize> {
+    // Children always have a greater id than their parent,
+    // so iterating backwards extends each parent with the already complete subtree of its children.
+    let mut subtree_ends: Vec<usize> = (1..=nodes.len()).collect();
+    for id in (0..nodes.len()).rev() {
+        if let Some(parent) = nodes[id].get_parent_id() {
+            subtree_ends[parent] = subtree_ends[parent].max(subtree_ends[id]);
+        }
+    }
+    subtree_ends
+}
+
+/// Describes how a subtree of a previous AST is moved into a new AST.
+///
+/// Passed to [`AstNode::relocate`] for every node of the subtree.
+pub struct Relocation<'a> {
+    /// ID of the subtree root in the previous AST
+    old_id: usize,
+    /// ID of the subtree root in the new AST
+    new_id: usize,
+    /// Parent of the subtree root in the new AST
+    parent: Option<usize>,
+    /// Edits applied to the document since the previous AST
+    edits: &'a [InputEdit],
+}
+
+impl Relocation<'_> {
+    /// Returns the new ID of a node of the subtree.
+    pub fn id(&self, id: usize) -> usize {
+        id - self.old_id + self.new_id
+    }
+
+    /// Returns the new parent ID of a node of the subtree, given its previous ID and parent ID.
+    pub fn parent(&self, id: usize, parent: Option<usize>) -> Option<usize> {
+        match id == self.old_id {
+            true => self.parent,
+            false => parent.map(|parent| self.id(parent)),
+        }
+    }
+
+    /// Returns `true` if the subtree keeps its IDs, parent and spans, in which case its nodes are unchanged.
+    pub(crate) fn is_identity(&self, root_parent: Option<usize>, span: Span) -> bool {
+        self.old_id == self.new_id
+            && self.parent == root_parent
+            && self
+                .edits
+                .iter()
+                .all(|edit| span.end_byte() < edit.start_byte)
+    }
+
+    /// Returns the span of a node of the subtree in the new document.
+    pub fn span(&self, span: Span) -> Span {
+        // Reused subtrees never overlap an edit
+        shift_span(span, self.edits).unwrap_or(span)
+    }
+
+    /// Returns the range of a node of the subtree in the new document.
+    pub fn range(&self, range: Range) -> Range {
+        let span = self.span(Span::new(range.start_byte, range.end_byte));
+        Range {
+            start_byte: span.start_byte(),
+            end_byte: span.end_byte(),
+            start_point: shift_point(range.start_point, range.start_byte, self.edits),
+            end_point: shift_point(range.end_point, range.end_byte, self.edits),
+        }
+    }
+}
+
+/// Applies the edits to the point of a byte that is not within one of the edits, in order.
+fn shift_point(mut point: Point, mut byte: usize, edits: &[InputEdit]) -> Point {
+    for edit in edits {
+        if byte < edit.old_end_byte {
+            continue;
+        }
+        point = match point.row == edit.old_end_position.row {
+            // Columns on the last line of the edit move with its end
+            true => Point::new(
+                edit.new_end_position.row,
+                point.column - edit.old_end_position.column + edit.new_end_position.column,
+            ),
+            false => Point::new(
+                point.row - edit.old_end_position.row + edit.new_end_position.row,
+                point.column,
+            ),
+        };
+        byte = byte - edit.old_end_byte + edit.new_end_byte;
+    }
+    point
+}
+
+/// Applies the edits to a span, in order.
+///
+/// Returns `None` if the span overlaps or touches one of the edits.
+pub(crate) fn shift_span(span: Span, edits: &[InputEdit]) -> Option<Span> {
+    let mut span = span;
+    for edit in edits {
+        if span.end_byte() < edit.start_byte {
+            continue;
+        }
+        if span.start_byte() <= edit.old_end_byte {
+            return None;
+        }
+        span = Span::new(
+            span.start_byte() - edit.old_end_byte + edit.new_end_byte,
+            span.end_byte() - edit.old_end_byte + edit.new_end_byte,
+        );
+    }
+    Some(span)
+}
+
+/// Reverts the edits applied to a span, in reverse order.
+///
+/// Returns `None` if the span overlaps or touches one of the edits,
+/// otherwise [`shift_span`] maps the returned span back to `span`.
+pub(crate) fn unshift_span(span: Span, edits: &[InputEdit]) -> Option<Span> {
+    let mut span = span;
+    for edit in edits.iter().rev() {
+        if span.end_byte() < edit.start_byte {
+            continue;
+        }
+        if span.start_byte() <= edit.new_end_byte {
+            return None;
+        }
+        span = Span::new(
+            span.start_byte() - edit.new_end_byte + edit.old_end_byte,
+            span.end_byte() - edit.new_end_byte + edit.old_end_byte,
+        );
+    }
+    Some(span)
+}
+
+/// The AST of the previous version of a document, from which the [`crate::ast::Builder`] reuses subtrees.
+pub(crate) struct PreviousAst {
+    pub(crate) nodes: Arc<AstArena>,
+    edits: Vec<InputEdit>,
+    changed_ranges: Vec<Range>,
+}
+
+impl PreviousAst {
+    pub(crate) fn new(nodes: Arc<AstArena>, document: &Document) -> Self {
+        Self {
+            nodes,
+            edits: document.edits.clone(),
+            changed_ranges: document.changed_ranges.clone(),
+        }
+    }
+
+    /// Finds the previous node of type `T` that can be reused for the given tree-sitter node.
+    ///
+    /// A node is reusable if its syntactic structure did not change and if its text was not edited.
+    ///
+    /// When several previous nodes of type `T` have the same span, none of them is reused.
+    pub(crate) fn find<T: AstNode>(&self, node: &tree_sitter::Node) -> Option<usize> {
+        if node.has_error() || node.is_missing() {
+            return None;
+        }
+        let (start, end) = (node.start_byte(), node.end_byte());
+        if self
+            .changed_ranges
+            .iter()
+            .any(|range| start <= range.end_byte && range.start_byte <= end)
+        {
+            return None;
+        }
+
+        // Nodes are sorted by start byte, so only the nodes starting at the same byte are compared
+        let span = unshift_span(Span::from(node), &self.edits)?;
+        let first = self
+            .nodes
+            .partition_point(|node| node.get_span().start_byte() < span.start_byte());
+        let mut found = None;
+        for id in first..self.nodes.len() {
+            let previous = &self.nodes[id];
+            if previous.get_span().start_byte() != span.start_byte() {
+                break;
+            }
+            if previous.get_span() == span && previous.as_any().type_id() == TypeId::of::<T>() {
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(id);
+            }
+        }
+        found
+    }
+
+    /// Returns the IDs of the subtree of the given previous node, the node itself included.
+    pub(crate) fn subtree(&self, id: usize) -> std::ops::Range<usize> {
+        // The descendants of a node follow it, and their parent is never before it
+        let end = (id + 1..self.nodes.len())
+            .find(|descendant| {
+                self.nodes[*descendant]
+                    .get_parent_id()
+                    .is_none_or(|parent| parent < id)
+            })
+            .unwrap_or(self.nodes.len());
+        id..end
+    }
+
+    pub(crate) fn relocation(
+        &self,
+        old_id: usize,
+        new_id: usize,
+        parent: Option<usize>,
+    ) -> Relocation<'_> {
+        Relocation {
+            old_id,
+            new_id,
+            parent,
+            edits: &self.edits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inserts `len` bytes at the given offset
+    fn insert(byte: usize, len: usize) -> InputEdit {
+        InputEdit {
+            start_byte: byte,
+            old_end_byte: byte,
+            new_end_byte: byte + len,
+            start_position: Point::default(),
+            old_end_position: Point::default(),
+            new_end_position: Point::default(),
+        }
+    }
+
+    #[test]
+    fn shift_before_edit() {
+        let span = Span::new(0, 3);
+        assert_eq!(shift_span(span, &[insert(10, 4)]), Some(span));
+    }
+
+    #[test]
+    fn shift_after_edit() {
+        assert_eq!(
+            shift_span(Span::new(12, 15), &[insert(10, 4)]),
+            Some(Span::new(16, 19))
+        );
+
+        // Deletions move spans backwards
+        let delete = InputEdit {
+            old_end_byte: 10,
+            new_end_byte: 5,
+            ..insert(5, 0)
+        };
+        assert_eq!(
+            shift_span(Span::new(12, 15), &[delete]),
+            Some(Span::new(7, 10))
+        );
+    }
+
+    #[test]
+    fn shift_overlapping_edit() {
+        // Spans overlapping or touching an edit can't be shifted
+        let edit = insert(10, 4);
+        assert_eq!(shift_span(Span::new(5, 12), &[edit]), None);
+        assert_eq!(shift_span(Span::new(5, 10), &[edit]), None);
+        assert_eq!(shift_span(Span::new(10, 12), &[edit]), None);
+    }
+
+    #[test]
+    fn shift_multiple_edits() {
+        // Edits are applied in order, each one on the result of the previous
+        assert_eq!(
+            shift_span(Span::new(20, 25), &[insert(10, 4), insert(0, 1)]),
+            Some(Span::new(25, 30))
+        );
+    }
+
+    #[test]
+    fn unshift_reverts_shift() {
+        let edits = [insert(10, 4), insert(0, 1)];
+        assert_eq!(
+            unshift_span(Span::new(25, 30), &edits),
+            Some(Span::new(20, 25))
+        );
+        assert_eq!(unshift_span(Span::new(3, 6), &edits), Some(Span::new(2, 5)));
+        // Spans overlapping or touching an edit have no previous span
+        assert_eq!(unshift_span(Span::new(12, 14), &edits), None);
+        assert_eq!(unshift_span(Span::new(0, 1), &edits), None);
+    }
+
+    #[test]
+    fn shift_point_after_edit() {
+        // "ab\ncd" becomes "ab\nxy\nzcd" by inserting "xy\nz" at byte 3
+        let edit = InputEdit {
+            start_byte: 3,
+            old_end_byte: 3,
+            new_end_byte: 7,
+            start_position: Point::new(1, 0),
+            old_end_position: Point::new(1, 0),
+            new_end_position: Point::new(2, 1),
+        };
+        // Same line as the end of the edit
+        assert_eq!(shift_point(Point::new(1, 1), 4, &[edit]), Point::new(2, 2));
+        // Following lines only move down
+        assert_eq!(shift_point(Point::new(3, 1), 10, &[edit]), Point::new(4, 1));
+        // Before the edit
+        assert_eq!(shift_point(Point::new(0, 1), 1, &[edit]), Point::new(0, 1));
+    }
+}
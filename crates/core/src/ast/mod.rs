@@ -1,5 +1,25 @@
+mod arena;
 mod builder;
+mod comments;
+mod error_node;
+mod incremental;
 mod node;
+mod printer;
+#[cfg(feature = "serde")]
+mod serialize;
+mod span_index;
+mod stable;
+mod view;
 
+pub use arena::AstArena;
 pub use builder::*;
+pub use comments::{Comment, CommentRules, Comments};
+pub use error_node::ErrorNode;
+pub use incremental::{Relocation, subtree_ends};
 pub use node::*;
+pub use printer::{AstPrinter, PrintFormat};
+#[cfg(feature = "serde")]
+pub use serialize::{FieldValue, Serializable, SerializeFields, serialize_node, to_json};
+pub use span_index::SpanIndex;
+pub use stable::{StableNodeId, stable_ids};
+pub use view::AstView;
@@ -1,18 +1,112 @@
-use crate::errors::DocumentError;
+use crate::ast::{AstArena, Relocation};
+use crate::errors::{DocumentError, Mismatch, MissingField};
 use crate::{document::Document, errors::PositionError};
 use downcast_rs::{DowncastSync, impl_downcast};
+use smallvec::SmallVec;
 use std::cmp::Ordering;
 use tree_sitter::Node;
 
+/// Byte span of a node in the source document.
+///
+/// Nodes only store their byte offsets, rows and columns are computed on demand by the [`Document`],
+/// see [`AstNode::get_range`].
+///
+/// Offsets are stored as `u32`, like tree-sitter does internally.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    start: u32,
+    end: u32,
+}
+
+impl Span {
+    pub fn new(start_byte: usize, end_byte: usize) -> Self {
+        Self {
+            start: start_byte as u32,
+            end: end_byte as u32,
+        }
+    }
+
+    pub fn start_byte(&self) -> usize {
+        self.start as usize
+    }
+
+    pub fn end_byte(&self) -> usize {
+        self.end as usize
+    }
+
+    /// Returns `true` if the given byte offset is within this span, bounds included.
+    pub fn contains(&self, byte: usize) -> bool {
+        self.start_byte() <= byte && byte <= self.end_byte()
+    }
+}
+
+impl Span {
+    /// Returns the UTF-8 text slice corresponding to this span.
+    pub fn get_text<'a>(&self, source_code: &'a [u8]) -> Result<&'a str, PositionError> {
+        let range: std::ops::Range<usize> = (*self).into();
+        match source_code.get(range.start..range.end) {
+            Some(text) => match std::str::from_utf8(text) {
+                Ok(text) => Ok(text),
+                Err(utf8_error) => Err(PositionError::UTF8Error { range, utf8_error }),
+            },
+            None => Err(PositionError::WrongTextRange { range }),
+        }
+    }
+}
+
+impl From<&Node<'_>> for Span {
+    fn from(node: &Node<'_>) -> Self {
+        Self::new(node.start_byte(), node.end_byte())
+    }
+}
+
+impl From<Span> for std::ops::Range<usize> {
+    fn from(span: Span) -> Self {
+        span.start_byte()..span.end_byte()
+    }
+}
+
 /// An ast node that uniquely identifies a node in the AST.
 ///
 /// It can be casted to the specific node type using the `cast` method.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// The traits are implemented regardless of `T`, since only the ID is stored.
 pub struct AstNodeId<T> {
     pub id: usize,
     _marker: std::marker::PhantomData<T>,
 }
 
+impl<T> std::fmt::Debug for AstNodeId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AstNodeId")
+            .field("id", &self.id)
+            .field("_marker", &self._marker)
+            .finish()
+    }
+}
+
+impl<T> Clone for AstNodeId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AstNodeId<T> {}
+
+impl<T> PartialEq for AstNodeId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T> Eq for AstNodeId<T> {}
+
+impl<T> std::hash::Hash for AstNodeId<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
 impl<'a, T: AstNode> AstNodeId<T> {
     pub(crate) fn new(id: usize) -> Self {
         Self {
@@ -21,8 +115,13 @@ impl<'a, T: AstNode> AstNodeId<T> {
         }
     }
 
-    pub fn cast(&self, nodes: &'a Vec<Box<dyn AstNode>>) -> &'a T {
-        debug_assert!(nodes.is_sorted());
+    /// Casts this id to its node in the given node list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id is out of bounds or if the node is not of type `T`,
+    /// see [`AstNodeId::try_cast`] for a non-panicking version.
+    pub fn cast(&self, nodes: &'a AstArena) -> &'a T {
         match nodes[self.id].downcast_ref::<T>() {
             Some(node) => node,
             None => panic!(
@@ -32,6 +131,121 @@ impl<'a, T: AstNode> AstNodeId<T> {
             ),
         }
     }
+
+    /// Casts this id to its node in the given node list.
+    ///
+    /// Returns `None` if the id is out of bounds or if the node is not of type `T`.
+    pub fn try_cast(&self, nodes: &'a AstArena) -> Option<&'a T> {
+        nodes.get(self.id)?.downcast_ref::<T>()
+    }
+}
+
+/// IDs of the direct children of a node, see [`AstNode::get_children_ids`].
+///
+/// Stored inline up to 8 children, so most nodes do not allocate.
+pub type ChildrenIds = SmallVec<[usize; 8]>;
+
+/// Field of a generated node, holding the ids of some of its children.
+///
+/// Implemented for every shape of generated field, so generated nodes collect and relocate
+/// the ids of their fields the same way whatever their shape.
+pub trait NodeField {
+    /// Pushes the ids held by this field.
+    fn push_ids(&self, ids: &mut ChildrenIds);
+
+    /// Returns `true` if this field holds the given id.
+    fn holds(&self, id: usize) -> bool;
+
+    /// Updates the ids held by this field after an incremental reparse.
+    fn relocate(&mut self, relocation: &Relocation);
+
+    /// Returns the ids held by this field, to serialize it.
+    #[cfg(feature = "serde")]
+    fn to_field_value(&self) -> crate::ast::FieldValue<'_>;
+}
+
+impl<T> NodeField for AstNodeId<T> {
+    fn push_ids(&self, ids: &mut ChildrenIds) {
+        ids.push(self.id);
+    }
+
+    fn holds(&self, id: usize) -> bool {
+        self.id == id
+    }
+
+    fn relocate(&mut self, relocation: &Relocation) {
+        self.id = relocation.id(self.id);
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_field_value(&self) -> crate::ast::FieldValue<'_> {
+        crate::ast::FieldValue::Node(self.id)
+    }
+}
+
+impl<T> NodeField for Option<AstNodeId<T>> {
+    fn push_ids(&self, ids: &mut ChildrenIds) {
+        ids.extend(self.map(|node| node.id));
+    }
+
+    fn holds(&self, id: usize) -> bool {
+        self.is_some_and(|node| node.id == id)
+    }
+
+    fn relocate(&mut self, relocation: &Relocation) {
+        if let Some(node) = self {
+            node.relocate(relocation);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_field_value(&self) -> crate::ast::FieldValue<'_> {
+        crate::ast::FieldValue::Optional(self.map(|node| node.id))
+    }
+}
+
+impl<T> NodeField for Vec<AstNodeId<T>> {
+    fn push_ids(&self, ids: &mut ChildrenIds) {
+        ids.extend(self.iter().map(|node| node.id));
+    }
+
+    fn holds(&self, id: usize) -> bool {
+        self.iter().any(|node| node.id == id)
+    }
+
+    fn relocate(&mut self, relocation: &Relocation) {
+        for node in self {
+            node.relocate(relocation);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_field_value(&self) -> crate::ast::FieldValue<'_> {
+        crate::ast::FieldValue::Nodes(self.iter().map(|node| node.id).collect())
+    }
+}
+
+/// Required field generated with the `lenient_fields` option of `auto_lsp_codegen`
+impl<T> NodeField for Result<AstNodeId<T>, MissingField> {
+    fn push_ids(&self, ids: &mut ChildrenIds) {
+        ids.extend(self.as_ref().map(|node| node.id));
+    }
+
+    fn holds(&self, id: usize) -> bool {
+        self.as_ref().is_ok_and(|node| node.id == id)
+    }
+
+    fn relocate(&mut self, relocation: &Relocation) {
+        if let Ok(node) = self {
+            node.relocate(relocation);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    // Missing fields are serialized as `null`
+    fn to_field_value(&self) -> crate::ast::FieldValue<'_> {
+        crate::ast::FieldValue::Optional(self.as_ref().ok().map(|node| node.id))
+    }
 }
 
 /// Trait representing an AST node.
@@ -41,6 +255,16 @@ pub trait AstNode: std::fmt::Debug + Send + Sync + DowncastSync {
     where
         Self: Sized;
 
+    /// Checks that the node kind and field ids of the generated AST match the given language.
+    ///
+    /// Generated types check every id of their AST, see [`crate::errors::Mismatch`].
+    fn verify_language(_language: &tree_sitter::Language) -> Result<(), Vec<Mismatch>>
+    where
+        Self: Sized,
+    {
+        Ok(())
+    }
+
     /// Returns the inner node as a trait object.
     ///
     /// If the node is a struct, returns self.    
@@ -54,12 +278,53 @@ pub trait AstNode: std::fmt::Debug + Send + Sync + DowncastSync {
     /// Returns the ID of the parent node, if any.
     fn get_parent_id(&self) -> Option<usize>;
 
+    /// Returns the IDs of the direct children of this node, in source order.
+    ///
+    /// Children are collected from every [`AstNodeId`] field of the node, without allocating for most nodes.
+    fn get_children_ids(&self) -> ChildrenIds;
+
+    /// Returns the byte [`Span`] of this node.
+    fn get_span(&self) -> Span;
+
+    /// Returns the tree-sitter kind of this node, such as `function_definition` or `+`.
+    ///
+    /// The kind of an [`crate::ast::ErrorNode`] is always [`crate::ast::ErrorNode::KIND_NAME`].
+    fn get_kind_name(&self) -> &'static str;
+
+    /// Returns the kind of this node as a number, unique per generated type of a language.
+    ///
+    /// Generated ASTs convert it to their `NodeKind` enum with `NodeKind::of`,
+    /// the kind of an [`crate::ast::ErrorNode`] is always [`crate::ast::ErrorNode::KIND_ID`].
+    fn get_kind_id(&self) -> u16;
+
+    /// Returns `true` if the tree-sitter kind of this node is named, `false` for anonymous tokens.
+    fn is_named(&self) -> bool;
+
+    /// Returns the name of the field of this node holding the child with the given ID.
+    ///
+    /// Returns `None` if the child is not in a named field.
+    fn get_field_name(&self, id: usize) -> Option<&'static str>;
+
+    /// Returns the name of the Rust type of this node.
+    fn get_type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Moves this node into a new AST, updating its ID, parent ID, range and the IDs of its children.
+    ///
+    /// Used by [`crate::ast::Builder`] to reuse unchanged subtrees of a previous AST.
+    fn relocate(&mut self, relocation: &Relocation);
+
     /// Returns the [`tree_sitter::Range`] of this node.
-    fn get_range(&self) -> &tree_sitter::Range;
+    ///
+    /// Points are computed from the node's [`Span`] using the line breaks of the document.
+    fn get_range(&self, document: &Document) -> tree_sitter::Range {
+        document.span_to_range(self.get_span())
+    }
 
     /// Returns the LSP-compatible range of this node.
     fn get_lsp_range(&self, document: &Document) -> Result<lsp_types::Range, DocumentError> {
-        document.denormalize_range(self.get_range())
+        document.denormalize_range(&self.get_range(document))
     }
 
     /// Returns `true` if this node is a MISSING node.
@@ -67,21 +332,29 @@ pub trait AstNode: std::fmt::Debug + Send + Sync + DowncastSync {
     /// Mirrors [is_missing](https://docs.rs/tree-sitter/latest/tree_sitter/struct.Node.html#method.is_missing)
     fn is_missing(&self) -> bool;
 
+    /// Returns `false` if a required field of this node could not be built.
+    ///
+    /// Always `true`, unless the node was generated with the `lenient_fields` option of `auto_lsp_codegen`,
+    /// where required fields hold a [`crate::errors::MissingField`] instead of dropping the node.
+    fn has_all_required_fields(&self) -> bool {
+        true
+    }
+
     /// Returns the start position in LSP format.
-    fn get_start_position(&self) -> lsp_types::Position {
-        let range = self.get_range();
+    fn get_start_position(&self, document: &Document) -> lsp_types::Position {
+        let point = document.byte_to_point(self.get_span().start_byte());
         lsp_types::Position {
-            line: range.start_point.row as u32,
-            character: range.start_point.column as u32,
+            line: point.row as u32,
+            character: point.column as u32,
         }
     }
 
     /// Returns the end position in LSP format.
-    fn get_end_position(&self) -> lsp_types::Position {
-        let range = self.get_range();
+    fn get_end_position(&self, document: &Document) -> lsp_types::Position {
+        let point = document.byte_to_point(self.get_span().end_byte());
         lsp_types::Position {
-            line: range.end_point.row as u32,
-            character: range.end_point.column as u32,
+            line: point.row as u32,
+            character: point.column as u32,
         }
     }
 
@@ -92,32 +365,20 @@ pub trait AstNode: std::fmt::Debug + Send + Sync + DowncastSync {
     /// - `Err(PositionError::WrongTextRange)` if the range is invalid
     /// - `Err(PositionError::UTF8Error)` if the byte slice is not valid UTF-8
     fn get_text<'a>(&self, source_code: &'a [u8]) -> Result<&'a str, PositionError> {
-        let range = self.get_range();
-        let range = range.start_byte..range.end_byte;
-        match source_code.get(range.start..range.end) {
-            Some(text) => match std::str::from_utf8(text) {
-                Ok(text) => Ok(text),
-                Err(utf8_error) => Err(PositionError::UTF8Error { range, utf8_error }),
-            },
-            None => Err(PositionError::WrongTextRange { range }),
-        }
+        self.get_span().get_text(source_code)
     }
 
     /// Retrieves the parent node, if present, from the node list.
+    fn get_parent<'a>(&'a self, nodes: &'a AstArena) -> Option<&'a dyn AstNode> {
+        nodes.get(self.get_parent_id()?)
+    }
+
+    /// Returns the fields of this node to serialize, see [`crate::ast::Serializable`].
     ///
-    /// The node list must be sorted by ID.
-    fn get_parent<'a>(&'a self, nodes: &'a [Box<dyn AstNode>]) -> Option<&'a Box<dyn AstNode>> {
-        match nodes.first() {
-            Some(first) => {
-                assert_eq!(
-                    first.get_id(),
-                    0,
-                    "get_parent called on an unsorted node list"
-                );
-                nodes.get(self.get_parent_id()?)
-            }
-            None => None,
-        }
+    /// Implemented by the nodes generated with the `serde` option of `auto_lsp_codegen`.
+    #[cfg(feature = "serde")]
+    fn serializable_fields(&self) -> Option<Vec<(&'static str, crate::ast::FieldValue<'_>)>> {
+        None
     }
 }
 
@@ -125,7 +386,7 @@ impl_downcast!(AstNode);
 
 impl PartialEq for dyn AstNode {
     fn eq(&self, other: &Self) -> bool {
-        self.get_range().eq(other.get_range()) && self.get_id().eq(&other.get_id())
+        self.get_span().eq(&other.get_span()) && self.get_id().eq(&other.get_id())
     }
 }
 
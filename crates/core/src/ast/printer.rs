@@ -0,0 +1,188 @@
+use crate::ast::{AstArena, AstNode};
+use crate::document::Document;
+use std::fmt::{self, Display, Formatter, Write};
+
+/// Maximum number of characters of the text snippet shown by [`PrintFormat::Pretty`] and [`PrintFormat::Dot`]
+const SNIPPET_LENGTH: usize = 40;
+
+/// Output format of an [`AstPrinter`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PrintFormat {
+    /// One node per line, indented by depth, with its field name, Rust type, range and a text snippet.
+    ///
+    /// ```text
+    /// Module 0:0..1:0 "a = 1"
+    ///   ExpressionStatement 0:0..0:5 "a = 1"
+    ///     Assignment 0:0..0:5 "a = 1"
+    ///       left: Identifier 0:0..0:1 "a"
+    ///       right: Integer 0:4..0:5 "1"
+    /// ```
+    #[default]
+    Pretty,
+    /// S-expression of the named nodes, in the format of tree-sitter's `Node::to_sexp`.
+    ///
+    /// ```text
+    /// (module (expression_statement (assignment left: (identifier) right: (integer))))
+    /// ```
+    SExpression,
+    /// Graphviz DOT graph, with one box per node and one edge per child labeled with its field name.
+    Dot,
+}
+
+/// Prints the nodes of an [`AstArena`], starting from the root node.
+///
+/// Ranges are printed as `row:column..row:column`, rows and columns starting at 0.
+///
+/// ```rust, ignore
+/// println!("{}", AstPrinter::new(&nodes, &document).format(PrintFormat::SExpression));
+/// ```
+pub struct AstPrinter<'a> {
+    nodes: &'a AstArena,
+    document: &'a Document,
+    format: PrintFormat,
+}
+
+impl<'a> AstPrinter<'a> {
+    pub fn new(nodes: &'a AstArena, document: &'a Document) -> Self {
+        Self {
+            nodes,
+            document,
+            format: PrintFormat::default(),
+        }
+    }
+
+    /// Sets the output format, [`PrintFormat::Pretty`] by default.
+    pub fn format(mut self, format: PrintFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    fn write_pretty(
+        &self,
+        f: &mut Formatter<'_>,
+        node: &dyn AstNode,
+        field: Option<&str>,
+        depth: usize,
+    ) -> fmt::Result {
+        write!(f, "{:indent$}", "", indent = depth * 2)?;
+        if let Some(field) = field {
+            write!(f, "{field}: ")?;
+        }
+        write!(f, "{} {}", type_name(node), self.range(node))?;
+        if node.is_missing() {
+            write!(f, " MISSING")?;
+        }
+        writeln!(f, " {:?}", self.snippet(node))?;
+
+        for id in node.get_children_ids() {
+            self.write_pretty(f, &self.nodes[id], node.get_field_name(id), depth + 1)?;
+        }
+        Ok(())
+    }
+
+    fn write_sexp(
+        &self,
+        f: &mut Formatter<'_>,
+        node: &dyn AstNode,
+        field: Option<&str>,
+        is_root: bool,
+    ) -> fmt::Result {
+        // Anonymous tokens are hidden, unless missing
+        let is_visible = node.is_named() || node.is_missing();
+        if is_visible {
+            if !is_root {
+                f.write_char(' ')?;
+            }
+            if let Some(field) = field {
+                write!(f, "{field}: ")?;
+            }
+            match (node.is_missing(), node.is_named()) {
+                (true, true) => write!(f, "(MISSING {}", node.get_kind_name())?,
+                (true, false) => write!(f, "(MISSING {:?}", node.get_kind_name())?,
+                (false, _) => write!(f, "({}", node.get_kind_name())?,
+            }
+        }
+
+        for id in node.get_children_ids() {
+            self.write_sexp(f, &self.nodes[id], node.get_field_name(id), false)?;
+        }
+
+        if is_visible {
+            f.write_char(')')?;
+        }
+        Ok(())
+    }
+
+    fn write_dot(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph ast {{")?;
+        writeln!(f, "  node [shape=box];")?;
+        for node in self.nodes.iter() {
+            let label = format!(
+                "{}\n{}\n{:?}",
+                type_name(node),
+                self.range(node),
+                self.snippet(node)
+            );
+            writeln!(f, "  n{} [label=\"{}\"];", node.get_id(), escape(&label))?;
+        }
+        for node in self.nodes.iter() {
+            for id in node.get_children_ids() {
+                write!(f, "  n{} -> n{id}", node.get_id())?;
+                match node.get_field_name(id) {
+                    Some(field) => writeln!(f, " [label=\"{field}\"];")?,
+                    None => writeln!(f, ";")?,
+                }
+            }
+        }
+        writeln!(f, "}}")
+    }
+
+    fn range(&self, node: &dyn AstNode) -> String {
+        let range = node.get_range(self.document);
+        format!(
+            "{}:{}..{}:{}",
+            range.start_point.row,
+            range.start_point.column,
+            range.end_point.row,
+            range.end_point.column
+        )
+    }
+
+    /// Returns the first line of the text of a node, truncated to [`SNIPPET_LENGTH`] characters.
+    fn snippet(&self, node: &dyn AstNode) -> String {
+        let text = node.get_text(self.document.as_bytes()).unwrap_or_default();
+        let line = text.lines().next().unwrap_or_default();
+        match line.char_indices().nth(SNIPPET_LENGTH) {
+            Some((end, _)) => format!("{}...", &line[..end]),
+            None if line.len() < text.len() => format!("{line}..."),
+            None => line.to_string(),
+        }
+    }
+}
+
+impl Display for AstPrinter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some(root) = self.nodes.get(0) else {
+            return Ok(());
+        };
+        match self.format {
+            PrintFormat::Pretty => self.write_pretty(f, root, None, 0),
+            PrintFormat::SExpression => self.write_sexp(f, root, None, true),
+            PrintFormat::Dot => self.write_dot(f),
+        }
+    }
+}
+
+/// Returns the name of the innermost Rust type of a node, without its module path.
+fn type_name(node: &dyn AstNode) -> &'static str {
+    let name = node.lower().get_type_name();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Escapes a DOT label.
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,220 @@
+use crate::ast::{AstArena, AstNode, ErrorNode, Span};
+use crate::document::Document;
+use serde::ser::{Error, SerializeMap, SerializeStruct};
+use serde::{Serialize, Serializer};
+
+/// Value of a field of a node, as listed by [`SerializeFields`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue<'a> {
+    /// A node, serialized as the node it points to
+    Node(usize),
+    /// An optional node, serialized as `null` when absent
+    Optional(Option<usize>),
+    /// A list of nodes
+    Nodes(Vec<usize>),
+    /// A list of node kinds
+    Kinds(&'a [&'static str]),
+}
+
+/// Serializes the IDs of the nodes the field points to, or the kinds it lists.
+impl Serialize for FieldValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            FieldValue::Node(id) => id.serialize(serializer),
+            FieldValue::Optional(id) => id.serialize(serializer),
+            FieldValue::Nodes(ids) => ids.serialize(serializer),
+            FieldValue::Kinds(kinds) => kinds.serialize(serializer),
+        }
+    }
+}
+
+/// Lists the fields of a node, so it can be serialized by [`Serializable`].
+///
+/// Implemented by the structs generated with the `serde` option of `auto_lsp_codegen`.
+pub trait SerializeFields {
+    /// Returns the name and the value of each field of the node.
+    fn fields(&self) -> Vec<(&'static str, FieldValue<'_>)>;
+}
+
+/// A node along with the arena and the document of its AST, to serialize it with its descendants.
+///
+/// Nodes point to their children by ID, so the arena and the document are passed down to every child.
+///
+/// Each node is serialized as `{kind, range, text, fields}`,
+/// where `text` is only present for nodes without children and `fields` contains the children of the node.
+#[derive(Clone, Copy)]
+pub struct Serializable<'a> {
+    node: &'a dyn AstNode,
+    nodes: &'a AstArena,
+    document: &'a Document,
+}
+
+impl<'a> Serializable<'a> {
+    /// Returns the node with the given ID, or `None` if it is not in the arena.
+    pub fn new(nodes: &'a AstArena, id: usize, document: &'a Document) -> Option<Self> {
+        Some(Self {
+            node: nodes.get(id)?,
+            nodes,
+            document,
+        })
+    }
+
+    /// Returns the child of this node with the given ID.
+    fn child<E: Error>(&self, id: usize) -> Result<Self, E> {
+        Self::new(self.nodes, id, self.document)
+            .ok_or_else(|| E::custom(format!("No node with id {id}")))
+    }
+}
+
+/// Serializes a node without its descendants, as `{id, kind, span, fields}`,
+/// where `fields` maps each field name to the IDs of the nodes it points to.
+///
+/// Used by the `Serialize` implementations generated with the `serde` option of `auto_lsp_codegen`,
+/// see [`Serializable`] to serialize a node along with its descendants.
+pub fn serialize_node<S: Serializer>(node: &dyn AstNode, serializer: S) -> Result<S::Ok, S::Error> {
+    let fields = node.serializable_fields().ok_or_else(|| {
+        S::Error::custom(format!(
+            "{} does not implement SerializeFields",
+            std::any::type_name_of_val(node)
+        ))
+    })?;
+
+    let mut state = serializer.serialize_struct("AstNode", 4)?;
+    state.serialize_field("id", &node.get_id())?;
+    state.serialize_field("kind", node.get_kind_name())?;
+    state.serialize_field("span", &node.get_span())?;
+    state.serialize_field("fields", &FieldIds(fields))?;
+    state.end()
+}
+
+/// Fields of a node, serialized as a map of each field name to the IDs it points to
+struct FieldIds<'a>(Vec<(&'static str, FieldValue<'a>)>);
+
+impl Serialize for FieldIds<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(name, value)| (name, value)))
+    }
+}
+
+/// Serializes the node with the given ID and all its descendants to JSON.
+///
+/// Returns `null` if there is no node with the given ID, see [`Serializable`].
+///
+/// The node types must be generated with the `serde` option of `auto_lsp_codegen`.
+pub fn to_json(
+    nodes: &AstArena,
+    id: usize,
+    document: &Document,
+) -> serde_json::Result<serde_json::Value> {
+    match Serializable::new(nodes, id, document) {
+        Some(node) => serde_json::to_value(node),
+        None => Ok(serde_json::Value::Null),
+    }
+}
+
+impl Serialize for Serializable<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let node = self.node;
+        let fields = node.serializable_fields().ok_or_else(|| {
+            S::Error::custom(format!(
+                "{} does not implement SerializeFields",
+                std::any::type_name_of_val(node)
+            ))
+        })?;
+        let range = node.get_range(self.document);
+        let text = match node.get_children_ids().is_empty() {
+            true => node
+                .get_text(self.document.as_bytes())
+                .ok()
+                .map(str::to_string),
+            false => None,
+        };
+
+        let mut state = serializer.serialize_struct("AstNode", 4)?;
+        state.serialize_field("kind", node.get_kind_name())?;
+        state.serialize_field(
+            "range",
+            &Range {
+                start: Point::from(range.start_point),
+                end: Point::from(range.end_point),
+            },
+        )?;
+        match text {
+            Some(text) => state.serialize_field("text", &text)?,
+            None => state.skip_field("text")?,
+        }
+        state.serialize_field("fields", &Fields { node: self, fields })?;
+        state.end()
+    }
+}
+
+/// Fields of a node, serialized as a map of each field name to its value
+struct Fields<'a> {
+    node: &'a Serializable<'a>,
+    fields: Vec<(&'static str, FieldValue<'a>)>,
+}
+
+impl Serialize for Fields<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for (name, value) in &self.fields {
+            match value {
+                FieldValue::Node(id) => map.serialize_entry(name, &self.node.child(*id)?)?,
+                FieldValue::Optional(id) => {
+                    map.serialize_entry(name, &id.map(|id| self.node.child(id)).transpose()?)?
+                }
+                FieldValue::Nodes(ids) => map.serialize_entry(
+                    name,
+                    &ids.iter()
+                        .map(|id| self.node.child(*id))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )?,
+                FieldValue::Kinds(kinds) => map.serialize_entry(name, kinds)?,
+            }
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Span {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Span", 2)?;
+        state.serialize_field("start", &self.start_byte())?;
+        state.serialize_field("end", &self.end_byte())?;
+        state.end()
+    }
+}
+
+#[derive(Serialize)]
+struct Range {
+    start: Point,
+    end: Point,
+}
+
+#[derive(Serialize)]
+struct Point {
+    row: usize,
+    column: usize,
+}
+
+impl From<tree_sitter::Point> for Point {
+    fn from(point: tree_sitter::Point) -> Self {
+        Self {
+            row: point.row,
+            column: point.column,
+        }
+    }
+}
+
+/// Serializes the kinds of the children of the error node as its only field.
+impl SerializeFields for ErrorNode {
+    fn fields(&self) -> Vec<(&'static str, FieldValue<'_>)> {
+        vec![("child_kinds", FieldValue::Kinds(&self.child_kinds))]
+    }
+}
+
+impl Serialize for ErrorNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_node(self, serializer)
+    }
+}
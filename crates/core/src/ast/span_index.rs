@@ -0,0 +1,157 @@
+use crate::ast::{AstArena, Span};
+use std::ops::Range;
+
+/// Index of the byte spans of the nodes of an AST, answering position and range queries
+/// without visiting every node.
+///
+/// Since ids are assigned in pre-order, the nodes are sorted by start byte and their spans are nested:
+/// - The nodes starting within a range have contiguous ids, found by binary search.
+/// - The nodes containing a byte are the ancestors of the last node starting before it.
+///
+/// Queries run in `O(log n + depth + k)`, `k` being the number of nodes returned.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SpanIndex {
+    spans: Vec<Span>,
+    parents: Vec<Option<usize>>,
+}
+
+impl SpanIndex {
+    pub fn new(nodes: &AstArena) -> Self {
+        Self {
+            spans: nodes.iter().map(|node| node.get_span()).collect(),
+            parents: nodes.iter().map(|node| node.get_parent_id()).collect(),
+        }
+    }
+
+    /// Returns the id of the deepest node containing the given byte, bounds included.
+    pub fn descendant_at(&self, byte: usize) -> Option<usize> {
+        // Last node starting before the byte
+        let last = self
+            .spans
+            .partition_point(|span| span.start_byte() <= byte)
+            .checked_sub(1)?;
+        self.ancestors_or_self(last)
+            .find(|id| self.spans[*id].end_byte() >= byte)
+    }
+
+    /// Returns the ids of the nodes fully contained in the given byte range, in pre-order.
+    pub fn contained(&self, range: Range<usize>) -> impl Iterator<Item = usize> + '_ {
+        let start = self
+            .spans
+            .partition_point(|span| span.start_byte() < range.start);
+        let end = self
+            .spans
+            .partition_point(|span| span.start_byte() <= range.end);
+        // Nodes starting in the range but ending after it are ancestors of its end
+        (start..end.max(start)).filter(move |id| self.spans[*id].end_byte() <= range.end)
+    }
+
+    /// Returns the ids of the nodes overlapping the given byte range, in pre-order.
+    ///
+    /// Nodes only touching the range, or empty nodes at its boundaries, are not included.
+    pub fn intersecting(&self, range: Range<usize>) -> impl Iterator<Item = usize> + '_ {
+        let start = self
+            .spans
+            .partition_point(|span| span.start_byte() < range.start);
+        let end = self
+            .spans
+            .partition_point(|span| span.start_byte() < range.end);
+
+        // Nodes starting before the range and overlapping it are ancestors of the last one starting before it
+        let mut enclosing: Vec<usize> = match start.checked_sub(1) {
+            Some(last) => self
+                .ancestors_or_self(last)
+                .skip_while(|id| self.spans[*id].end_byte() <= range.start)
+                .collect(),
+            None => vec![],
+        };
+        enclosing.reverse();
+
+        // Nodes starting in the range overlap it, unless they are empty and at its start
+        let inner = (start..end.max(start)).filter(move |id| {
+            let span = self.spans[*id];
+            span.end_byte() > range.start || span.start_byte() > range.start
+        });
+        enclosing.into_iter().chain(inner)
+    }
+
+    fn ancestors_or_self(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(Some(id), |id| self.parents[*id])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds an index from `(start, end, parent)` tuples, in pre-order
+    fn index(nodes: &[(usize, usize, Option<usize>)]) -> SpanIndex {
+        SpanIndex {
+            spans: nodes
+                .iter()
+                .map(|(start, end, _)| Span::new(*start, *end))
+                .collect(),
+            parents: nodes.iter().map(|(_, _, parent)| *parent).collect(),
+        }
+    }
+
+    /// 0: root 0..20
+    ///   1: 0..8
+    ///     2: 0..3
+    ///     3: 4..8
+    ///   4: 10..20
+    ///     5: 12..12 (empty)
+    ///     6: 12..18
+    fn tree() -> SpanIndex {
+        index(&[
+            (0, 20, None),
+            (0, 8, Some(0)),
+            (0, 3, Some(1)),
+            (4, 8, Some(1)),
+            (10, 20, Some(0)),
+            (12, 12, Some(4)),
+            (12, 18, Some(4)),
+        ])
+    }
+
+    #[test]
+    fn descendant_at() {
+        let index = tree();
+        assert_eq!(index.descendant_at(1), Some(2));
+        assert_eq!(index.descendant_at(3), Some(2));
+        assert_eq!(index.descendant_at(4), Some(3));
+        assert_eq!(index.descendant_at(9), Some(0));
+        assert_eq!(index.descendant_at(12), Some(6));
+        assert_eq!(index.descendant_at(19), Some(4));
+        assert_eq!(index.descendant_at(25), None);
+        assert_eq!(SpanIndex::default().descendant_at(0), None);
+    }
+
+    #[test]
+    fn contained() {
+        let index = tree();
+        assert_eq!(
+            index.contained(0..20).collect::<Vec<_>>(),
+            (0..7).collect::<Vec<_>>()
+        );
+        assert_eq!(index.contained(4..12).collect::<Vec<_>>(), vec![3, 5]);
+        assert_eq!(index.contained(9..10).count(), 0);
+        assert_eq!(index.contained(30..40).count(), 0);
+    }
+
+    #[test]
+    fn intersecting() {
+        let index = tree();
+        assert_eq!(
+            index.intersecting(5..11).collect::<Vec<_>>(),
+            vec![0, 1, 3, 4]
+        );
+        assert_eq!(
+            index.intersecting(12..13).collect::<Vec<_>>(),
+            vec![0, 4, 6]
+        );
+        // Touching nodes are excluded
+        assert_eq!(index.intersecting(8..10).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(index.intersecting(30..40).count(), 0);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ast::AstArena;
+use std::collections::HashMap;
+
+/// An identity of a node that survives edits made elsewhere in the document.
+///
+/// Unlike IDs, which number nodes in pre-order, a stable identity is derived from the path of the node:
+/// the identity of its parent, its kind and its index among the siblings of the same kind.
+///
+/// Inserting a statement at the top of a file keeps the identities of the following nodes,
+/// unless they are siblings of the same kind, whose index changes.
+///
+/// Identities are hashes, so they can be stored in LSP `data` payloads using the `u64` conversions.
+/// They only depend on the kind IDs of the nodes, see [`crate::ast::AstNode::get_kind_id`],
+/// so they stay the same across builds of the server as long as the generated AST does not change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StableNodeId(u64);
+
+impl From<u64> for StableNodeId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<StableNodeId> for u64 {
+    fn from(value: StableNodeId) -> Self {
+        value.0
+    }
+}
+
+/// Computes the [`StableNodeId`] of each node, indexed by ID.
+///
+/// IDs must be assigned in pre-order.
+pub fn stable_ids(nodes: &AstArena) -> Vec<StableNodeId> {
+    let mut stable_ids: Vec<StableNodeId> = Vec::with_capacity(nodes.len());
+    // Number of children of each kind seen so far, by parent
+    let mut counts: HashMap<(usize, u16), u64> = HashMap::new();
+
+    for node in nodes.iter() {
+        // Enums share the id of their variant, the kind is the innermost struct
+        let kind = node.lower().get_kind_id();
+        let mut hasher = Hasher::new();
+        if let Some(parent) = node.get_parent_id() {
+            let index = counts.entry((parent, kind)).or_default();
+            hasher.write(&stable_ids[parent].0.to_le_bytes());
+            hasher.write(&index.to_le_bytes());
+            *index += 1;
+        }
+        hasher.write(&kind.to_le_bytes());
+        stable_ids.push(StableNodeId(hasher.finish()));
+    }
+    stable_ids
+}
+
+/// FNV-1a, whose output does not change across Rust versions unlike `DefaultHasher`
+struct Hasher(u64);
+
+impl Hasher {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // Length prefix, so consecutive inputs can not be shifted into each other
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,55 @@
+use crate::ast::AstArena;
+
+/// A node along with the AST it belongs to.
+///
+/// Generated types have a `view` method, and views of structs have an accessor for each field,
+/// which returns the views of the nodes it points to:
+/// - `AstView<T>` for required fields
+/// - `Option<AstView<T>>` for optional fields
+/// - `impl Iterator<Item = AstView<T>>` for repeated fields
+///
+/// The accessors of a struct are generated as a `<Struct>View` trait, implemented for `AstView<Struct>`.
+///
+/// A view dereferences to its node.
+pub struct AstView<'a, T> {
+    node: &'a T,
+    nodes: &'a AstArena,
+}
+
+impl<'a, T> AstView<'a, T> {
+    pub fn new(node: &'a T, nodes: &'a AstArena) -> Self {
+        Self { node, nodes }
+    }
+
+    /// Returns the node of this view
+    pub fn node(&self) -> &'a T {
+        self.node
+    }
+
+    /// Returns the AST of this view
+    pub fn nodes(&self) -> &'a AstArena {
+        self.nodes
+    }
+}
+
+impl<T> Clone for AstView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AstView<'_, T> {}
+
+impl<T> std::ops::Deref for AstView<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.node
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for AstView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.node.fmt(f)
+    }
+}
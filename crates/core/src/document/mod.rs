@@ -1,8 +1,9 @@
 use lsp_types::PositionEncodingKind;
 use texter::{change::GridIndex, core::text::Text};
 use texter_impl::{change::WrapChange, updateable::WrapTree};
-use tree_sitter::{Point, Tree};
+use tree_sitter::{InputEdit, Point, Tree};
 
+use crate::ast::Span;
 use crate::errors::{DocumentError, TreeSitterError};
 
 pub(crate) mod texter_impl;
@@ -17,6 +18,12 @@ pub(crate) mod texter_impl;
 pub struct Document {
     pub texter: Text,
     pub tree: Tree,
+    /// Edits applied to the previous tree during the last [`Document::update`], in order.
+    pub edits: Vec<InputEdit>,
+    /// Ranges of the new tree whose syntactic structure changed during the last [`Document::update`].
+    ///
+    /// Mirrors [`Tree::changed_ranges`].
+    pub changed_ranges: Vec<tree_sitter::Range>,
 }
 
 impl Document {
@@ -29,7 +36,12 @@ impl Document {
             Some("utf-32") => Text::new_utf32(source),
             _ => Text::new_utf16(source),
         };
-        Self { texter, tree }
+        Self {
+            texter,
+            tree,
+            edits: vec![],
+            changed_ranges: vec![],
+        }
     }
 
     pub fn as_str(&self) -> &str {
@@ -49,6 +61,8 @@ impl Document {
     /// Applies the changes to both the text [`texter`] and the syntax tree [`Tree`], using
     /// incremental parsing to minimize the cost of updating the syntax tree.
     ///
+    /// The applied edits and the changed ranges are kept in [`Document::edits`] and [`Document::changed_ranges`].
+    ///
     /// # Errors
     /// Returns an error if Tree-sitter fails to reparse the updated text.
     pub fn update(
@@ -63,13 +77,48 @@ impl Document {
                 .update(WrapChange::from(change).change, &mut new_tree)?;
         }
 
-        self.tree = parser
+        let edits = new_tree.edits;
+        let tree = parser
             .parse(self.texter.text.as_bytes(), Some(&self.tree))
             .ok_or_else(|| DocumentError::from(TreeSitterError::TreeSitterParser))?;
 
+        self.changed_ranges = self.tree.changed_ranges(&tree).collect();
+        self.edits = edits;
+        self.tree = tree;
         Ok(())
     }
 
+    /// Returns the tree-sitter [`Point`] of a byte offset.
+    ///
+    /// Rows are found with a binary search over the line breaks of the document,
+    /// columns are counted in bytes like tree-sitter does.
+    pub fn byte_to_point(&self, byte: usize) -> Point {
+        let line_breaks = &self.texter.br_indexes.0[1..];
+        let row = line_breaks.partition_point(|&line_break| line_break < byte);
+        let row_start = match row {
+            0 => 0,
+            _ => line_breaks[row - 1] + 1,
+        };
+        Point::new(row, byte - row_start)
+    }
+
+    /// Returns the byte offset of a tree-sitter [`Point`].
+    ///
+    /// Returns `None` if the row is out of bounds.
+    pub fn point_to_byte(&self, point: Point) -> Option<usize> {
+        Some(self.texter.br_indexes.row_start(point.row)? + point.column)
+    }
+
+    /// Converts a byte [`Span`] to a tree-sitter [`tree_sitter::Range`].
+    pub fn span_to_range(&self, span: Span) -> tree_sitter::Range {
+        tree_sitter::Range {
+            start_byte: span.start_byte(),
+            end_byte: span.end_byte(),
+            start_point: self.byte_to_point(span.start_byte()),
+            end_point: self.byte_to_point(span.end_byte()),
+        }
+    }
+
     /// Converts an LSP [`lsp_types::Range`] from the client encoding to UTF-8, returning a new range.
     /// Mirrors texter's own [`GridIndex::normalize`].
     pub fn normalize_range(
@@ -233,6 +282,28 @@ mod test {
         );
     }
 
+    #[rstest]
+    fn byte_to_point(mut parser: Parser) {
+        let source = "<div>\n  <p>こんにちは</p>\n</div>\n";
+        let tree = parser.parse(source, None).unwrap();
+        let document = Document::new(source.into(), tree.clone(), None);
+
+        // Every node's points match the ones computed by tree-sitter
+        let mut cursor = tree.walk();
+        let mut stack = vec![tree.root_node()];
+        while let Some(node) = stack.pop() {
+            let span = Span::from(&node);
+            assert_eq!(document.span_to_range(span), node.range());
+            assert_eq!(
+                document.point_to_byte(node.start_position()),
+                Some(node.start_byte())
+            );
+            stack.extend(node.children(&mut cursor));
+        }
+
+        assert_eq!(document.point_to_byte(Point::new(10, 0)), None);
+    }
+
     #[rstest]
     #[case(Encoding::UTF8, 20)]
     #[case(Encoding::UTF16, 10)]
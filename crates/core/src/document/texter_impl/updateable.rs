@@ -7,11 +7,16 @@ use tree_sitter::{InputEdit, Point, Tree};
 pub struct WrapTree<'a> {
     /// The tree being wrapped.
     pub tree: &'a mut Tree,
+    /// The edits applied to the tree, in order.
+    pub edits: Vec<InputEdit>,
 }
 
 impl<'a> From<&'a mut Tree> for WrapTree<'a> {
     fn from(tree: &'a mut Tree) -> Self {
-        Self { tree }
+        Self {
+            tree,
+            edits: vec![],
+        }
     }
 }
 
@@ -19,6 +24,7 @@ impl Updateable for WrapTree<'_> {
     fn update(&mut self, ctx: UpdateContext) -> Result<(), Error> {
         let new_edits = WrapTree::edit_from_ctx(&ctx)?;
         self.tree.edit(&new_edits);
+        self.edits.push(new_edits);
         Ok(())
     }
 }
@@ -73,6 +73,8 @@ impl ParseError {
 }
 
 /// Error type for AST parsing.
+///
+/// Kinds are tree-sitter kinds, `expected` lists the kinds allowed by `node-types.json`.
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
 pub enum AstError {
     #[error("Unexpected {symbol} in {parent_name}")]
@@ -81,15 +83,154 @@ pub enum AstError {
         symbol: &'static str,
         parent_name: &'static str,
     },
+    /// A required field of a node could not be built
+    #[error("Missing {field} in {parent}, expected {}", .expected.join(" or "))]
+    MissingField {
+        range: tree_sitter::Range,
+        parent: &'static str,
+        /// Name of the field, or `children` for required children
+        field: &'static str,
+        expected: &'static [&'static str],
+    },
+    /// A child has a kind that is not allowed in its parent
+    #[error("Unexpected {found} in {parent}, expected {}", .expected.join(" or "))]
+    UnexpectedChild {
+        range: tree_sitter::Range,
+        parent: &'static str,
+        found: &'static str,
+        expected: &'static [&'static str],
+    },
+    /// A node has a kind that is not a subtype of the expected supertype
+    #[error("{found} is not a valid {supertype}, expected {}", .expected.join(" or "))]
+    InvalidSupertypeVariant {
+        range: tree_sitter::Range,
+        supertype: &'static str,
+        found: &'static str,
+        expected: &'static [&'static str],
+    },
+}
+
+impl AstError {
+    /// Returns the range of the node that caused this error.
+    pub fn range(&self) -> tree_sitter::Range {
+        match self {
+            AstError::UnexpectedSymbol { range, .. }
+            | AstError::MissingField { range, .. }
+            | AstError::UnexpectedChild { range, .. }
+            | AstError::InvalidSupertypeVariant { range, .. } => *range,
+        }
+    }
+
+    /// Returns the same error with another range, used to move errors of reused subtrees.
+    pub(crate) fn with_range(mut self, new_range: tree_sitter::Range) -> Self {
+        match &mut self {
+            AstError::UnexpectedSymbol { range, .. }
+            | AstError::MissingField { range, .. }
+            | AstError::UnexpectedChild { range, .. }
+            | AstError::InvalidSupertypeVariant { range, .. } => *range = new_range,
+        }
+        self
+    }
+}
+
+/// Error held by a required field that could not be built.
+///
+/// Only used by the nodes generated with the `lenient_fields` option of `auto_lsp_codegen`,
+/// which keep such nodes in the AST instead of returning an [`AstError`].
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[error("Missing {field} in {parent}, expected {}", .expected.join(" or "))]
+pub struct MissingField {
+    /// Tree-sitter kind of the node holding the field
+    pub parent: &'static str,
+    /// Name of the field, or `children` for required children
+    pub field: &'static str,
+    /// Tree-sitter kinds allowed in the field
+    pub expected: &'static [&'static str],
+}
+
+/// Id of a generated AST that does not match the tree-sitter language it is used with.
+///
+/// Returned by the `verify_language` function generated by `auto_lsp_codegen`,
+/// usually because the grammar was updated without regenerating the AST.
+///
+/// A `found` kind of `None` means the id is not a node kind of the language,
+/// and a `found` field id of `0` means the language does not know the field.
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mismatch {
+    #[error(
+        "Node id {id} is {} in the language, expected {}",
+        describe_kind(*.found, *.found_named),
+        describe_kind(Some(*.kind), *.named)
+    )]
+    NodeKind {
+        id: u16,
+        kind: &'static str,
+        named: bool,
+        found: Option<&'static str>,
+        found_named: bool,
+    },
+    #[error("Field {field} has id {found} in the language, expected {expected}")]
+    Field {
+        field: &'static str,
+        expected: u16,
+        found: u16,
+    },
+}
+
+/// Displays a node kind in a [`Mismatch`]
+fn describe_kind(kind: Option<&str>, named: bool) -> String {
+    match (kind, named) {
+        (None, _) => "not a node kind".to_string(),
+        (Some(kind), true) => kind.to_string(),
+        (Some(kind), false) => format!("anonymous {kind:?}"),
+    }
+}
+
+impl Mismatch {
+    /// Compares the ids of a generated AST with the ones of a language.
+    ///
+    /// `node_kinds` holds the kind, whether it is named and the id of each node kind,
+    /// with one entry per id when a kind has several (aliases).
+    /// Each id must have the same kind and namedness in the language.
+    ///
+    /// `fields` holds the name and id of each field.
+    pub fn check(
+        language: &tree_sitter::Language,
+        node_kinds: &[(&'static str, bool, u16)],
+        fields: &[(&'static str, u16)],
+    ) -> Result<(), Vec<Mismatch>> {
+        let node_kinds = node_kinds.iter().filter_map(|&(kind, named, id)| {
+            let found = language.node_kind_for_id(id);
+            let found_named = language.node_kind_is_named(id);
+            (found != Some(kind) || found_named != named).then_some(Mismatch::NodeKind {
+                id,
+                kind,
+                named,
+                found,
+                found_named,
+            })
+        });
+        let fields = fields.iter().filter_map(|&(field, expected)| {
+            let found = language.field_id_for_name(field).map_or(0, |id| id.get());
+            (found != expected).then_some(Mismatch::Field {
+                field,
+                expected,
+                found,
+            })
+        });
+
+        let mismatches: Vec<_> = node_kinds.chain(fields).collect();
+        match mismatches.is_empty() {
+            true => Ok(()),
+            false => Err(mismatches),
+        }
+    }
 }
 
 impl From<AstError> for ParseError {
     fn from(error: AstError) -> Self {
-        let range = match &error {
-            AstError::UnexpectedSymbol { range, .. } => *range,
-        };
         Self::AstError {
-            span: range.into(),
+            span: error.range(),
             error,
         }
     }
@@ -315,3 +456,111 @@ impl From<texter::error::Error> for DocumentError {
         DocumentError::Texter(TexterError::from(error))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range() -> tree_sitter::Range {
+        tree_sitter::Range {
+            start_byte: 4,
+            end_byte: 8,
+            start_point: tree_sitter::Point { row: 0, column: 4 },
+            end_point: tree_sitter::Point { row: 0, column: 8 },
+        }
+    }
+
+    #[test]
+    fn ast_error_messages() {
+        let missing = AstError::MissingField {
+            range: range(),
+            parent: "function_definition",
+            field: "body",
+            expected: &["block"],
+        };
+        assert_eq!(
+            missing.to_string(),
+            "Missing body in function_definition, expected block"
+        );
+        assert_eq!(missing.range(), range());
+
+        let unexpected = AstError::UnexpectedChild {
+            range: range(),
+            parent: "comparison_operator",
+            found: "+",
+            expected: &["<", ">"],
+        };
+        assert_eq!(
+            unexpected.to_string(),
+            "Unexpected + in comparison_operator, expected < or >"
+        );
+
+        let variant = AstError::InvalidSupertypeVariant {
+            range: range(),
+            supertype: "_simple_statement",
+            found: "block",
+            expected: &["pass_statement", "return_statement"],
+        };
+        assert_eq!(
+            variant.to_string(),
+            "block is not a valid _simple_statement, expected pass_statement or return_statement"
+        );
+        assert_eq!(
+            ParseError::from(variant).to_string(),
+            "block is not a valid _simple_statement, expected pass_statement or return_statement"
+        );
+    }
+
+    #[test]
+    fn language_mismatches() {
+        let language: tree_sitter::Language = tree_sitter_html::LANGUAGE.into();
+        let element = language.id_for_node_kind("element", true);
+        let name = language.field_id_for_name("name").map_or(0, |id| id.get());
+        let count = language.node_kind_count() as u16;
+
+        assert_eq!(
+            Mismatch::check(&language, &[("element", true, element)], &[("name", name)]),
+            Ok(())
+        );
+
+        let mismatches = Mismatch::check(
+            &language,
+            &[
+                ("element", true, element + 1),
+                ("element", false, element),
+                ("function_definition", true, count),
+            ],
+            &[("body", 1)],
+        )
+        .unwrap_err();
+        assert_eq!(mismatches.len(), 4);
+        assert_eq!(
+            mismatches[0],
+            Mismatch::NodeKind {
+                id: element + 1,
+                kind: "element",
+                named: true,
+                found: language.node_kind_for_id(element + 1),
+                found_named: language.node_kind_is_named(element + 1),
+            }
+        );
+        assert_eq!(
+            mismatches[1].to_string(),
+            format!("Node id {element} is element in the language, expected anonymous \"element\"")
+        );
+        assert_eq!(
+            mismatches[2].to_string(),
+            format!(
+                "Node id {count} is not a node kind in the language, expected function_definition"
+            )
+        );
+        assert_eq!(
+            mismatches[3],
+            Mismatch::Field {
+                field: "body",
+                expected: 1,
+                found: 0
+            }
+        );
+    }
+}
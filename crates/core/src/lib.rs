@@ -16,3 +16,10 @@ pub mod errors;
 pub mod parsers;
 pub mod regex;
 pub mod utils;
+
+pub use tree_sitter;
+
+#[cfg(feature = "serde")]
+pub use serde;
+#[cfg(feature = "serde")]
+pub use serde_json;
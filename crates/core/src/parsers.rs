@@ -1,4 +1,4 @@
-use crate::ast::AstNode;
+use crate::ast::{AstArena, Builder, CommentRules};
 use crate::document::Document;
 use crate::errors::ParseError;
 use parking_lot::RwLock;
@@ -11,6 +11,8 @@ pub struct Parser {
     pub language: Language,
     /// Function to invoke the AST parser.
     pub ast_parser: InvokeParserFn,
+    /// Rules used to attach comments to the nodes of the AST.
+    pub comment_rules: CommentRules,
 }
 
 impl std::fmt::Debug for Parser {
@@ -21,5 +23,6 @@ impl std::fmt::Debug for Parser {
     }
 }
 
+/// Builds the AST of a document with the given [`Builder`], returning its nodes.
 pub type InvokeParserFn =
-    fn(&dyn salsa::Database, &Document) -> Result<Vec<Box<dyn AstNode>>, ParseError>;
+    fn(&dyn salsa::Database, &Document, Builder) -> Result<AstArena, ParseError>;
@@ -1,10 +1,11 @@
 use std::{
     io::{BufReader, Read},
     path::Path,
-    sync::Arc,
+    sync::{Arc, Mutex, Weak},
 };
 
 use auto_lsp_core::{
+    ast::AstArena,
     document::Document,
     errors::{DataBaseError, FileSystemError, RuntimeError, TreeSitterError},
     parsers::Parser,
@@ -16,6 +17,8 @@ use salsa::Setter;
 use texter::core::text::Text;
 use tree_sitter::Tree;
 
+use super::BaseDatabase;
+
 /// A salsa input that represents a file in the database.
 ///
 /// # Creating a File
@@ -56,8 +59,62 @@ pub struct File {
 
     // Document version, None if created via the file system.
     pub version: Option<i32>,
+
+    /// Nodes of the AST before the last edit, reused by [`get_ast`] to only rebuild the edited nodes.
+    ///
+    /// Shared with the previous [`super::tracked::ParsedAst`], so keeping them does not copy the AST.
+    ///
+    /// `None` if the document was not edited incrementally.
+    #[default]
+    #[returns(ref)]
+    pub previous_ast: Option<Arc<AstArena>>,
+
+    /// Nodes of the last AST built by [`super::tracked::get_ast`], kept by [`File::update_edit`].
+    #[default]
+    #[returns(ref)]
+    pub(crate) last_ast: LastAst,
 }
 
+/// The nodes of the last AST built for a file, with the document they were built from.
+///
+/// Recorded as a side effect of [`super::tracked::get_ast`], so that an edit can reuse the AST
+/// memoized for the current document without running the query again.
+#[derive(Default, Clone)]
+pub struct LastAst(Arc<Mutex<Option<BuiltAst>>>);
+
+/// Nodes of an AST and the document they were built from.
+type BuiltAst = (Weak<Document>, Arc<AstArena>);
+
+impl LastAst {
+    pub(crate) fn set(&self, document: &Arc<Document>, nodes: &Arc<AstArena>) {
+        *self.0.lock().unwrap() = Some((Arc::downgrade(document), nodes.clone()));
+    }
+
+    /// Returns the nodes built from `document`, if any.
+    fn get(&self, document: &Arc<Document>) -> Option<Arc<AstArena>> {
+        match &*self.0.lock().unwrap() {
+            Some((built_from, nodes)) if Weak::ptr_eq(built_from, &Arc::downgrade(document)) => {
+                Some(nodes.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for LastAst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LastAst")
+    }
+}
+
+impl PartialEq for LastAst {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for LastAst {}
+
 #[bon]
 impl File {
     /// Creates a new file by reading the file system.
@@ -121,9 +178,13 @@ impl File {
     }
 
     /// Updates the file from a [`DidChangeTextDocumentParams`] event.
+    ///
+    /// If the AST of the current document was built, it is kept so that the next
+    /// [`super::tracked::get_ast`] call only rebuilds the nodes affected by the edit.
+    /// Otherwise the next call builds the whole AST.
     pub fn update_edit(
         &self,
-        db: &mut impl salsa::Database,
+        db: &mut impl BaseDatabase,
         event: &DidChangeTextDocumentParams,
     ) -> Result<(), DataBaseError> {
         let changes = &event.content_changes;
@@ -132,6 +193,8 @@ impl File {
         doc.update(&mut self.parsers(db).parser.write(), changes)
             .map_err(|e| DataBaseError::from((self.url(db), e)))?;
 
+        let previous_ast = self.last_ast(db).get(self.document(db));
+        self.set_previous_ast(db).to(previous_ast);
         self.set_document(db).to(Arc::new(doc));
         self.set_version(db).to(Some(event.text_document.version));
         Ok(())
@@ -161,6 +224,7 @@ impl File {
 
         let db = &mut session.db;
         self.set_document(db).to(Arc::new(document));
+        self.set_previous_ast(db).to(None);
         Ok(())
     }
 
@@ -175,6 +239,7 @@ impl File {
         let document = Document::new(event.text.clone(), tree, Some(&session.encoding));
 
         self.set_document(db).to(Arc::new(document));
+        self.set_previous_ast(db).to(None);
         self.set_version(db).to(Some(event.version));
         Ok(())
     }
@@ -189,9 +254,12 @@ impl File {
         let document = Document {
             texter: Text::new("".into()),
             tree,
+            edits: vec![],
+            changed_ranges: vec![],
         };
 
         self.set_document(db).to(Arc::new(document));
+        self.set_previous_ast(db).to(None);
         self.set_version(db).to(None);
         Ok(())
     }
@@ -1,16 +1,20 @@
 use super::lexer::get_tree_sitter_errors;
 use super::{BaseDatabase, File};
-use auto_lsp_core::ast::AstNode;
+use auto_lsp_core::ast::{
+    AstArena, AstNode, AstPrinter, Builder, Comment, CommentRules, Comments, SpanIndex,
+    StableNodeId, stable_ids, subtree_ends,
+};
 use auto_lsp_core::document::Document;
 use auto_lsp_core::errors::ParseErrorAccumulator;
-use lsp_types::Position;
+use lsp_types::{Position, Range};
 use salsa::Accumulator;
+use std::collections::HashMap;
 use std::ops::Deref;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Query that returns the AST of a file.
 ///
-/// This query will also sort the nodes by their id.
+/// If the file was edited incrementally, the subtrees of the previous AST that were not affected by the edit are reused.
 #[salsa::tracked(returns(ref))]
 pub fn get_ast<'db>(db: &'db dyn BaseDatabase, file: File) -> ParsedAst {
     let parsers = file.parsers(db);
@@ -25,23 +29,64 @@ pub fn get_ast<'db>(db: &'db dyn BaseDatabase, file: File) -> ParsedAst {
     // Find tree-sitter errors and accumulate them
     get_tree_sitter_errors(db, &node, doc.as_bytes());
 
-    match (parsers.ast_parser)(db, &doc) {
-        Ok(nodes) => ParsedAst::new(nodes),
+    // Unchanged subtrees of the previous AST are reused
+    let builder = match file.previous_ast(db) {
+        Some(previous) => Builder::incremental(previous.clone(), doc),
+        None => Builder::default(),
+    };
+
+    let ast = match (parsers.ast_parser)(db, &doc, builder) {
+        Ok(nodes) => ParsedAst::new(nodes).with_comments(doc, &parsers.comment_rules),
         Err(e) => {
             ParseErrorAccumulator::accumulate(e.clone().into(), db);
             ParsedAst::default()
         }
-    }
+    };
+    // Kept for the next edit, see `File::update_edit`
+    file.last_ast(db).set(doc, &ast.nodes);
+    ast
 }
 
 /// Cloneable wrapper around a parsed AST.
 ///
-/// The nodes are sorted by their id.
+/// The nodes are stored in an [`AstArena`] and indexed by their id.
+///
+/// The first node of the arena is always the root node.
 ///
-/// The first node of the list is always the root node.
-#[derive(Debug, Default, Clone, Eq)]
+/// Since ids are assigned in pre-order, the descendants of a node have contiguous ids right after it.
+/// [`ParsedAst`] records where each subtree ends, which makes [`ParsedAst::descendants`]
+/// a contiguous range of ids.
+///
+/// Ids change whenever a node is inserted before another one,
+/// [`ParsedAst::stable_id`] returns an identity that survives edits made elsewhere in the document.
+///
+/// Position and range lookups are backed by a [`SpanIndex`], built on first use.
+#[derive(Default, Clone)]
 pub struct ParsedAst {
-    pub nodes: Arc<Vec<Box<dyn AstNode>>>,
+    pub nodes: Arc<AstArena>,
+    /// Exclusive end index of the subtree of each node
+    subtree_ends: Arc<Vec<usize>>,
+    /// Stable identities, computed on first use
+    stable_ids: Arc<OnceLock<StableIds>>,
+    /// Index of the node spans, computed on first use
+    spans: Arc<OnceLock<SpanIndex>>,
+    /// Leading and trailing comments of the nodes
+    comments: Arc<Comments>,
+}
+
+/// Stable identities of the nodes, and their reverse lookup.
+struct StableIds {
+    ids: Vec<StableNodeId>,
+    /// `None` when several nodes share the same identity
+    resolve: HashMap<StableNodeId, Option<usize>>,
+}
+
+impl std::fmt::Debug for ParsedAst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParsedAst")
+            .field("nodes", &self.nodes)
+            .finish()
+    }
 }
 
 impl PartialEq for ParsedAst {
@@ -50,8 +95,10 @@ impl PartialEq for ParsedAst {
     }
 }
 
+impl Eq for ParsedAst {}
+
 impl Deref for ParsedAst {
-    type Target = Vec<Box<dyn AstNode>>;
+    type Target = AstArena;
 
     fn deref(&self) -> &Self::Target {
         &self.nodes
@@ -59,16 +106,154 @@ impl Deref for ParsedAst {
 }
 
 impl ParsedAst {
-    pub fn new(mut nodes: Vec<Box<dyn AstNode>>) -> Self {
-        nodes.sort_unstable();
+    pub fn new(nodes: AstArena) -> Self {
+        debug_assert!(nodes.iter().enumerate().all(|(i, n)| n.get_id() == i));
+
         Self {
+            subtree_ends: Arc::new(subtree_ends(&nodes)),
             nodes: Arc::new(nodes),
+            stable_ids: Default::default(),
+            spans: Default::default(),
+            comments: Default::default(),
         }
     }
 
+    /// Attaches the comments found while building the AST to its nodes, following the given rules.
+    ///
+    /// See [`Comments`] for how comments are attached.
+    pub fn with_comments(mut self, document: &Document, rules: &CommentRules) -> Self {
+        self.comments = Arc::new(Comments::new(&self.nodes, document, rules));
+        self
+    }
+
+    /// Returns all the comments of the AST, in source order.
+    pub fn comments(&self) -> &[Comment] {
+        self.comments.all()
+    }
+
+    /// Returns the comments placed right before the node with the given id.
+    ///
+    /// Returns an empty slice if the id is out of bounds.
+    pub fn leading_comments(&self, id: usize) -> &[Comment] {
+        match self.nodes.get(id) {
+            Some(node) => self.comments.leading(node.get_span()),
+            None => &[],
+        }
+    }
+
+    /// Returns the comment placed right after the node with the given id, on the same line.
+    pub fn trailing_comment(&self, id: usize) -> Option<&Comment> {
+        self.comments.trailing(self.nodes.get(id)?.get_span())
+    }
+
+    fn stable_ids(&self) -> &StableIds {
+        self.stable_ids.get_or_init(|| {
+            let ids = stable_ids(&self.nodes);
+            let mut resolve = HashMap::with_capacity(ids.len());
+            for (id, stable_id) in ids.iter().enumerate() {
+                resolve
+                    .entry(*stable_id)
+                    .and_modify(|id| *id = None)
+                    .or_insert(Some(id));
+            }
+            StableIds { ids, resolve }
+        })
+    }
+
+    /// Returns the [`StableNodeId`] of the node with the given id.
+    ///
+    /// Returns `None` if the id is out of bounds.
+    pub fn stable_id(&self, id: usize) -> Option<StableNodeId> {
+        self.stable_ids().ids.get(id).copied()
+    }
+
+    /// Resolves a [`StableNodeId`], possibly obtained from a previous version of the AST,
+    /// to the id of the same node in this AST.
+    ///
+    /// Returns `None` if no node has this identity, or if several nodes share it.
+    pub fn resolve(&self, stable_id: StableNodeId) -> Option<usize> {
+        *self.stable_ids().resolve.get(&stable_id)?
+    }
+
+    /// Resolves a [`StableNodeId`] to a node of type `T`, see [`ParsedAst::resolve`].
+    ///
+    /// Like [`ParsedAst::get`], `T` can either be the stored type or the innermost struct.
+    ///
+    /// Returns `None` if no node has this identity, or if the node is not a `T`.
+    pub fn resolve_as<T: AstNode>(&self, stable_id: StableNodeId) -> Option<&T> {
+        self.get(self.resolve(stable_id)?)
+    }
+
     /// Returns the root node of the AST.
-    pub fn get_root(&self) -> Option<&Box<dyn AstNode>> {
-        self.nodes.first()
+    pub fn get_root(&self) -> Option<&dyn AstNode> {
+        self.nodes.get(0)
+    }
+
+    /// Returns a printer of the typed tree, see [`AstPrinter`].
+    ///
+    /// ```rust, ignore
+    /// println!("{}", ast.pretty(document));
+    /// println!("{}", ast.pretty(document).format(PrintFormat::SExpression));
+    /// ```
+    pub fn pretty<'a>(&'a self, document: &'a Document) -> AstPrinter<'a> {
+        AstPrinter::new(&self.nodes, document)
+    }
+
+    /// Serializes the AST to JSON, starting from the root node.
+    ///
+    /// Each node is serialized as `{kind, range, text, fields}`, see [`auto_lsp_core::ast::to_json`].
+    ///
+    /// Returns `null` if the AST is empty.
+    #[cfg(feature = "serde")]
+    pub fn to_json(
+        &self,
+        document: &Document,
+    ) -> auto_lsp_core::serde_json::Result<auto_lsp_core::serde_json::Value> {
+        auto_lsp_core::ast::to_json(&self.nodes, 0, document)
+    }
+
+    /// Returns the node with the given id as a `T`.
+    ///
+    /// Nodes are stored with the type of the field they belong to,
+    /// so `T` can either be that type or the innermost struct returned by [`AstNode::lower`].
+    ///
+    /// Returns `None` if the id is out of bounds or if the node is not a `T`.
+    pub fn get<T: AstNode>(&self, id: usize) -> Option<&T> {
+        downcast(self.nodes.get(id)?)
+    }
+
+    /// Returns the nodes that are a `T`, in source order.
+    ///
+    /// Like [`ParsedAst::get`], `T` can either be the stored type or the innermost struct.
+    /// Nodes are looked up in the index of the arena, see [`AstArena::ids_of`],
+    /// so only the nodes of type `T` are visited.
+    pub fn of_type<T: AstNode>(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.nodes
+            .ids_of::<T>()
+            .filter_map(|id| downcast(&self.nodes[id]))
+    }
+
+    /// Returns the ancestors of the node with the given id, from its parent up to the root node.
+    ///
+    /// The node itself is not included.
+    pub fn ancestors(&self, id: usize) -> impl Iterator<Item = &dyn AstNode> {
+        let mut parent = self.nodes.get(id).and_then(|node| node.get_parent_id());
+        std::iter::from_fn(move || {
+            let node = self.nodes.get(parent?)?;
+            parent = node.get_parent_id();
+            Some(node)
+        })
+    }
+
+    /// Returns the closest ancestor of the node with the given id that is a `T`.
+    ///
+    /// Like [`ParsedAst::get`], `T` can either be the stored type or the innermost struct.
+    pub fn ancestor_of_type<T: AstNode>(&self, id: usize) -> Option<&T> {
+        self.ancestors(id).find_map(downcast)
+    }
+
+    fn spans(&self) -> &SpanIndex {
+        self.spans.get_or_init(|| SpanIndex::new(&self.nodes))
     }
 
     /// Returns the deepest node that contains the given LSP position.
@@ -80,20 +265,125 @@ impl ParsedAst {
         &self,
         doc: &Document,
         position: &Position,
-    ) -> Option<&Box<dyn AstNode>> {
-        debug_assert!(self.nodes.is_sorted());
+    ) -> Option<&dyn AstNode> {
+        let byte = position_to_byte(doc, position)?;
+        Some(&self.nodes[self.spans().descendant_at(byte)?])
+    }
 
-        let position = doc.normalize_position(position).ok()?;
-        let position = (position.line as usize, position.character as usize);
+    /// Returns the nodes fully contained in the given LSP range, in pre-order.
+    ///
+    /// Like [`ParsedAst::descendant_for_position`], the range is normalized first.
+    /// Returns an empty iterator if the range is out of bounds.
+    pub fn nodes_in_range(
+        &self,
+        doc: &Document,
+        range: &Range,
+    ) -> impl Iterator<Item = &dyn AstNode> {
+        range_to_bytes(doc, range)
+            .into_iter()
+            .flat_map(|range| self.spans().contained(range))
+            .map(|id| &self.nodes[id])
+    }
 
-        let idx = self.nodes.partition_point(|f| {
-            let s = f.get_range().start_point;
-            (s.row, s.column) <= position
-        });
+    /// Returns the nodes overlapping the given LSP range, in pre-order.
+    ///
+    /// This includes the nodes enclosing the range, but not the nodes only touching it.
+    /// Returns an empty iterator if the range is out of bounds.
+    pub fn nodes_intersecting(
+        &self,
+        doc: &Document,
+        range: &Range,
+    ) -> impl Iterator<Item = &dyn AstNode> {
+        range_to_bytes(doc, range)
+            .into_iter()
+            .flat_map(|range| self.spans().intersecting(range))
+            .map(|id| &self.nodes[id])
+    }
 
-        self.nodes[..idx].iter().rev().find(|f| {
-            let e = f.get_range().end_point;
-            (e.row, e.column) >= position
+    /// Returns the nodes fully contained in the given LSP range that are a `T`, in pre-order.
+    ///
+    /// Like [`ParsedAst::get`], `T` can either be the stored type or the innermost struct.
+    pub fn nodes_in_range_of<T: AstNode>(
+        &self,
+        doc: &Document,
+        range: &Range,
+    ) -> impl Iterator<Item = &T> {
+        self.nodes_in_range(doc, range).filter_map(downcast)
+    }
+
+    /// Returns the nodes overlapping the given LSP range that are a `T`, in pre-order.
+    ///
+    /// Like [`ParsedAst::get`], `T` can either be the stored type or the innermost struct.
+    pub fn nodes_intersecting_of<T: AstNode>(
+        &self,
+        doc: &Document,
+        range: &Range,
+    ) -> impl Iterator<Item = &T> {
+        self.nodes_intersecting(doc, range).filter_map(downcast)
+    }
+
+    /// Returns all the descendants of the node with the given id, in pre-order.
+    ///
+    /// The node itself is not included.
+    /// Returns an empty iterator if the id is out of bounds.
+    pub fn descendants(
+        &self,
+        id: usize,
+    ) -> impl DoubleEndedIterator<Item = &dyn AstNode> + ExactSizeIterator {
+        let end = self.subtree_ends.get(id).copied().unwrap_or(id + 1);
+        (id + 1..end).map(|id| &self.nodes[id])
+    }
+
+    /// Returns the direct children of the node with the given id, in source order.
+    pub fn children(&self, id: usize) -> impl Iterator<Item = &dyn AstNode> {
+        let end = self.subtree_ends.get(id).copied().unwrap_or(id);
+        let mut next = id + 1;
+        std::iter::from_fn(move || {
+            if next >= end {
+                return None;
+            }
+            let child = next;
+            // Skips the subtree of the current child to land on its next sibling
+            next = self.subtree_ends[child];
+            Some(&self.nodes[child])
         })
     }
+
+    /// Returns the sibling that follows the node with the given id, if any.
+    pub fn next_sibling(&self, id: usize) -> Option<&dyn AstNode> {
+        let parent = self.nodes.get(id)?.get_parent_id()?;
+        let next = self.subtree_ends[id];
+        match next < self.subtree_ends[parent] {
+            true => Some(&self.nodes[next]),
+            false => None,
+        }
+    }
+
+    /// Returns the sibling that precedes the node with the given id, if any.
+    pub fn prev_sibling(&self, id: usize) -> Option<&dyn AstNode> {
+        let parent = self.nodes.get(id)?.get_parent_id()?;
+        self.children(parent)
+            .take_while(|child| child.get_id() < id)
+            .last()
+    }
+}
+
+/// Converts an LSP position to a byte offset, see [`ParsedAst::descendant_for_position`].
+fn position_to_byte(doc: &Document, position: &Position) -> Option<usize> {
+    let position = doc.normalize_position(position).ok()?;
+    doc.point_to_byte(tree_sitter::Point::new(
+        position.line as usize,
+        position.character as usize,
+    ))
+}
+
+/// Converts an LSP range to a byte range.
+fn range_to_bytes(doc: &Document, range: &Range) -> Option<std::ops::Range<usize>> {
+    Some(position_to_byte(doc, &range.start)?..position_to_byte(doc, &range.end)?)
+}
+
+/// Downcasts a node to `T`, falling back to its innermost struct.
+fn downcast<T: AstNode>(node: &dyn AstNode) -> Option<&T> {
+    node.downcast_ref::<T>()
+        .or_else(|| node.lower().downcast_ref::<T>())
 }
@@ -0,0 +1,124 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, LitBool, LitStr, Path, Token, Visibility, braced, bracketed};
+
+/// Arguments of [`crate::generate_ast`]
+pub(crate) struct Input {
+    /// Path of `node-types.json` relative to the manifest of the crate, such as `"tree-sitter-foo/src/node-types.json"`
+    pub(crate) node_types: LitStr,
+    pub(crate) language: Path,
+    pub(crate) anonymous_tokens: bool,
+    pub(crate) lenient_fields: bool,
+    pub(crate) serde: bool,
+    pub(crate) tokens: Vec<(LitStr, LitStr)>,
+    pub(crate) renames: Vec<(LitStr, LitStr)>,
+    pub(crate) skip_rules: Vec<LitStr>,
+    pub(crate) derives: Vec<Path>,
+    pub(crate) attributes: Vec<Attribute>,
+    pub(crate) visibility: Option<Visibility>,
+    pub(crate) crate_path: Option<Path>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut node_types = None;
+        let mut language = None;
+        let mut anonymous_tokens = false;
+        let mut lenient_fields = false;
+        let mut serde = false;
+        let mut tokens = vec![];
+        let mut renames = vec![];
+        let mut skip_rules = vec![];
+        let mut derives = vec![];
+        let mut attributes = vec![];
+        let mut visibility = None;
+        let mut crate_path = None;
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            match key.to_string().as_str() {
+                "node_types" => {
+                    // The sources of a grammar crate can not be found reliably from a proc macro
+                    if !input.peek(LitStr) {
+                        return Err(input.error(
+                            "expected the path of `node-types.json` relative to the manifest of the crate, \
+                             with `parser.c` in the same directory",
+                        ));
+                    }
+                    node_types = Some(input.parse()?)
+                }
+                "language" => language = Some(input.parse()?),
+                "anonymous_tokens" => anonymous_tokens = input.parse::<LitBool>()?.value,
+                "lenient_fields" => lenient_fields = input.parse::<LitBool>()?.value,
+                "serde" => serde = input.parse::<LitBool>()?.value,
+                "tokens" => tokens = parse_map(input)?,
+                "renames" => renames = parse_map(input)?,
+                "skip_rules" => skip_rules = parse_list(input)?,
+                "derives" => derives = parse_list(input)?,
+                "attributes" => {
+                    let content;
+                    bracketed!(content in input);
+                    while !content.is_empty() {
+                        attributes.extend(content.call(Attribute::parse_outer)?);
+                        if !content.is_empty() {
+                            content.parse::<Token![,]>()?;
+                        }
+                    }
+                }
+                "visibility" => visibility = Some(input.parse()?),
+                "crate_path" => crate_path = Some(input.parse()?),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown option `{key}`"),
+                    ));
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let missing = |option| syn::Error::new(input.span(), format!("missing option `{option}`"));
+        Ok(Input {
+            node_types: node_types.ok_or_else(|| missing("node_types"))?,
+            language: language.ok_or_else(|| missing("language"))?,
+            anonymous_tokens,
+            lenient_fields,
+            serde,
+            tokens,
+            renames,
+            skip_rules,
+            derives,
+            attributes,
+            visibility,
+            crate_path,
+        })
+    }
+}
+
+/// Parses `["a", "b"]` or `[A, B]`
+fn parse_list<T: Parse>(input: ParseStream) -> syn::Result<Vec<T>> {
+    let content;
+    bracketed!(content in input);
+    Ok(Punctuated::<T, Token![,]>::parse_terminated(&content)?
+        .into_iter()
+        .collect())
+}
+
+/// Parses `{ "a" => "b", "c" => "d" }`
+fn parse_map(input: ParseStream) -> syn::Result<Vec<(LitStr, LitStr)>> {
+    let content;
+    braced!(content in input);
+    let mut entries = vec![];
+    while !content.is_empty() {
+        let key = content.parse()?;
+        content.parse::<Token![=>]>()?;
+        entries.push((key, content.parse()?));
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(entries)
+}
@@ -0,0 +1,159 @@
+//! # Auto LSP Macros
+//!
+//! Proc macros for `auto_lsp`, re-exported by the main crate with the `macros` feature.
+//!
+//! See [`generate_ast!`] to generate an AST without a build script.
+
+mod input;
+
+use crate::input::Input;
+use auto_lsp_codegen::{CodegenConfig, GenerateOptions, Generator, LanguageSymbols};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, quote};
+use std::path::{Path, PathBuf};
+use syn::parse_macro_input;
+
+/// Generates the AST of a tree-sitter grammar in place, as an alternative to a build script
+///
+/// Takes the path of the `node-types.json` of the grammar, relative to the manifest of the crate,
+/// along with the language function of the grammar.
+///
+/// ```rust, ignore
+/// mod ast {
+///     auto_lsp::generate_ast!(
+///         node_types: "tree-sitter-json/src/node-types.json",
+///         language: tree_sitter_json::LANGUAGE,
+///         // Optional, same as the options and config of `auto_lsp_codegen::Generator`
+///         lenient_fields: true,
+///         serde: true,
+///         anonymous_tokens: false,
+///         tokens: { "`" => "Backtick" },
+///         renames: { "CompoundStatement_SimpleStatement" => "Statement" },
+///         skip_rules: ["print_statement"],
+///         derives: [Eq, Hash],
+///         attributes: [#[non_exhaustive]],
+///         visibility: pub(crate),
+///         crate_path: auto_lsp::core,
+///     );
+/// }
+/// ```
+///
+/// Since the language can not be loaded while the macro expands, node kind and field ids are read from the `parser.c`
+/// found next to `node-types.json`, like `grammar.json` when `anonymous_tokens` is set.
+/// Both are written by `tree-sitter generate`, so the `src` directory of the grammar can be copied as is.
+/// The ids are checked against the language when configuring the parser, see `verify_language`.
+///
+/// The generated items are defined in a `generated` module and re-exported, along with the language as `LANGUAGE`.
+/// Cargo rebuilds the crate when one of the files read by the macro changes.
+#[proc_macro]
+pub fn generate_ast(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as Input);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: Input) -> syn::Result<TokenStream> {
+    let node_types_path = manifest_dir().join(input.node_types.value());
+    let error = |error: String| syn::Error::new(input.node_types.span(), error);
+
+    // `tree-sitter generate` writes the three files in the same directory
+    let dir = node_types_path.parent().unwrap_or(Path::new(""));
+    let mut paths = vec![node_types_path.clone(), dir.join("parser.c")];
+    if input.anonymous_tokens {
+        paths.push(dir.join("grammar.json"));
+    }
+    let files = paths
+        .iter()
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|e| error(format!("Failed to read {}: {e}", path.display())))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let symbols = LanguageSymbols::from_parser(&files[1]).map_err(|e| error(e.to_string()))?;
+    let mut generator = Generator::from_symbols(symbols)
+        .with_tokens(
+            input
+                .tokens
+                .iter()
+                .map(|(token, name)| (token.value(), name.value())),
+        )
+        .with_options(GenerateOptions {
+            lenient_fields: input.lenient_fields,
+            serde: input.serde,
+        })
+        .with_config(config(&input));
+    if let Some(grammar) = files.get(2) {
+        generator = generator.with_grammar(grammar);
+    }
+    let code = generator
+        .generate(&files[0])
+        .map_err(|e| error(e.to_string()))?;
+
+    // Inner attributes are not allowed in a macro expansion, so they are moved to the module
+    let (attributes, code) = split_inner_attributes(code);
+    let visibility = match &input.visibility {
+        Some(visibility) => visibility.to_token_stream(),
+        None => quote! { pub },
+    };
+    let language = &input.language;
+    let paths = paths.iter().map(|path| path.to_string_lossy().to_string());
+
+    Ok(quote! {
+        #(const _: &[u8] = include_bytes!(#paths);)*
+
+        #(#[#attributes])*
+        mod generated {
+            #code
+        }
+
+        #visibility use generated::*;
+        #visibility use #language as LANGUAGE;
+    })
+}
+
+fn config(input: &Input) -> CodegenConfig {
+    let mut config = CodegenConfig::new();
+    for (from, to) in &input.renames {
+        config = config.rename(from.value(), to.value());
+    }
+    for kind in &input.skip_rules {
+        config = config.skip_rule(kind.value());
+    }
+    for derive in &input.derives {
+        config = config.derive(derive.to_token_stream().to_string());
+    }
+    for attribute in &input.attributes {
+        config = config.attribute(attribute.to_token_stream().to_string());
+    }
+    if let Some(visibility) = &input.visibility {
+        config = config.visibility(visibility.to_token_stream().to_string());
+    }
+    if let Some(crate_path) = &input.crate_path {
+        config = config.crate_path(crate_path.to_token_stream().to_string());
+    }
+    config
+}
+
+/// Splits the leading `#![...]` attributes of the generated code, returning their content
+fn split_inner_attributes(code: TokenStream) -> (Vec<TokenStream>, TokenStream) {
+    let mut tokens = code.into_iter().peekable();
+    let mut attributes = vec![];
+    while let Some(TokenTree::Punct(punct)) = tokens.peek()
+        && punct.as_char() == '#'
+    {
+        let attribute: Vec<_> = tokens.by_ref().take(3).collect();
+        match attribute.as_slice() {
+            [_, TokenTree::Punct(bang), TokenTree::Group(group)] if bang.as_char() == '!' => {
+                attributes.push(group.stream())
+            }
+            _ => return (attributes, attribute.into_iter().chain(tokens).collect()),
+        }
+    }
+    (attributes, tokens.collect())
+}
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default())
+}
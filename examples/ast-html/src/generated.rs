@@ -1 +1,762 @@
-# ! [allow (clippy :: all)] # ! [allow (unused)] # ! [allow (dead_code)] # ! [allow (non_camel_case_types)] # ! [allow (non_snake_case)] # [derive (Debug , Clone , PartialEq)] pub struct Attribute { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < AttributeName_AttributeValue_QuotedAttributeValue >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for Attribute { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 37u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Attribute { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Doctype { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Doctype { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 26u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Doctype { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Document { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for Document { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 25u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Document { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Element { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for Element { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 28u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Element { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct EndTag { pub children : auto_lsp :: core :: ast :: AstNodeId < TagName > , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for EndTag { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 35u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for EndTag { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = Ok (None) ; ; builder . builder (db , & node , Some (id) , | b | { b . on_children_id (& mut children) }) ; Ok (Self { children : children ? . ok_or_else (|| { auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (TagName) , } }) ? , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct ErroneousEndTag { pub children : auto_lsp :: core :: ast :: AstNodeId < ErroneousEndTagName > , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for ErroneousEndTag { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 36u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for ErroneousEndTag { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = Ok (None) ; ; builder . builder (db , & node , Some (id) , | b | { b . on_children_id (& mut children) }) ; Ok (Self { children : children ? . ok_or_else (|| { auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (ErroneousEndTagName) , } }) ? , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct QuotedAttributeValue { pub children : Option < auto_lsp :: core :: ast :: AstNodeId < AttributeValue >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for QuotedAttributeValue { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 38u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for QuotedAttributeValue { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = Ok (None) ; ; builder . builder (db , & node , Some (id) , | b | { b . on_children_id (& mut children) }) ; Ok (Self { children : children ? , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct ScriptElement { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < EndTag_RawText_StartTag >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for ScriptElement { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 29u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for ScriptElement { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct SelfClosingTag { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Attribute_TagName >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for SelfClosingTag { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 34u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for SelfClosingTag { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct StartTag { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Attribute_TagName >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for StartTag { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 31u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for StartTag { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct StyleElement { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < EndTag_RawText_StartTag >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for StyleElement { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 30u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for StyleElement { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_DoubleQuote { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_DoubleQuote { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 14u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_DoubleQuote { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_Quote { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_Quote { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 12u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_Quote { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_SlashGreater { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_SlashGreater { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 6u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_SlashGreater { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_Less { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_Less { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 5u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_Less { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_LessBang { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_LessBang { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 1u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_LessBang { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_LessSlash { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_LessSlash { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 7u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_LessSlash { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_Equal { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_Equal { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 8u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_Equal { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_Greater { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_Greater { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 3u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_Greater { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct AttributeName { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for AttributeName { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 9u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for AttributeName { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct AttributeValue { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for AttributeValue { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 10u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for AttributeValue { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Comment { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Comment { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 24u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Comment { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_doctype { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_doctype { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 26u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_doctype { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Entity { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Entity { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 11u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Entity { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct ErroneousEndTagName { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for ErroneousEndTagName { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 21u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for ErroneousEndTagName { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct RawText { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for RawText { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 23u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for RawText { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct TagName { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for TagName { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 17u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for TagName { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Text { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Text { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 16u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Text { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub enum AttributeName_AttributeValue_QuotedAttributeValue { AttributeName (AttributeName) , AttributeValue (AttributeValue) , QuotedAttributeValue (QuotedAttributeValue) } impl auto_lsp :: core :: ast :: AstNode for AttributeName_AttributeValue_QuotedAttributeValue { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 9u16 | 10u16 | 38u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { Self :: AttributeName (node) => node . lower () , Self :: AttributeValue (node) => node . lower () , Self :: QuotedAttributeValue (node) => node . lower () } } fn get_id (& self) -> usize { match self { Self :: AttributeName (node) => node . get_id () , Self :: AttributeValue (node) => node . get_id () , Self :: QuotedAttributeValue (node) => node . get_id () } } fn get_parent_id (& self) -> Option < usize > { match self { Self :: AttributeName (node) => node . get_parent_id () , Self :: AttributeValue (node) => node . get_parent_id () , Self :: QuotedAttributeValue (node) => node . get_parent_id () } } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { match self { Self :: AttributeName (node) => node . get_range () , Self :: AttributeValue (node) => node . get_range () , Self :: QuotedAttributeValue (node) => node . get_range () } } fn is_missing (& self) -> bool { match self { Self :: AttributeName (node) => node . is_missing () , Self :: AttributeValue (node) => node . is_missing () , Self :: QuotedAttributeValue (node) => node . is_missing () } } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for AttributeName_AttributeValue_QuotedAttributeValue { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 9u16 => Ok (Self :: AttributeName (AttributeName :: try_from ((node , db , builder , id , parent_id)) ?)) , 10u16 => Ok (Self :: AttributeValue (AttributeValue :: try_from ((node , db , builder , id , parent_id)) ?)) , 38u16 => Ok (Self :: QuotedAttributeValue (QuotedAttributeValue :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (AttributeName_AttributeValue_QuotedAttributeValue) , }) } } } # [derive (Debug , Clone , PartialEq)] pub enum Attribute_TagName { Attribute (Attribute) , TagName (TagName) } impl auto_lsp :: core :: ast :: AstNode for Attribute_TagName { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 37u16 | 17u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { Self :: Attribute (node) => node . lower () , Self :: TagName (node) => node . lower () } } fn get_id (& self) -> usize { match self { Self :: Attribute (node) => node . get_id () , Self :: TagName (node) => node . get_id () } } fn get_parent_id (& self) -> Option < usize > { match self { Self :: Attribute (node) => node . get_parent_id () , Self :: TagName (node) => node . get_parent_id () } } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { match self { Self :: Attribute (node) => node . get_range () , Self :: TagName (node) => node . get_range () } } fn is_missing (& self) -> bool { match self { Self :: Attribute (node) => node . is_missing () , Self :: TagName (node) => node . is_missing () } } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Attribute_TagName { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 37u16 => Ok (Self :: Attribute (Attribute :: try_from ((node , db , builder , id , parent_id)) ?)) , 17u16 => Ok (Self :: TagName (TagName :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (Attribute_TagName) , }) } } } # [derive (Debug , Clone , PartialEq)] pub enum Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text { Doctype (Doctype) , Element (Element) , EndTag (EndTag) , Entity (Entity) , ErroneousEndTag (ErroneousEndTag) , ScriptElement (ScriptElement) , SelfClosingTag (SelfClosingTag) , StartTag (StartTag) , StyleElement (StyleElement) , Text (Text) } impl auto_lsp :: core :: ast :: AstNode for Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 26u16 | 28u16 | 35u16 | 11u16 | 36u16 | 29u16 | 34u16 | 31u16 | 30u16 | 16u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { Self :: Doctype (node) => node . lower () , Self :: Element (node) => node . lower () , Self :: EndTag (node) => node . lower () , Self :: Entity (node) => node . lower () , Self :: ErroneousEndTag (node) => node . lower () , Self :: ScriptElement (node) => node . lower () , Self :: SelfClosingTag (node) => node . lower () , Self :: StartTag (node) => node . lower () , Self :: StyleElement (node) => node . lower () , Self :: Text (node) => node . lower () } } fn get_id (& self) -> usize { match self { Self :: Doctype (node) => node . get_id () , Self :: Element (node) => node . get_id () , Self :: EndTag (node) => node . get_id () , Self :: Entity (node) => node . get_id () , Self :: ErroneousEndTag (node) => node . get_id () , Self :: ScriptElement (node) => node . get_id () , Self :: SelfClosingTag (node) => node . get_id () , Self :: StartTag (node) => node . get_id () , Self :: StyleElement (node) => node . get_id () , Self :: Text (node) => node . get_id () } } fn get_parent_id (& self) -> Option < usize > { match self { Self :: Doctype (node) => node . get_parent_id () , Self :: Element (node) => node . get_parent_id () , Self :: EndTag (node) => node . get_parent_id () , Self :: Entity (node) => node . get_parent_id () , Self :: ErroneousEndTag (node) => node . get_parent_id () , Self :: ScriptElement (node) => node . get_parent_id () , Self :: SelfClosingTag (node) => node . get_parent_id () , Self :: StartTag (node) => node . get_parent_id () , Self :: StyleElement (node) => node . get_parent_id () , Self :: Text (node) => node . get_parent_id () } } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { match self { Self :: Doctype (node) => node . get_range () , Self :: Element (node) => node . get_range () , Self :: EndTag (node) => node . get_range () , Self :: Entity (node) => node . get_range () , Self :: ErroneousEndTag (node) => node . get_range () , Self :: ScriptElement (node) => node . get_range () , Self :: SelfClosingTag (node) => node . get_range () , Self :: StartTag (node) => node . get_range () , Self :: StyleElement (node) => node . get_range () , Self :: Text (node) => node . get_range () } } fn is_missing (& self) -> bool { match self { Self :: Doctype (node) => node . is_missing () , Self :: Element (node) => node . is_missing () , Self :: EndTag (node) => node . is_missing () , Self :: Entity (node) => node . is_missing () , Self :: ErroneousEndTag (node) => node . is_missing () , Self :: ScriptElement (node) => node . is_missing () , Self :: SelfClosingTag (node) => node . is_missing () , Self :: StartTag (node) => node . is_missing () , Self :: StyleElement (node) => node . is_missing () , Self :: Text (node) => node . is_missing () } } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 26u16 => Ok (Self :: Doctype (Doctype :: try_from ((node , db , builder , id , parent_id)) ?)) , 28u16 => Ok (Self :: Element (Element :: try_from ((node , db , builder , id , parent_id)) ?)) , 35u16 => Ok (Self :: EndTag (EndTag :: try_from ((node , db , builder , id , parent_id)) ?)) , 11u16 => Ok (Self :: Entity (Entity :: try_from ((node , db , builder , id , parent_id)) ?)) , 36u16 => Ok (Self :: ErroneousEndTag (ErroneousEndTag :: try_from ((node , db , builder , id , parent_id)) ?)) , 29u16 => Ok (Self :: ScriptElement (ScriptElement :: try_from ((node , db , builder , id , parent_id)) ?)) , 34u16 => Ok (Self :: SelfClosingTag (SelfClosingTag :: try_from ((node , db , builder , id , parent_id)) ?)) , 31u16 => Ok (Self :: StartTag (StartTag :: try_from ((node , db , builder , id , parent_id)) ?)) , 30u16 => Ok (Self :: StyleElement (StyleElement :: try_from ((node , db , builder , id , parent_id)) ?)) , 16u16 => Ok (Self :: Text (Text :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text) , }) } } } # [derive (Debug , Clone , PartialEq)] pub enum Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text { Doctype (Doctype) , Element (Element) , Entity (Entity) , ErroneousEndTag (ErroneousEndTag) , ScriptElement (ScriptElement) , StyleElement (StyleElement) , Text (Text) } impl auto_lsp :: core :: ast :: AstNode for Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 26u16 | 28u16 | 11u16 | 36u16 | 29u16 | 30u16 | 16u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { Self :: Doctype (node) => node . lower () , Self :: Element (node) => node . lower () , Self :: Entity (node) => node . lower () , Self :: ErroneousEndTag (node) => node . lower () , Self :: ScriptElement (node) => node . lower () , Self :: StyleElement (node) => node . lower () , Self :: Text (node) => node . lower () } } fn get_id (& self) -> usize { match self { Self :: Doctype (node) => node . get_id () , Self :: Element (node) => node . get_id () , Self :: Entity (node) => node . get_id () , Self :: ErroneousEndTag (node) => node . get_id () , Self :: ScriptElement (node) => node . get_id () , Self :: StyleElement (node) => node . get_id () , Self :: Text (node) => node . get_id () } } fn get_parent_id (& self) -> Option < usize > { match self { Self :: Doctype (node) => node . get_parent_id () , Self :: Element (node) => node . get_parent_id () , Self :: Entity (node) => node . get_parent_id () , Self :: ErroneousEndTag (node) => node . get_parent_id () , Self :: ScriptElement (node) => node . get_parent_id () , Self :: StyleElement (node) => node . get_parent_id () , Self :: Text (node) => node . get_parent_id () } } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { match self { Self :: Doctype (node) => node . get_range () , Self :: Element (node) => node . get_range () , Self :: Entity (node) => node . get_range () , Self :: ErroneousEndTag (node) => node . get_range () , Self :: ScriptElement (node) => node . get_range () , Self :: StyleElement (node) => node . get_range () , Self :: Text (node) => node . get_range () } } fn is_missing (& self) -> bool { match self { Self :: Doctype (node) => node . is_missing () , Self :: Element (node) => node . is_missing () , Self :: Entity (node) => node . is_missing () , Self :: ErroneousEndTag (node) => node . is_missing () , Self :: ScriptElement (node) => node . is_missing () , Self :: StyleElement (node) => node . is_missing () , Self :: Text (node) => node . is_missing () } } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 26u16 => Ok (Self :: Doctype (Doctype :: try_from ((node , db , builder , id , parent_id)) ?)) , 28u16 => Ok (Self :: Element (Element :: try_from ((node , db , builder , id , parent_id)) ?)) , 11u16 => Ok (Self :: Entity (Entity :: try_from ((node , db , builder , id , parent_id)) ?)) , 36u16 => Ok (Self :: ErroneousEndTag (ErroneousEndTag :: try_from ((node , db , builder , id , parent_id)) ?)) , 29u16 => Ok (Self :: ScriptElement (ScriptElement :: try_from ((node , db , builder , id , parent_id)) ?)) , 30u16 => Ok (Self :: StyleElement (StyleElement :: try_from ((node , db , builder , id , parent_id)) ?)) , 16u16 => Ok (Self :: Text (Text :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text) , }) } } } # [derive (Debug , Clone , PartialEq)] pub enum EndTag_RawText_StartTag { EndTag (EndTag) , RawText (RawText) , StartTag (StartTag) } impl auto_lsp :: core :: ast :: AstNode for EndTag_RawText_StartTag { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 35u16 | 23u16 | 31u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { Self :: EndTag (node) => node . lower () , Self :: RawText (node) => node . lower () , Self :: StartTag (node) => node . lower () } } fn get_id (& self) -> usize { match self { Self :: EndTag (node) => node . get_id () , Self :: RawText (node) => node . get_id () , Self :: StartTag (node) => node . get_id () } } fn get_parent_id (& self) -> Option < usize > { match self { Self :: EndTag (node) => node . get_parent_id () , Self :: RawText (node) => node . get_parent_id () , Self :: StartTag (node) => node . get_parent_id () } } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { match self { Self :: EndTag (node) => node . get_range () , Self :: RawText (node) => node . get_range () , Self :: StartTag (node) => node . get_range () } } fn is_missing (& self) -> bool { match self { Self :: EndTag (node) => node . is_missing () , Self :: RawText (node) => node . is_missing () , Self :: StartTag (node) => node . is_missing () } } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for EndTag_RawText_StartTag { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 35u16 => Ok (Self :: EndTag (EndTag :: try_from ((node , db , builder , id , parent_id)) ?)) , 23u16 => Ok (Self :: RawText (RawText :: try_from ((node , db , builder , id , parent_id)) ?)) , 31u16 => Ok (Self :: StartTag (StartTag :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (EndTag_RawText_StartTag) , }) } } }
\ No newline at end of file
+// @generated by auto-lsp-codegen 0.2.1, do not edit manually.
+// stamp: 3f3c0f4d28b190ce-add5b6c7a2c675d2
+
+#![allow(clippy::all)]
+#![allow(unused)]
+#![allow(dead_code)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+pub use auto_lsp::core::ast::ErrorNode;
+macro_rules! ast_node {
+    (
+        $name:ident, $kind:literal, $kind_id:literal, $named:literal, [$($id:literal),+],
+        fields : [$($field:ident : $field_name:literal),*], children :
+        [$($child:ident),*], lenient : [$($lenient:ident),*]
+    ) => {
+        impl auto_lsp::core::ast::AstNode for $name { fn contains(node : &
+        auto_lsp::core::tree_sitter::Node) -> bool { matches!(node.kind_id(), $($id)|+) }
+        fn verify_language(language : & auto_lsp::core::tree_sitter::Language) -> Result
+        < (), Vec < auto_lsp::core::errors::Mismatch >> { verify_language(language) } fn
+        lower(& self) -> & dyn auto_lsp::core::ast::AstNode { self } fn get_id(& self) ->
+        usize { self._id } fn get_parent_id(& self) -> Option < usize > { self._parent }
+        fn get_children_ids(& self) -> auto_lsp::core::ast::ChildrenIds { let mut ids =
+        auto_lsp::core::ast::ChildrenIds::new();
+        $(auto_lsp::core::ast::NodeField::push_ids(& self. $field, & mut ids);)*
+        $(auto_lsp::core::ast::NodeField::push_ids(& self. $child, & mut ids);)* ids
+        .sort_unstable(); ids } fn get_span(& self) -> auto_lsp::core::ast::Span { self
+        ._span } fn get_kind_name(& self) -> & 'static str { $kind } fn get_kind_id(&
+        self) -> u16 { $kind_id } fn is_named(& self) -> bool { $named } fn
+        get_field_name(& self, id : usize) -> Option < & 'static str > { $(if
+        auto_lsp::core::ast::NodeField::holds(& self. $field, id) { return
+        Some($field_name); })* None } fn relocate(& mut self, relocation : &
+        auto_lsp::core::ast::Relocation) { self._parent = relocation.parent(self._id,
+        self._parent); self._id = relocation.id(self._id); self._span = relocation
+        .span(self._span); $(auto_lsp::core::ast::NodeField::relocate(& mut self. $field,
+        relocation);)* $(auto_lsp::core::ast::NodeField::relocate(& mut self. $child,
+        relocation);)* } fn is_missing(& self) -> bool { self._is_missing } fn
+        has_all_required_fields(& self) -> bool { true $(&& self. $lenient .is_ok())* } }
+        impl $name { pub fn kind(& self) -> NodeKind { NodeKind:: $name } #[doc =
+        r" Returns a view of this node, to navigate its fields without passing the AST around."]
+        pub fn view < 'a > (& 'a self, nodes : & 'a auto_lsp::core::ast::AstArena) ->
+        auto_lsp::core::ast::AstView < 'a, Self > {
+        auto_lsp::core::ast::AstView::new(self, nodes) } }
+    };
+}
+macro_rules! ast_leaf {
+    (
+        $name:ident, $kind:literal, $kind_id:literal, $named:literal, [$($id:literal),+]
+    ) => {
+        #[derive(Debug, Clone, PartialEq)] pub struct $name { _span :
+        auto_lsp::core::ast::Span, _id : usize, _parent : Option < usize >, _is_missing :
+        bool, } ast_node!($name, $kind, $kind_id, $named, [$($id),+], fields : [],
+        children : [], lenient : []); impl < 'a > TryFrom <
+        auto_lsp::core::ast::TryFromParams < 'a >> for $name { type Error =
+        auto_lsp::core::errors::AstError; fn try_from((node, db, builder, id, parent_id)
+        : auto_lsp::core::ast::TryFromParams) -> Result < Self,
+        auto_lsp::core::errors::AstError > { Ok(Self { _span :
+        auto_lsp::core::ast::Span::from(node), _id : id, _parent : parent_id, _is_missing
+        : node.is_missing(), }) } }
+    };
+}
+macro_rules! ast_enum {
+    ($name:ident, [$($variant:ident),+], [$($id:literal),+]) => {
+        impl auto_lsp::core::ast::AstNode for $name { fn contains(node : &
+        auto_lsp::core::tree_sitter::Node) -> bool { matches!(node.kind_id(), $($id)|+) }
+        fn verify_language(language : & auto_lsp::core::tree_sitter::Language) -> Result
+        < (), Vec < auto_lsp::core::errors::Mismatch >> { verify_language(language) } fn
+        lower(& self) -> & dyn auto_lsp::core::ast::AstNode { match self { $(Self::
+        $variant (node) => node.lower()),+ } } fn get_id(& self) -> usize { match self {
+        $(Self:: $variant (node) => node.get_id()),+ } } fn get_parent_id(& self) ->
+        Option < usize > { match self { $(Self:: $variant (node) => node
+        .get_parent_id()),+ } } fn get_children_ids(& self) ->
+        auto_lsp::core::ast::ChildrenIds { match self { $(Self:: $variant (node) => node
+        .get_children_ids()),+ } } fn get_span(& self) -> auto_lsp::core::ast::Span {
+        match self { $(Self:: $variant (node) => node.get_span()),+ } } fn
+        get_kind_name(& self) -> & 'static str { match self { $(Self:: $variant (node) =>
+        node.get_kind_name()),+ } } fn get_kind_id(& self) -> u16 { match self { $(Self::
+        $variant (node) => node.get_kind_id()),+ } } fn is_named(& self) -> bool { match
+        self { $(Self:: $variant (node) => node.is_named()),+ } } fn get_field_name(&
+        self, id : usize) -> Option < & 'static str > { match self { $(Self:: $variant
+        (node) => node.get_field_name(id)),+ } } fn relocate(& mut self, relocation : &
+        auto_lsp::core::ast::Relocation) { match self { $(Self:: $variant (node) => node
+        .relocate(relocation)),+ } } fn is_missing(& self) -> bool { match self {
+        $(Self:: $variant (node) => node.is_missing()),+ } } fn has_all_required_fields(&
+        self) -> bool { match self { $(Self:: $variant (node) => node
+        .has_all_required_fields()),+ } } } impl $name { pub fn kind(& self) -> NodeKind
+        { match self { $(Self:: $variant (node) => node.kind()),+ } } #[doc =
+        r" Returns a view of this node, to navigate its fields without passing the AST around."]
+        pub fn view < 'a > (& 'a self, nodes : & 'a auto_lsp::core::ast::AstArena) ->
+        auto_lsp::core::ast::AstView < 'a, Self > {
+        auto_lsp::core::ast::AstView::new(self, nodes) } }
+    };
+}
+pub mod structs;
+pub use structs::*;
+pub mod enums;
+pub use enums::*;
+pub mod supertypes;
+pub use supertypes::*;
+pub mod tokens;
+pub use tokens::*;
+pub mod operators;
+pub use operators::*;
+impl AttributeName_AttributeValue_QuotedAttributeValue {
+    pub fn is_attribute_name(&self) -> bool {
+        self.as_attribute_name().is_some()
+    }
+    pub fn as_attribute_name(&self) -> Option<&AttributeName> {
+        match self {
+            Self::AttributeName(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_attribute_value(&self) -> bool {
+        self.as_attribute_value().is_some()
+    }
+    pub fn as_attribute_value(&self) -> Option<&AttributeValue> {
+        match self {
+            Self::AttributeValue(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_quoted_attribute_value(&self) -> bool {
+        self.as_quoted_attribute_value().is_some()
+    }
+    pub fn as_quoted_attribute_value(&self) -> Option<&QuotedAttributeValue> {
+        match self {
+            Self::QuotedAttributeValue(node) => Some(node),
+            _ => None,
+        }
+    }
+}
+impl Attribute_TagName {
+    pub fn is_attribute(&self) -> bool {
+        self.as_attribute().is_some()
+    }
+    pub fn as_attribute(&self) -> Option<&Attribute> {
+        match self {
+            Self::Attribute(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_tag_name(&self) -> bool {
+        self.as_tag_name().is_some()
+    }
+    pub fn as_tag_name(&self) -> Option<&TagName> {
+        match self {
+            Self::TagName(node) => Some(node),
+            _ => None,
+        }
+    }
+}
+impl Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text {
+    pub fn is_doctype(&self) -> bool {
+        self.as_doctype().is_some()
+    }
+    pub fn as_doctype(&self) -> Option<&Doctype> {
+        match self {
+            Self::Doctype(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_element(&self) -> bool {
+        self.as_element().is_some()
+    }
+    pub fn as_element(&self) -> Option<&Element> {
+        match self {
+            Self::Element(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_end_tag(&self) -> bool {
+        self.as_end_tag().is_some()
+    }
+    pub fn as_end_tag(&self) -> Option<&EndTag> {
+        match self {
+            Self::EndTag(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_entity(&self) -> bool {
+        self.as_entity().is_some()
+    }
+    pub fn as_entity(&self) -> Option<&Entity> {
+        match self {
+            Self::Entity(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_erroneous_end_tag(&self) -> bool {
+        self.as_erroneous_end_tag().is_some()
+    }
+    pub fn as_erroneous_end_tag(&self) -> Option<&ErroneousEndTag> {
+        match self {
+            Self::ErroneousEndTag(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_script_element(&self) -> bool {
+        self.as_script_element().is_some()
+    }
+    pub fn as_script_element(&self) -> Option<&ScriptElement> {
+        match self {
+            Self::ScriptElement(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_self_closing_tag(&self) -> bool {
+        self.as_self_closing_tag().is_some()
+    }
+    pub fn as_self_closing_tag(&self) -> Option<&SelfClosingTag> {
+        match self {
+            Self::SelfClosingTag(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_start_tag(&self) -> bool {
+        self.as_start_tag().is_some()
+    }
+    pub fn as_start_tag(&self) -> Option<&StartTag> {
+        match self {
+            Self::StartTag(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_style_element(&self) -> bool {
+        self.as_style_element().is_some()
+    }
+    pub fn as_style_element(&self) -> Option<&StyleElement> {
+        match self {
+            Self::StyleElement(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_text(&self) -> bool {
+        self.as_text().is_some()
+    }
+    pub fn as_text(&self) -> Option<&Text> {
+        match self {
+            Self::Text(node) => Some(node),
+            _ => None,
+        }
+    }
+}
+impl Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text {
+    pub fn is_doctype(&self) -> bool {
+        self.as_doctype().is_some()
+    }
+    pub fn as_doctype(&self) -> Option<&Doctype> {
+        match self {
+            Self::Doctype(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_element(&self) -> bool {
+        self.as_element().is_some()
+    }
+    pub fn as_element(&self) -> Option<&Element> {
+        match self {
+            Self::Element(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_entity(&self) -> bool {
+        self.as_entity().is_some()
+    }
+    pub fn as_entity(&self) -> Option<&Entity> {
+        match self {
+            Self::Entity(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_erroneous_end_tag(&self) -> bool {
+        self.as_erroneous_end_tag().is_some()
+    }
+    pub fn as_erroneous_end_tag(&self) -> Option<&ErroneousEndTag> {
+        match self {
+            Self::ErroneousEndTag(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_script_element(&self) -> bool {
+        self.as_script_element().is_some()
+    }
+    pub fn as_script_element(&self) -> Option<&ScriptElement> {
+        match self {
+            Self::ScriptElement(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_style_element(&self) -> bool {
+        self.as_style_element().is_some()
+    }
+    pub fn as_style_element(&self) -> Option<&StyleElement> {
+        match self {
+            Self::StyleElement(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_text(&self) -> bool {
+        self.as_text().is_some()
+    }
+    pub fn as_text(&self) -> Option<&Text> {
+        match self {
+            Self::Text(node) => Some(node),
+            _ => None,
+        }
+    }
+}
+impl EndTag_RawText_StartTag {
+    pub fn is_end_tag(&self) -> bool {
+        self.as_end_tag().is_some()
+    }
+    pub fn as_end_tag(&self) -> Option<&EndTag> {
+        match self {
+            Self::EndTag(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_raw_text(&self) -> bool {
+        self.as_raw_text().is_some()
+    }
+    pub fn as_raw_text(&self) -> Option<&RawText> {
+        match self {
+            Self::RawText(node) => Some(node),
+            _ => None,
+        }
+    }
+    pub fn is_start_tag(&self) -> bool {
+        self.as_start_tag().is_some()
+    }
+    pub fn as_start_tag(&self) -> Option<&StartTag> {
+        match self {
+            Self::StartTag(node) => Some(node),
+            _ => None,
+        }
+    }
+}
+pub fn walk_error_node<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &ErrorNode,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+/// A visitor over the typed AST.
+///
+/// Each node type has a `visit_<node>` method which, by default, calls the matching `walk_<node>` function
+/// to visit its children in source order.
+///
+/// Override a `visit_<node>` method to act on a specific node kind, and call `walk_<node>` to keep descending.
+///
+/// [`Visitor::enter`] and [`Visitor::leave`] are called around every struct node,
+/// returning [`std::ops::ControlFlow::Break`] from `enter` skips the node's subtree (and its `leave` call).
+pub trait Visitor {
+    fn enter(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &dyn auto_lsp::core::ast::AstNode,
+    ) -> std::ops::ControlFlow<()> {
+        std::ops::ControlFlow::Continue(())
+    }
+    fn leave(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &dyn auto_lsp::core::ast::AstNode,
+    ) {}
+    fn visit_attribute(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Attribute,
+    ) {
+        walk_attribute(self, nodes, node)
+    }
+    fn visit_doctype(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &Doctype) {
+        walk_doctype(self, nodes, node)
+    }
+    fn visit_document(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Document,
+    ) {
+        walk_document(self, nodes, node)
+    }
+    fn visit_element(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &Element) {
+        walk_element(self, nodes, node)
+    }
+    fn visit_end_tag(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &EndTag) {
+        walk_end_tag(self, nodes, node)
+    }
+    fn visit_erroneous_end_tag(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &ErroneousEndTag,
+    ) {
+        walk_erroneous_end_tag(self, nodes, node)
+    }
+    fn visit_quoted_attribute_value(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &QuotedAttributeValue,
+    ) {
+        walk_quoted_attribute_value(self, nodes, node)
+    }
+    fn visit_script_element(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &ScriptElement,
+    ) {
+        walk_script_element(self, nodes, node)
+    }
+    fn visit_self_closing_tag(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &SelfClosingTag,
+    ) {
+        walk_self_closing_tag(self, nodes, node)
+    }
+    fn visit_start_tag(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &StartTag,
+    ) {
+        walk_start_tag(self, nodes, node)
+    }
+    fn visit_style_element(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &StyleElement,
+    ) {
+        walk_style_element(self, nodes, node)
+    }
+    fn visit_token_double_quote(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_DoubleQuote,
+    ) {
+        walk_token_double_quote(self, nodes, node)
+    }
+    fn visit_token_quote(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_Quote,
+    ) {
+        walk_token_quote(self, nodes, node)
+    }
+    fn visit_token_slash_greater(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_SlashGreater,
+    ) {
+        walk_token_slash_greater(self, nodes, node)
+    }
+    fn visit_token_less(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_Less,
+    ) {
+        walk_token_less(self, nodes, node)
+    }
+    fn visit_token_less_bang(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_LessBang,
+    ) {
+        walk_token_less_bang(self, nodes, node)
+    }
+    fn visit_token_less_slash(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_LessSlash,
+    ) {
+        walk_token_less_slash(self, nodes, node)
+    }
+    fn visit_token_equal(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_Equal,
+    ) {
+        walk_token_equal(self, nodes, node)
+    }
+    fn visit_token_greater(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_Greater,
+    ) {
+        walk_token_greater(self, nodes, node)
+    }
+    fn visit_attribute_name(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &AttributeName,
+    ) {
+        walk_attribute_name(self, nodes, node)
+    }
+    fn visit_attribute_value(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &AttributeValue,
+    ) {
+        walk_attribute_value(self, nodes, node)
+    }
+    fn visit_comment(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &Comment) {
+        walk_comment(self, nodes, node)
+    }
+    fn visit_token_doctype(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Token_doctype,
+    ) {
+        walk_token_doctype(self, nodes, node)
+    }
+    fn visit_entity(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &Entity) {
+        walk_entity(self, nodes, node)
+    }
+    fn visit_erroneous_end_tag_name(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &ErroneousEndTagName,
+    ) {
+        walk_erroneous_end_tag_name(self, nodes, node)
+    }
+    fn visit_raw_text(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &RawText) {
+        walk_raw_text(self, nodes, node)
+    }
+    fn visit_tag_name(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &TagName) {
+        walk_tag_name(self, nodes, node)
+    }
+    fn visit_text(&mut self, nodes: &auto_lsp::core::ast::AstArena, node: &Text) {
+        walk_text(self, nodes, node)
+    }
+    fn visit_attribute_name_attribute_value_quoted_attribute_value(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &AttributeName_AttributeValue_QuotedAttributeValue,
+    ) {
+        walk_attribute_name_attribute_value_quoted_attribute_value(self, nodes, node)
+    }
+    fn visit_attribute_tag_name(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Attribute_TagName,
+    ) {
+        walk_attribute_tag_name(self, nodes, node)
+    }
+    fn visit_doctype_element_end_tag_entity_erroneous_end_tag_script_element_self_closing_tag_start_tag_style_element_text(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text,
+    ) {
+        walk_doctype_element_end_tag_entity_erroneous_end_tag_script_element_self_closing_tag_start_tag_style_element_text(
+            self,
+            nodes,
+            node,
+        )
+    }
+    fn visit_doctype_element_entity_erroneous_end_tag_script_element_style_element_text(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text,
+    ) {
+        walk_doctype_element_entity_erroneous_end_tag_script_element_style_element_text(
+            self,
+            nodes,
+            node,
+        )
+    }
+    fn visit_end_tag_raw_text_start_tag(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &EndTag_RawText_StartTag,
+    ) {
+        walk_end_tag_raw_text_start_tag(self, nodes, node)
+    }
+    fn visit_error_node(
+        &mut self,
+        nodes: &auto_lsp::core::ast::AstArena,
+        node: &ErrorNode,
+    ) {
+        walk_error_node(self, nodes, node)
+    }
+}
+/// Kind of a node, with a variant for each generated struct.
+///
+/// Enums share the kind of the struct they wrap, and `ErrorNode` stands for the nodes that could not be built.
+///
+/// Matching on a `NodeKind` is exhaustive, and its discriminant can index tables of `NodeKind::COUNT` entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u16)]
+pub enum NodeKind {
+    ErrorNode = auto_lsp::core::ast::ErrorNode::KIND_ID,
+    Attribute = 1u16,
+    Doctype = 2u16,
+    Document = 3u16,
+    Element = 4u16,
+    EndTag = 5u16,
+    ErroneousEndTag = 6u16,
+    QuotedAttributeValue = 7u16,
+    ScriptElement = 8u16,
+    SelfClosingTag = 9u16,
+    StartTag = 10u16,
+    StyleElement = 11u16,
+    Token_DoubleQuote = 12u16,
+    Token_Quote = 13u16,
+    Token_SlashGreater = 14u16,
+    Token_Less = 15u16,
+    Token_LessBang = 16u16,
+    Token_LessSlash = 17u16,
+    Token_Equal = 18u16,
+    Token_Greater = 19u16,
+    AttributeName = 20u16,
+    AttributeValue = 21u16,
+    Comment = 22u16,
+    Token_doctype = 23u16,
+    Entity = 24u16,
+    ErroneousEndTagName = 25u16,
+    RawText = 26u16,
+    TagName = 27u16,
+    Text = 28u16,
+}
+impl NodeKind {
+    /// Number of kinds, error nodes included
+    pub const COUNT: usize = 29usize;
+    /// Every kind, indexed by discriminant
+    pub const ALL: [NodeKind; 29usize] = [
+        NodeKind::ErrorNode,
+        NodeKind::Attribute,
+        NodeKind::Doctype,
+        NodeKind::Document,
+        NodeKind::Element,
+        NodeKind::EndTag,
+        NodeKind::ErroneousEndTag,
+        NodeKind::QuotedAttributeValue,
+        NodeKind::ScriptElement,
+        NodeKind::SelfClosingTag,
+        NodeKind::StartTag,
+        NodeKind::StyleElement,
+        NodeKind::Token_DoubleQuote,
+        NodeKind::Token_Quote,
+        NodeKind::Token_SlashGreater,
+        NodeKind::Token_Less,
+        NodeKind::Token_LessBang,
+        NodeKind::Token_LessSlash,
+        NodeKind::Token_Equal,
+        NodeKind::Token_Greater,
+        NodeKind::AttributeName,
+        NodeKind::AttributeValue,
+        NodeKind::Comment,
+        NodeKind::Token_doctype,
+        NodeKind::Entity,
+        NodeKind::ErroneousEndTagName,
+        NodeKind::RawText,
+        NodeKind::TagName,
+        NodeKind::Text,
+    ];
+    /// Returns the kind of the given node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node was not generated along with this enum.
+    pub fn of(node: &dyn auto_lsp::core::ast::AstNode) -> Self {
+        match Self::try_from(node.get_kind_id()) {
+            Ok(kind) => kind,
+            Err(id) => panic!("Unknown node kind {id}"),
+        }
+    }
+    /// Returns the tree-sitter kind of the nodes of this kind, `ERROR` for error nodes.
+    pub fn name(self) -> &'static str {
+        match self {
+            NodeKind::ErrorNode => auto_lsp::core::ast::ErrorNode::KIND_NAME,
+            NodeKind::Attribute => "attribute",
+            NodeKind::Doctype => "doctype",
+            NodeKind::Document => "document",
+            NodeKind::Element => "element",
+            NodeKind::EndTag => "end_tag",
+            NodeKind::ErroneousEndTag => "erroneous_end_tag",
+            NodeKind::QuotedAttributeValue => "quoted_attribute_value",
+            NodeKind::ScriptElement => "script_element",
+            NodeKind::SelfClosingTag => "self_closing_tag",
+            NodeKind::StartTag => "start_tag",
+            NodeKind::StyleElement => "style_element",
+            NodeKind::Token_DoubleQuote => "\"",
+            NodeKind::Token_Quote => "'",
+            NodeKind::Token_SlashGreater => "/>",
+            NodeKind::Token_Less => "<",
+            NodeKind::Token_LessBang => "<!",
+            NodeKind::Token_LessSlash => "</",
+            NodeKind::Token_Equal => "=",
+            NodeKind::Token_Greater => ">",
+            NodeKind::AttributeName => "attribute_name",
+            NodeKind::AttributeValue => "attribute_value",
+            NodeKind::Comment => "comment",
+            NodeKind::Token_doctype => "doctype",
+            NodeKind::Entity => "entity",
+            NodeKind::ErroneousEndTagName => "erroneous_end_tag_name",
+            NodeKind::RawText => "raw_text",
+            NodeKind::TagName => "tag_name",
+            NodeKind::Text => "text",
+        }
+    }
+}
+impl TryFrom<u16> for NodeKind {
+    type Error = u16;
+    fn try_from(id: u16) -> Result<Self, u16> {
+        Self::ALL.get(id as usize).copied().ok_or(id)
+    }
+}
+impl From<NodeKind> for u16 {
+    fn from(kind: NodeKind) -> u16 {
+        kind as u16
+    }
+}
+/// Checks that the node kind and field ids of this AST match the given language.
+///
+/// Fails when the AST was generated from another version of the grammar.
+pub fn verify_language(
+    language: &auto_lsp::core::tree_sitter::Language,
+) -> Result<(), Vec<auto_lsp::core::errors::Mismatch>> {
+    auto_lsp::core::errors::Mismatch::check(
+        language,
+        &[
+            ("attribute", true, 37u16),
+            ("attribute_name", true, 9u16),
+            ("attribute_value", true, 10u16),
+            ("attribute_value", true, 13u16),
+            ("attribute_value", true, 15u16),
+            ("comment", true, 24u16),
+            ("doctype", true, 26u16),
+            ("document", true, 25u16),
+            ("element", true, 28u16),
+            ("end_tag", true, 35u16),
+            ("entity", true, 11u16),
+            ("erroneous_end_tag", true, 36u16),
+            ("erroneous_end_tag_name", true, 21u16),
+            ("quoted_attribute_value", true, 38u16),
+            ("raw_text", true, 23u16),
+            ("script_element", true, 29u16),
+            ("self_closing_tag", true, 34u16),
+            ("start_tag", true, 31u16),
+            ("start_tag", true, 32u16),
+            ("start_tag", true, 33u16),
+            ("style_element", true, 30u16),
+            ("tag_name", true, 17u16),
+            ("tag_name", true, 18u16),
+            ("tag_name", true, 19u16),
+            ("tag_name", true, 20u16),
+            ("text", true, 16u16),
+            ("\"", false, 14u16),
+            ("'", false, 12u16),
+            ("/>", false, 6u16),
+            ("<", false, 5u16),
+            ("<!", false, 1u16),
+            ("</", false, 7u16),
+            ("=", false, 8u16),
+            (">", false, 3u16),
+            ("doctype", false, 4u16),
+        ],
+        &[],
+    )
+}
@@ -0,0 +1,413 @@
+// @generated by auto-lsp-codegen 0.2.1, do not edit manually.
+// stamp: 3f3c0f4d28b190ce-aa397c8d1e3fd204
+
+use super::*;
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeName_AttributeValue_QuotedAttributeValue {
+    AttributeName(AttributeName),
+    AttributeValue(AttributeValue),
+    QuotedAttributeValue(QuotedAttributeValue),
+}
+ast_enum!(
+    AttributeName_AttributeValue_QuotedAttributeValue, [AttributeName, AttributeValue,
+    QuotedAttributeValue], [9u16, 10u16, 13u16, 15u16, 38u16]
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>>
+for AttributeName_AttributeValue_QuotedAttributeValue {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        match node.kind_id() {
+            9u16 => {
+                Ok(
+                    Self::AttributeName(
+                        AttributeName::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            10u16 | 13u16 | 15u16 => {
+                Ok(
+                    Self::AttributeValue(
+                        AttributeValue::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            38u16 => {
+                Ok(
+                    Self::QuotedAttributeValue(
+                        QuotedAttributeValue::try_from((
+                            node,
+                            db,
+                            builder,
+                            id,
+                            parent_id,
+                        ))?,
+                    ),
+                )
+            }
+            _ => {
+                Err(auto_lsp::core::errors::AstError::UnexpectedChild {
+                    range: node.range(),
+                    parent: node.parent().map_or("", |parent| parent.kind()),
+                    found: node.kind(),
+                    expected: &[
+                        "attribute_name",
+                        "attribute_value",
+                        "quoted_attribute_value",
+                    ],
+                })
+            }
+        }
+    }
+}
+pub fn walk_attribute_name_attribute_value_quoted_attribute_value<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &AttributeName_AttributeValue_QuotedAttributeValue,
+) {
+    match node {
+        AttributeName_AttributeValue_QuotedAttributeValue::AttributeName(node) => {
+            visitor.visit_attribute_name(nodes, node)
+        }
+        AttributeName_AttributeValue_QuotedAttributeValue::AttributeValue(node) => {
+            visitor.visit_attribute_value(nodes, node)
+        }
+        AttributeName_AttributeValue_QuotedAttributeValue::QuotedAttributeValue(node) => {
+            visitor.visit_quoted_attribute_value(nodes, node)
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attribute_TagName {
+    Attribute(Attribute),
+    TagName(TagName),
+}
+ast_enum!(Attribute_TagName, [Attribute, TagName], [37u16, 17u16, 18u16, 19u16, 20u16]);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for Attribute_TagName {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        match node.kind_id() {
+            37u16 => {
+                Ok(
+                    Self::Attribute(
+                        Attribute::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            17u16 | 18u16 | 19u16 | 20u16 => {
+                Ok(Self::TagName(TagName::try_from((node, db, builder, id, parent_id))?))
+            }
+            _ => {
+                Err(auto_lsp::core::errors::AstError::UnexpectedChild {
+                    range: node.range(),
+                    parent: node.parent().map_or("", |parent| parent.kind()),
+                    found: node.kind(),
+                    expected: &["attribute", "tag_name"],
+                })
+            }
+        }
+    }
+}
+pub fn walk_attribute_tag_name<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Attribute_TagName,
+) {
+    match node {
+        Attribute_TagName::Attribute(node) => visitor.visit_attribute(nodes, node),
+        Attribute_TagName::TagName(node) => visitor.visit_tag_name(nodes, node),
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text {
+    Doctype(Doctype),
+    Element(Element),
+    EndTag(EndTag),
+    Entity(Entity),
+    ErroneousEndTag(ErroneousEndTag),
+    ScriptElement(ScriptElement),
+    SelfClosingTag(SelfClosingTag),
+    StartTag(StartTag),
+    StyleElement(StyleElement),
+    Text(Text),
+}
+ast_enum!(
+    Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text,
+    [Doctype, Element, EndTag, Entity, ErroneousEndTag, ScriptElement, SelfClosingTag,
+    StartTag, StyleElement, Text], [26u16, 28u16, 35u16, 11u16, 36u16, 29u16, 34u16,
+    31u16, 32u16, 33u16, 30u16, 16u16]
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>>
+for Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        match node.kind_id() {
+            26u16 => {
+                Ok(Self::Doctype(Doctype::try_from((node, db, builder, id, parent_id))?))
+            }
+            28u16 => {
+                Ok(Self::Element(Element::try_from((node, db, builder, id, parent_id))?))
+            }
+            35u16 => {
+                Ok(Self::EndTag(EndTag::try_from((node, db, builder, id, parent_id))?))
+            }
+            11u16 => {
+                Ok(Self::Entity(Entity::try_from((node, db, builder, id, parent_id))?))
+            }
+            36u16 => {
+                Ok(
+                    Self::ErroneousEndTag(
+                        ErroneousEndTag::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            29u16 => {
+                Ok(
+                    Self::ScriptElement(
+                        ScriptElement::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            34u16 => {
+                Ok(
+                    Self::SelfClosingTag(
+                        SelfClosingTag::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            31u16 | 32u16 | 33u16 => {
+                Ok(
+                    Self::StartTag(
+                        StartTag::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            30u16 => {
+                Ok(
+                    Self::StyleElement(
+                        StyleElement::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            16u16 => Ok(Self::Text(Text::try_from((node, db, builder, id, parent_id))?)),
+            _ => {
+                Err(auto_lsp::core::errors::AstError::UnexpectedChild {
+                    range: node.range(),
+                    parent: node.parent().map_or("", |parent| parent.kind()),
+                    found: node.kind(),
+                    expected: &[
+                        "doctype",
+                        "element",
+                        "end_tag",
+                        "entity",
+                        "erroneous_end_tag",
+                        "script_element",
+                        "self_closing_tag",
+                        "start_tag",
+                        "style_element",
+                        "text",
+                    ],
+                })
+            }
+        }
+    }
+}
+pub fn walk_doctype_element_end_tag_entity_erroneous_end_tag_script_element_self_closing_tag_start_tag_style_element_text<
+    V: Visitor + ?Sized,
+>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text,
+) {
+    match node {
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::Doctype(
+            node,
+        ) => visitor.visit_doctype(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::Element(
+            node,
+        ) => visitor.visit_element(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::EndTag(
+            node,
+        ) => visitor.visit_end_tag(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::Entity(
+            node,
+        ) => visitor.visit_entity(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::ErroneousEndTag(
+            node,
+        ) => visitor.visit_erroneous_end_tag(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::ScriptElement(
+            node,
+        ) => visitor.visit_script_element(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::SelfClosingTag(
+            node,
+        ) => visitor.visit_self_closing_tag(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::StartTag(
+            node,
+        ) => visitor.visit_start_tag(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::StyleElement(
+            node,
+        ) => visitor.visit_style_element(nodes, node),
+        Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text::Text(
+            node,
+        ) => visitor.visit_text(nodes, node),
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text {
+    Doctype(Doctype),
+    Element(Element),
+    Entity(Entity),
+    ErroneousEndTag(ErroneousEndTag),
+    ScriptElement(ScriptElement),
+    StyleElement(StyleElement),
+    Text(Text),
+}
+ast_enum!(
+    Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text, [Doctype,
+    Element, Entity, ErroneousEndTag, ScriptElement, StyleElement, Text], [26u16, 28u16,
+    11u16, 36u16, 29u16, 30u16, 16u16]
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>>
+for Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        match node.kind_id() {
+            26u16 => {
+                Ok(Self::Doctype(Doctype::try_from((node, db, builder, id, parent_id))?))
+            }
+            28u16 => {
+                Ok(Self::Element(Element::try_from((node, db, builder, id, parent_id))?))
+            }
+            11u16 => {
+                Ok(Self::Entity(Entity::try_from((node, db, builder, id, parent_id))?))
+            }
+            36u16 => {
+                Ok(
+                    Self::ErroneousEndTag(
+                        ErroneousEndTag::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            29u16 => {
+                Ok(
+                    Self::ScriptElement(
+                        ScriptElement::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            30u16 => {
+                Ok(
+                    Self::StyleElement(
+                        StyleElement::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            16u16 => Ok(Self::Text(Text::try_from((node, db, builder, id, parent_id))?)),
+            _ => {
+                Err(auto_lsp::core::errors::AstError::UnexpectedChild {
+                    range: node.range(),
+                    parent: node.parent().map_or("", |parent| parent.kind()),
+                    found: node.kind(),
+                    expected: &[
+                        "doctype",
+                        "element",
+                        "entity",
+                        "erroneous_end_tag",
+                        "script_element",
+                        "style_element",
+                        "text",
+                    ],
+                })
+            }
+        }
+    }
+}
+pub fn walk_doctype_element_entity_erroneous_end_tag_script_element_style_element_text<
+    V: Visitor + ?Sized,
+>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text,
+) {
+    match node {
+        Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text::Doctype(
+            node,
+        ) => visitor.visit_doctype(nodes, node),
+        Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text::Element(
+            node,
+        ) => visitor.visit_element(nodes, node),
+        Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text::Entity(
+            node,
+        ) => visitor.visit_entity(nodes, node),
+        Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text::ErroneousEndTag(
+            node,
+        ) => visitor.visit_erroneous_end_tag(nodes, node),
+        Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text::ScriptElement(
+            node,
+        ) => visitor.visit_script_element(nodes, node),
+        Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text::StyleElement(
+            node,
+        ) => visitor.visit_style_element(nodes, node),
+        Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text::Text(
+            node,
+        ) => visitor.visit_text(nodes, node),
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum EndTag_RawText_StartTag {
+    EndTag(EndTag),
+    RawText(RawText),
+    StartTag(StartTag),
+}
+ast_enum!(
+    EndTag_RawText_StartTag, [EndTag, RawText, StartTag], [35u16, 23u16, 31u16, 32u16,
+    33u16]
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for EndTag_RawText_StartTag {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        match node.kind_id() {
+            35u16 => {
+                Ok(Self::EndTag(EndTag::try_from((node, db, builder, id, parent_id))?))
+            }
+            23u16 => {
+                Ok(Self::RawText(RawText::try_from((node, db, builder, id, parent_id))?))
+            }
+            31u16 | 32u16 | 33u16 => {
+                Ok(
+                    Self::StartTag(
+                        StartTag::try_from((node, db, builder, id, parent_id))?,
+                    ),
+                )
+            }
+            _ => {
+                Err(auto_lsp::core::errors::AstError::UnexpectedChild {
+                    range: node.range(),
+                    parent: node.parent().map_or("", |parent| parent.kind()),
+                    found: node.kind(),
+                    expected: &["end_tag", "raw_text", "start_tag"],
+                })
+            }
+        }
+    }
+}
+pub fn walk_end_tag_raw_text_start_tag<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &EndTag_RawText_StartTag,
+) {
+    match node {
+        EndTag_RawText_StartTag::EndTag(node) => visitor.visit_end_tag(nodes, node),
+        EndTag_RawText_StartTag::RawText(node) => visitor.visit_raw_text(nodes, node),
+        EndTag_RawText_StartTag::StartTag(node) => visitor.visit_start_tag(nodes, node),
+    }
+}
@@ -0,0 +1,4 @@
+// @generated by auto-lsp-codegen 0.2.1, do not edit manually.
+// stamp: 3f3c0f4d28b190ce-976cf289d040da48
+
+use super::*;
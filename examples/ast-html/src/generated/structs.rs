@@ -0,0 +1,901 @@
+// @generated by auto-lsp-codegen 0.2.1, do not edit manually.
+// stamp: 3f3c0f4d28b190ce-8d93df0b69cb6b77
+
+use super::*;
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    pub children: Vec<
+        auto_lsp::core::ast::AstNodeId<AttributeName_AttributeValue_QuotedAttributeValue>,
+    >,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    Attribute, "attribute", 1u16, true, [37u16], fields : [], children : [children,
+    errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for Attribute {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = vec![];
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_vec_children_id(&mut children) });
+        Ok(Self {
+            children,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_attribute<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Attribute,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child
+            .downcast_ref::<AttributeName_AttributeValue_QuotedAttributeValue>()
+        {
+            visitor
+                .visit_attribute_name_attribute_value_quoted_attribute_value(
+                    nodes,
+                    child,
+                );
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<Attribute>`
+pub trait AttributeView<'a> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<
+            'a,
+            AttributeName_AttributeValue_QuotedAttributeValue,
+        >,
+    > + ExactSizeIterator + 'a;
+}
+impl<'a> AttributeView<'a> for auto_lsp::core::ast::AstView<'a, Attribute> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<
+            'a,
+            AttributeName_AttributeValue_QuotedAttributeValue,
+        >,
+    > + ExactSizeIterator + 'a {
+        self.node()
+            .children
+            .iter()
+            .map(move |node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+ast_leaf!(Doctype, "doctype", 2u16, true, [26u16]);
+pub fn walk_doctype<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Doctype,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub children: Vec<
+        auto_lsp::core::ast::AstNodeId<
+            Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text,
+        >,
+    >,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    Document, "document", 3u16, true, [25u16], fields : [], children : [children,
+    errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for Document {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = vec![];
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_vec_children_id(&mut children) });
+        Ok(Self {
+            children,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_document<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Document,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child
+            .downcast_ref::<
+                Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text,
+            >()
+        {
+            visitor
+                .visit_doctype_element_entity_erroneous_end_tag_script_element_style_element_text(
+                    nodes,
+                    child,
+                );
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<Document>`
+pub trait DocumentView<'a> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<
+            'a,
+            Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text,
+        >,
+    > + ExactSizeIterator + 'a;
+}
+impl<'a> DocumentView<'a> for auto_lsp::core::ast::AstView<'a, Document> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<
+            'a,
+            Doctype_Element_Entity_ErroneousEndTag_ScriptElement_StyleElement_Text,
+        >,
+    > + ExactSizeIterator + 'a {
+        self.node()
+            .children
+            .iter()
+            .map(move |node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    pub children: Vec<
+        auto_lsp::core::ast::AstNodeId<
+            Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text,
+        >,
+    >,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    Element, "element", 4u16, true, [28u16], fields : [], children : [children, errors],
+    lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for Element {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = vec![];
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_vec_children_id(&mut children) });
+        Ok(Self {
+            children,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_element<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Element,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child
+            .downcast_ref::<
+                Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text,
+            >()
+        {
+            visitor
+                .visit_doctype_element_end_tag_entity_erroneous_end_tag_script_element_self_closing_tag_start_tag_style_element_text(
+                    nodes,
+                    child,
+                );
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<Element>`
+pub trait ElementView<'a> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<
+            'a,
+            Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text,
+        >,
+    > + ExactSizeIterator + 'a;
+}
+impl<'a> ElementView<'a> for auto_lsp::core::ast::AstView<'a, Element> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<
+            'a,
+            Doctype_Element_EndTag_Entity_ErroneousEndTag_ScriptElement_SelfClosingTag_StartTag_StyleElement_Text,
+        >,
+    > + ExactSizeIterator + 'a {
+        self.node()
+            .children
+            .iter()
+            .map(move |node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndTag {
+    pub children: auto_lsp::core::ast::AstNodeId<TagName>,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    EndTag, "end_tag", 5u16, true, [35u16], fields : [], children : [children, errors],
+    lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for EndTag {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = Ok(None);
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_children_id(&mut children) });
+        Ok(Self {
+            children: children?
+                .ok_or_else(|| {
+                    auto_lsp::core::errors::AstError::MissingField {
+                        range: node.range(),
+                        parent: node.kind(),
+                        field: "children",
+                        expected: &["tag_name"],
+                    }
+                })?,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_end_tag<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &EndTag,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child.downcast_ref::<TagName>() {
+            visitor.visit_tag_name(nodes, child);
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<EndTag>`
+pub trait EndTagView<'a> {
+    fn children(self) -> auto_lsp::core::ast::AstView<'a, TagName>;
+}
+impl<'a> EndTagView<'a> for auto_lsp::core::ast::AstView<'a, EndTag> {
+    fn children(self) -> auto_lsp::core::ast::AstView<'a, TagName> {
+        let node = &self.node().children;
+        auto_lsp::core::ast::AstView::new(node.cast(self.nodes()), self.nodes())
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErroneousEndTag {
+    pub children: auto_lsp::core::ast::AstNodeId<ErroneousEndTagName>,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    ErroneousEndTag, "erroneous_end_tag", 6u16, true, [36u16], fields : [], children :
+    [children, errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for ErroneousEndTag {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = Ok(None);
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_children_id(&mut children) });
+        Ok(Self {
+            children: children?
+                .ok_or_else(|| {
+                    auto_lsp::core::errors::AstError::MissingField {
+                        range: node.range(),
+                        parent: node.kind(),
+                        field: "children",
+                        expected: &["erroneous_end_tag_name"],
+                    }
+                })?,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_erroneous_end_tag<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &ErroneousEndTag,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child.downcast_ref::<ErroneousEndTagName>() {
+            visitor.visit_erroneous_end_tag_name(nodes, child);
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<ErroneousEndTag>`
+pub trait ErroneousEndTagView<'a> {
+    fn children(self) -> auto_lsp::core::ast::AstView<'a, ErroneousEndTagName>;
+}
+impl<'a> ErroneousEndTagView<'a> for auto_lsp::core::ast::AstView<'a, ErroneousEndTag> {
+    fn children(self) -> auto_lsp::core::ast::AstView<'a, ErroneousEndTagName> {
+        let node = &self.node().children;
+        auto_lsp::core::ast::AstView::new(node.cast(self.nodes()), self.nodes())
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotedAttributeValue {
+    pub children: Option<auto_lsp::core::ast::AstNodeId<AttributeValue>>,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    QuotedAttributeValue, "quoted_attribute_value", 7u16, true, [38u16], fields : [],
+    children : [children, errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for QuotedAttributeValue {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = Ok(None);
+        let errors = builder
+            .builder(
+                db,
+                &node,
+                Some(id),
+                |b| { b.on_optional_children_id(&mut children) },
+            );
+        Ok(Self {
+            children: children?,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_quoted_attribute_value<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &QuotedAttributeValue,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child.downcast_ref::<AttributeValue>() {
+            visitor.visit_attribute_value(nodes, child);
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<QuotedAttributeValue>`
+pub trait QuotedAttributeValueView<'a> {
+    fn children(self) -> Option<auto_lsp::core::ast::AstView<'a, AttributeValue>>;
+}
+impl<'a> QuotedAttributeValueView<'a>
+for auto_lsp::core::ast::AstView<'a, QuotedAttributeValue> {
+    fn children(self) -> Option<auto_lsp::core::ast::AstView<'a, AttributeValue>> {
+        self.node()
+            .children
+            .as_ref()
+            .map(|node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptElement {
+    pub children: Vec<auto_lsp::core::ast::AstNodeId<EndTag_RawText_StartTag>>,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    ScriptElement, "script_element", 8u16, true, [29u16], fields : [], children :
+    [children, errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for ScriptElement {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = vec![];
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_vec_children_id(&mut children) });
+        Ok(Self {
+            children,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_script_element<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &ScriptElement,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child.downcast_ref::<EndTag_RawText_StartTag>() {
+            visitor.visit_end_tag_raw_text_start_tag(nodes, child);
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<ScriptElement>`
+pub trait ScriptElementView<'a> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, EndTag_RawText_StartTag>,
+    > + ExactSizeIterator + 'a;
+}
+impl<'a> ScriptElementView<'a> for auto_lsp::core::ast::AstView<'a, ScriptElement> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, EndTag_RawText_StartTag>,
+    > + ExactSizeIterator + 'a {
+        self.node()
+            .children
+            .iter()
+            .map(move |node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfClosingTag {
+    pub children: Vec<auto_lsp::core::ast::AstNodeId<Attribute_TagName>>,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    SelfClosingTag, "self_closing_tag", 9u16, true, [34u16], fields : [], children :
+    [children, errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for SelfClosingTag {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = vec![];
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_vec_children_id(&mut children) });
+        Ok(Self {
+            children,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_self_closing_tag<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &SelfClosingTag,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child.downcast_ref::<Attribute_TagName>() {
+            visitor.visit_attribute_tag_name(nodes, child);
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<SelfClosingTag>`
+pub trait SelfClosingTagView<'a> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, Attribute_TagName>,
+    > + ExactSizeIterator + 'a;
+}
+impl<'a> SelfClosingTagView<'a> for auto_lsp::core::ast::AstView<'a, SelfClosingTag> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, Attribute_TagName>,
+    > + ExactSizeIterator + 'a {
+        self.node()
+            .children
+            .iter()
+            .map(move |node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartTag {
+    pub children: Vec<auto_lsp::core::ast::AstNodeId<Attribute_TagName>>,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    StartTag, "start_tag", 10u16, true, [31u16, 32u16, 33u16], fields : [], children :
+    [children, errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for StartTag {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = vec![];
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_vec_children_id(&mut children) });
+        Ok(Self {
+            children,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_start_tag<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &StartTag,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child.downcast_ref::<Attribute_TagName>() {
+            visitor.visit_attribute_tag_name(nodes, child);
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<StartTag>`
+pub trait StartTagView<'a> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, Attribute_TagName>,
+    > + ExactSizeIterator + 'a;
+}
+impl<'a> StartTagView<'a> for auto_lsp::core::ast::AstView<'a, StartTag> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, Attribute_TagName>,
+    > + ExactSizeIterator + 'a {
+        self.node()
+            .children
+            .iter()
+            .map(move |node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleElement {
+    pub children: Vec<auto_lsp::core::ast::AstNodeId<EndTag_RawText_StartTag>>,
+    /// Children that could not be built
+    pub errors: Vec<auto_lsp::core::ast::AstNodeId<auto_lsp::core::ast::ErrorNode>>,
+    _span: auto_lsp::core::ast::Span,
+    _id: usize,
+    _parent: Option<usize>,
+    _is_missing: bool,
+}
+ast_node!(
+    StyleElement, "style_element", 11u16, true, [30u16], fields : [], children :
+    [children, errors], lenient : []
+);
+impl<'a> TryFrom<auto_lsp::core::ast::TryFromParams<'a>> for StyleElement {
+    type Error = auto_lsp::core::errors::AstError;
+    fn try_from(
+        (node, db, builder, id, parent_id): auto_lsp::core::ast::TryFromParams,
+    ) -> Result<Self, auto_lsp::core::errors::AstError> {
+        let mut children = vec![];
+        let errors = builder
+            .builder(db, &node, Some(id), |b| { b.on_vec_children_id(&mut children) });
+        Ok(Self {
+            children,
+            errors,
+            _span: auto_lsp::core::ast::Span::from(node),
+            _id: id,
+            _parent: parent_id,
+            _is_missing: node.is_missing(),
+        })
+    }
+}
+pub fn walk_style_element<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &StyleElement,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    for id in auto_lsp::core::ast::AstNode::get_children_ids(node) {
+        let child = &nodes[id];
+        if let Some(child) = child.downcast_ref::<EndTag_RawText_StartTag>() {
+            visitor.visit_end_tag_raw_text_start_tag(nodes, child);
+            continue;
+        }
+        if let Some(child) = child.downcast_ref::<ErrorNode>() {
+            visitor.visit_error_node(nodes, child);
+            continue;
+        }
+    }
+    visitor.leave(nodes, node);
+}
+/// Accessors of the fields of an `AstView<StyleElement>`
+pub trait StyleElementView<'a> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, EndTag_RawText_StartTag>,
+    > + ExactSizeIterator + 'a;
+}
+impl<'a> StyleElementView<'a> for auto_lsp::core::ast::AstView<'a, StyleElement> {
+    fn children(
+        self,
+    ) -> impl DoubleEndedIterator<
+        Item = auto_lsp::core::ast::AstView<'a, EndTag_RawText_StartTag>,
+    > + ExactSizeIterator + 'a {
+        self.node()
+            .children
+            .iter()
+            .map(move |node| auto_lsp::core::ast::AstView::new(
+                node.cast(self.nodes()),
+                self.nodes(),
+            ))
+    }
+}
+ast_leaf!(AttributeName, "attribute_name", 20u16, true, [9u16]);
+pub fn walk_attribute_name<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &AttributeName,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(AttributeValue, "attribute_value", 21u16, true, [10u16, 13u16, 15u16]);
+pub fn walk_attribute_value<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &AttributeValue,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Comment, "comment", 22u16, true, [24u16]);
+pub fn walk_comment<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Comment,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Entity, "entity", 24u16, true, [11u16]);
+pub fn walk_entity<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Entity,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(ErroneousEndTagName, "erroneous_end_tag_name", 25u16, true, [21u16]);
+pub fn walk_erroneous_end_tag_name<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &ErroneousEndTagName,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(RawText, "raw_text", 26u16, true, [23u16]);
+pub fn walk_raw_text<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &RawText,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(TagName, "tag_name", 27u16, true, [17u16, 18u16, 19u16, 20u16]);
+pub fn walk_tag_name<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &TagName,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Text, "text", 28u16, true, [16u16]);
+pub fn walk_text<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Text,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
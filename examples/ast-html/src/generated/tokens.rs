@@ -0,0 +1,103 @@
+// @generated by auto-lsp-codegen 0.2.1, do not edit manually.
+// stamp: 3f3c0f4d28b190ce-4b8506543794aec4
+
+use super::*;
+ast_leaf!(Token_DoubleQuote, "\"", 12u16, false, [14u16]);
+pub fn walk_token_double_quote<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_DoubleQuote,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_Quote, "'", 13u16, false, [12u16]);
+pub fn walk_token_quote<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_Quote,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_SlashGreater, "/>", 14u16, false, [6u16]);
+pub fn walk_token_slash_greater<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_SlashGreater,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_Less, "<", 15u16, false, [5u16]);
+pub fn walk_token_less<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_Less,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_LessBang, "<!", 16u16, false, [1u16]);
+pub fn walk_token_less_bang<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_LessBang,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_LessSlash, "</", 17u16, false, [7u16]);
+pub fn walk_token_less_slash<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_LessSlash,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_Equal, "=", 18u16, false, [8u16]);
+pub fn walk_token_equal<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_Equal,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_Greater, ">", 19u16, false, [3u16]);
+pub fn walk_token_greater<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_Greater,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
+ast_leaf!(Token_doctype, "doctype", 23u16, false, [4u16]);
+pub fn walk_token_doctype<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    nodes: &auto_lsp::core::ast::AstArena,
+    node: &Token_doctype,
+) {
+    if visitor.enter(nodes, node).is_break() {
+        return;
+    }
+    visitor.leave(nodes, node);
+}
@@ -1,4 +1,6 @@
 pub mod db;
+// Written by auto-lsp-codegen, see the README
+#[rustfmt::skip]
 pub mod generated;
 #[cfg(test)]
 mod tests;
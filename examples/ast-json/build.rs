@@ -1,4 +1,4 @@
-use auto_lsp_codegen::generate;
+use auto_lsp_codegen::{CodegenConfig, GenerateOptions, Generator};
 use std::{fs, path::PathBuf};
 
 fn main() {
@@ -10,12 +10,16 @@ fn main() {
 
     fs::write(
         output_path,
-        generate(
-            tree_sitter_json::NODE_TYPES,
-            &tree_sitter_json::LANGUAGE.into(),
-            None,
-        )
-        .to_string(),
+        Generator::new(&tree_sitter_json::LANGUAGE.into())
+            .with_options(GenerateOptions {
+                lenient_fields: true,
+                // The `serde` feature of the generator is enabled by ast-python
+                serde: false,
+            })
+            .with_config(CodegenConfig::new().derive("Eq").derive("Hash"))
+            .generate(tree_sitter_json::NODE_TYPES)
+            .unwrap()
+            .to_string(),
     )
     .unwrap();
 }
@@ -1 +1 @@
-# ! [allow (clippy :: all)] # ! [allow (unused)] # ! [allow (dead_code)] # ! [allow (non_camel_case_types)] # ! [allow (non_snake_case)] # [derive (Debug , Clone , PartialEq)] pub struct Array { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Value >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for Array { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 19u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Array { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Document { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Value >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for Document { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 15u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Document { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Object { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Pair >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for Object { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 17u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Object { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Pair { pub key : auto_lsp :: core :: ast :: AstNodeId < String > , pub value : auto_lsp :: core :: ast :: AstNodeId < Value > , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for Pair { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 18u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Pair { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut key = Ok (None) ; ; let mut value = Ok (None) ; ; builder . builder (db , & node , Some (id) , | b | { b . on_field_id :: < String , 1u16 > (& mut key) ? . on_field_id :: < Value , 2u16 > (& mut value) }) ; Ok (Self { key : key ? . ok_or_else (|| { auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (key) , } }) ? , value : value ? . ok_or_else (|| { auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (value) , } }) ? , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct String { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < EscapeSequence_StringContent >> , _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool } impl auto_lsp :: core :: ast :: AstNode for String { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 20u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for String { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_DoubleQuote { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_DoubleQuote { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 7u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_DoubleQuote { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_Comma { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_Comma { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 2u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_Comma { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_Colon { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_Colon { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 4u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_Colon { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_LeftBracket { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_LeftBracket { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 5u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_LeftBracket { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_RightBracket { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_RightBracket { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 6u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_RightBracket { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Comment { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Comment { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 14u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Comment { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct EscapeSequence { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for EscapeSequence { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 9u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for EscapeSequence { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct False { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for False { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 12u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for False { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Null { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Null { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 13u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Null { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Number { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Number { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 10u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Number { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct StringContent { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for StringContent { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 8u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for StringContent { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct True { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for True { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 11u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for True { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_LeftCurly { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_LeftCurly { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 1u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_LeftCurly { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub struct Token_RightCurly { _range : auto_lsp :: tree_sitter :: Range , _id : usize , _parent : Option < usize > , _is_missing : bool , } impl auto_lsp :: core :: ast :: AstNode for Token_RightCurly { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 3u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { & self . _range } fn is_missing (& self) -> bool { self . _is_missing } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Token_RightCurly { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { ; Ok (Self { _range : node . range () , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } # [derive (Debug , Clone , PartialEq)] pub enum EscapeSequence_StringContent { EscapeSequence (EscapeSequence) , StringContent (StringContent) } impl auto_lsp :: core :: ast :: AstNode for EscapeSequence_StringContent { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 9u16 | 8u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { Self :: EscapeSequence (node) => node . lower () , Self :: StringContent (node) => node . lower () } } fn get_id (& self) -> usize { match self { Self :: EscapeSequence (node) => node . get_id () , Self :: StringContent (node) => node . get_id () } } fn get_parent_id (& self) -> Option < usize > { match self { Self :: EscapeSequence (node) => node . get_parent_id () , Self :: StringContent (node) => node . get_parent_id () } } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { match self { Self :: EscapeSequence (node) => node . get_range () , Self :: StringContent (node) => node . get_range () } } fn is_missing (& self) -> bool { match self { Self :: EscapeSequence (node) => node . is_missing () , Self :: StringContent (node) => node . is_missing () } } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for EscapeSequence_StringContent { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 9u16 => Ok (Self :: EscapeSequence (EscapeSequence :: try_from ((node , db , builder , id , parent_id)) ?)) , 8u16 => Ok (Self :: StringContent (StringContent :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (EscapeSequence_StringContent) , }) } } } # [derive (Debug , Clone , PartialEq)] pub enum Value { Array (Array) , False (False) , Null (Null) , Number (Number) , Object (Object) , String (String) , True (True) } impl auto_lsp :: core :: ast :: AstNode for Value { fn contains (node : & auto_lsp :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , 19u16 | 12u16 | 13u16 | 10u16 | 17u16 | 20u16 | 11u16) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { Self :: Array (node) => node . lower () , Self :: False (node) => node . lower () , Self :: Null (node) => node . lower () , Self :: Number (node) => node . lower () , Self :: Object (node) => node . lower () , Self :: String (node) => node . lower () , Self :: True (node) => node . lower () } } fn get_id (& self) -> usize { match self { Self :: Array (node) => node . get_id () , Self :: False (node) => node . get_id () , Self :: Null (node) => node . get_id () , Self :: Number (node) => node . get_id () , Self :: Object (node) => node . get_id () , Self :: String (node) => node . get_id () , Self :: True (node) => node . get_id () } } fn get_parent_id (& self) -> Option < usize > { match self { Self :: Array (node) => node . get_parent_id () , Self :: False (node) => node . get_parent_id () , Self :: Null (node) => node . get_parent_id () , Self :: Number (node) => node . get_parent_id () , Self :: Object (node) => node . get_parent_id () , Self :: String (node) => node . get_parent_id () , Self :: True (node) => node . get_parent_id () } } fn get_range (& self) -> & auto_lsp :: tree_sitter :: Range { match self { Self :: Array (node) => node . get_range () , Self :: False (node) => node . get_range () , Self :: Null (node) => node . get_range () , Self :: Number (node) => node . get_range () , Self :: Object (node) => node . get_range () , Self :: String (node) => node . get_range () , Self :: True (node) => node . get_range () } } fn is_missing (& self) -> bool { match self { Self :: Array (node) => node . is_missing () , Self :: False (node) => node . is_missing () , Self :: Null (node) => node . is_missing () , Self :: Number (node) => node . is_missing () , Self :: Object (node) => node . is_missing () , Self :: String (node) => node . is_missing () , Self :: True (node) => node . is_missing () } } } impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Value { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 19u16 => Ok (Self :: Array (Array :: try_from ((node , db , builder , id , parent_id)) ?)) , 12u16 => Ok (Self :: False (False :: try_from ((node , db , builder , id , parent_id)) ?)) , 13u16 => Ok (Self :: Null (Null :: try_from ((node , db , builder , id , parent_id)) ?)) , 10u16 => Ok (Self :: Number (Number :: try_from ((node , db , builder , id , parent_id)) ?)) , 17u16 => Ok (Self :: Object (Object :: try_from ((node , db , builder , id , parent_id)) ?)) , 20u16 => Ok (Self :: String (String :: try_from ((node , db , builder , id , parent_id)) ?)) , 11u16 => Ok (Self :: True (True :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedSymbol { range : node . range () , symbol : node . kind () , parent_name : stringify ! (Value) , }) } } }
\ No newline at end of file
+# ! [allow (clippy :: all)] # ! [allow (unused)] # ! [allow (dead_code)] # ! [allow (non_camel_case_types)] # ! [allow (non_snake_case)] pub use auto_lsp :: core :: ast :: ErrorNode ; macro_rules ! ast_node { ($ name : ident , $ kind : literal , $ kind_id : literal , $ named : literal , [$ ($ id : literal) , +] , fields : [$ ($ field : ident : $ field_name : literal) , *] , children : [$ ($ child : ident) , *] , lenient : [$ ($ lenient : ident) , *]) => { impl auto_lsp :: core :: ast :: AstNode for $ name { fn contains (node : & auto_lsp :: core :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , $ ($ id) | +) } fn verify_language (language : & auto_lsp :: core :: tree_sitter :: Language) -> Result < () , Vec < auto_lsp :: core :: errors :: Mismatch >> { verify_language (language) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { self } fn get_id (& self) -> usize { self . _id } fn get_parent_id (& self) -> Option < usize > { self . _parent } fn get_children_ids (& self) -> auto_lsp :: core :: ast :: ChildrenIds { let mut ids = auto_lsp :: core :: ast :: ChildrenIds :: new () ; $ (auto_lsp :: core :: ast :: NodeField :: push_ids (& self . $ field , & mut ids) ;) * $ (auto_lsp :: core :: ast :: NodeField :: push_ids (& self . $ child , & mut ids) ;) * ids . sort_unstable () ; ids } fn get_span (& self) -> auto_lsp :: core :: ast :: Span { self . _span } fn get_kind_name (& self) -> & 'static str { $ kind } fn get_kind_id (& self) -> u16 { $ kind_id } fn is_named (& self) -> bool { $ named } fn get_field_name (& self , id : usize) -> Option < & 'static str > { $ (if auto_lsp :: core :: ast :: NodeField :: holds (& self . $ field , id) { return Some ($ field_name) ; }) * None } fn relocate (& mut self , relocation : & auto_lsp :: core :: ast :: Relocation) { self . _parent = relocation . parent (self . _id , self . _parent) ; self . _id = relocation . id (self . _id) ; self . _span = relocation . span (self . _span) ; $ (auto_lsp :: core :: ast :: NodeField :: relocate (& mut self . $ field , relocation) ;) * $ (auto_lsp :: core :: ast :: NodeField :: relocate (& mut self . $ child , relocation) ;) * } fn is_missing (& self) -> bool { self . _is_missing } fn has_all_required_fields (& self) -> bool { true $ (&& self . $ lenient . is_ok ()) * } } impl $ name { pub fn kind (& self) -> NodeKind { NodeKind :: $ name } # [doc = r" Returns a view of this node, to navigate its fields without passing the AST around."] pub fn view < 'a > (& 'a self , nodes : & 'a auto_lsp :: core :: ast :: AstArena) -> auto_lsp :: core :: ast :: AstView < 'a , Self > { auto_lsp :: core :: ast :: AstView :: new (self , nodes) } } } ; } macro_rules ! ast_leaf { ($ name : ident , $ kind : literal , $ kind_id : literal , $ named : literal , [$ ($ id : literal) , +]) => { # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub struct $ name { _span : auto_lsp :: core :: ast :: Span , _id : usize , _parent : Option < usize > , _is_missing : bool , } ast_node ! ($ name , $ kind , $ kind_id , $ named , [$ ($ id) , +] , fields : [] , children : [] , lenient : []) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for $ name { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { Ok (Self { _span : auto_lsp :: core :: ast :: Span :: from (node) , _id : id , _parent : parent_id , _is_missing : node . is_missing () , }) } } } ; } macro_rules ! ast_enum { ($ name : ident , [$ ($ variant : ident) , +] , [$ ($ id : literal) , +]) => { impl auto_lsp :: core :: ast :: AstNode for $ name { fn contains (node : & auto_lsp :: core :: tree_sitter :: Node) -> bool { matches ! (node . kind_id () , $ ($ id) | +) } fn verify_language (language : & auto_lsp :: core :: tree_sitter :: Language) -> Result < () , Vec < auto_lsp :: core :: errors :: Mismatch >> { verify_language (language) } fn lower (& self) -> & dyn auto_lsp :: core :: ast :: AstNode { match self { $ (Self :: $ variant (node) => node . lower ()) , + } } fn get_id (& self) -> usize { match self { $ (Self :: $ variant (node) => node . get_id ()) , + } } fn get_parent_id (& self) -> Option < usize > { match self { $ (Self :: $ variant (node) => node . get_parent_id ()) , + } } fn get_children_ids (& self) -> auto_lsp :: core :: ast :: ChildrenIds { match self { $ (Self :: $ variant (node) => node . get_children_ids ()) , + } } fn get_span (& self) -> auto_lsp :: core :: ast :: Span { match self { $ (Self :: $ variant (node) => node . get_span ()) , + } } fn get_kind_name (& self) -> & 'static str { match self { $ (Self :: $ variant (node) => node . get_kind_name ()) , + } } fn get_kind_id (& self) -> u16 { match self { $ (Self :: $ variant (node) => node . get_kind_id ()) , + } } fn is_named (& self) -> bool { match self { $ (Self :: $ variant (node) => node . is_named ()) , + } } fn get_field_name (& self , id : usize) -> Option < & 'static str > { match self { $ (Self :: $ variant (node) => node . get_field_name (id)) , + } } fn relocate (& mut self , relocation : & auto_lsp :: core :: ast :: Relocation) { match self { $ (Self :: $ variant (node) => node . relocate (relocation)) , + } } fn is_missing (& self) -> bool { match self { $ (Self :: $ variant (node) => node . is_missing ()) , + } } fn has_all_required_fields (& self) -> bool { match self { $ (Self :: $ variant (node) => node . has_all_required_fields ()) , + } } } impl $ name { pub fn kind (& self) -> NodeKind { match self { $ (Self :: $ variant (node) => node . kind ()) , + } } # [doc = r" Returns a view of this node, to navigate its fields without passing the AST around."] pub fn view < 'a > (& 'a self , nodes : & 'a auto_lsp :: core :: ast :: AstArena) -> auto_lsp :: core :: ast :: AstView < 'a , Self > { auto_lsp :: core :: ast :: AstView :: new (self , nodes) } } } ; } pub mod structs { use super :: * ; # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub struct Array { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Value >> , # [doc = r" Children that could not be built"] pub errors : Vec < auto_lsp :: core :: ast :: AstNodeId < auto_lsp :: core :: ast :: ErrorNode >> , _span : auto_lsp :: core :: ast :: Span , _id : usize , _parent : Option < usize > , _is_missing : bool } ast_node ! (Array , "array" , 1u16 , true , [19u16] , fields : [] , children : [children , errors] , lenient : []) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Array { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; let errors = builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , errors , _span : auto_lsp :: core :: ast :: Span :: from (node) , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } pub fn walk_array < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Array) { if visitor . enter (nodes , node) . is_break () { return ; } for id in auto_lsp :: core :: ast :: AstNode :: get_children_ids (node) { let child = & nodes [id] ; if let Some (child) = child . downcast_ref :: < Value > () { visitor . visit_value (nodes , child) ; continue ; } if let Some (child) = child . downcast_ref :: < ErrorNode > () { visitor . visit_error_node (nodes , child) ; continue ; } } visitor . leave (nodes , node) ; } # [doc = " Accessors of the fields of an `AstView<Array>`"] pub trait ArrayView < 'a > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , Value > > + ExactSizeIterator + 'a ; } impl < 'a > ArrayView < 'a > for auto_lsp :: core :: ast :: AstView < 'a , Array > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , Value > > + ExactSizeIterator + 'a { self . node () . children . iter () . map (move | node | auto_lsp :: core :: ast :: AstView :: new (node . cast (self . nodes ()) , self . nodes ())) } } # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub struct Document { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Value >> , # [doc = r" Children that could not be built"] pub errors : Vec < auto_lsp :: core :: ast :: AstNodeId < auto_lsp :: core :: ast :: ErrorNode >> , _span : auto_lsp :: core :: ast :: Span , _id : usize , _parent : Option < usize > , _is_missing : bool } ast_node ! (Document , "document" , 2u16 , true , [15u16] , fields : [] , children : [children , errors] , lenient : []) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Document { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; let errors = builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , errors , _span : auto_lsp :: core :: ast :: Span :: from (node) , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } pub fn walk_document < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Document) { if visitor . enter (nodes , node) . is_break () { return ; } for id in auto_lsp :: core :: ast :: AstNode :: get_children_ids (node) { let child = & nodes [id] ; if let Some (child) = child . downcast_ref :: < Value > () { visitor . visit_value (nodes , child) ; continue ; } if let Some (child) = child . downcast_ref :: < ErrorNode > () { visitor . visit_error_node (nodes , child) ; continue ; } } visitor . leave (nodes , node) ; } # [doc = " Accessors of the fields of an `AstView<Document>`"] pub trait DocumentView < 'a > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , Value > > + ExactSizeIterator + 'a ; } impl < 'a > DocumentView < 'a > for auto_lsp :: core :: ast :: AstView < 'a , Document > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , Value > > + ExactSizeIterator + 'a { self . node () . children . iter () . map (move | node | auto_lsp :: core :: ast :: AstView :: new (node . cast (self . nodes ()) , self . nodes ())) } } # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub struct Object { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < Pair >> , # [doc = r" Children that could not be built"] pub errors : Vec < auto_lsp :: core :: ast :: AstNodeId < auto_lsp :: core :: ast :: ErrorNode >> , _span : auto_lsp :: core :: ast :: Span , _id : usize , _parent : Option < usize > , _is_missing : bool } ast_node ! (Object , "object" , 3u16 , true , [17u16] , fields : [] , children : [children , errors] , lenient : []) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Object { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; let errors = builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , errors , _span : auto_lsp :: core :: ast :: Span :: from (node) , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } pub fn walk_object < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Object) { if visitor . enter (nodes , node) . is_break () { return ; } for id in auto_lsp :: core :: ast :: AstNode :: get_children_ids (node) { let child = & nodes [id] ; if let Some (child) = child . downcast_ref :: < Pair > () { visitor . visit_pair (nodes , child) ; continue ; } if let Some (child) = child . downcast_ref :: < ErrorNode > () { visitor . visit_error_node (nodes , child) ; continue ; } } visitor . leave (nodes , node) ; } # [doc = " Accessors of the fields of an `AstView<Object>`"] pub trait ObjectView < 'a > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , Pair > > + ExactSizeIterator + 'a ; } impl < 'a > ObjectView < 'a > for auto_lsp :: core :: ast :: AstView < 'a , Object > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , Pair > > + ExactSizeIterator + 'a { self . node () . children . iter () . map (move | node | auto_lsp :: core :: ast :: AstView :: new (node . cast (self . nodes ()) , self . nodes ())) } } # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub struct Pair { pub key : Result < auto_lsp :: core :: ast :: AstNodeId < String > , auto_lsp :: core :: errors :: MissingField > , pub value : Result < auto_lsp :: core :: ast :: AstNodeId < Value > , auto_lsp :: core :: errors :: MissingField > , # [doc = r" Children that could not be built"] pub errors : Vec < auto_lsp :: core :: ast :: AstNodeId < auto_lsp :: core :: ast :: ErrorNode >> , _span : auto_lsp :: core :: ast :: Span , _id : usize , _parent : Option < usize > , _is_missing : bool } ast_node ! (Pair , "pair" , 4u16 , true , [18u16] , fields : [key : "key" , value : "value"] , children : [errors] , lenient : [key , value]) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Pair { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut key = Ok (None) ; ; let mut value = Ok (None) ; ; let errors = builder . builder (db , & node , Some (id) , | b | { b . on_optional_field_id :: < String , 1u16 > (& mut key) ? . on_optional_field_id :: < Value , 2u16 > (& mut value) }) ; Ok (Self { key : key ? . ok_or (auto_lsp :: core :: errors :: MissingField { parent : node . kind () , field : "key" , expected : & ["string"] , }) , value : value ? . ok_or (auto_lsp :: core :: errors :: MissingField { parent : node . kind () , field : "value" , expected : & ["_value"] , }) , errors , _span : auto_lsp :: core :: ast :: Span :: from (node) , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } pub fn walk_pair < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Pair) { if visitor . enter (nodes , node) . is_break () { return ; } for id in auto_lsp :: core :: ast :: AstNode :: get_children_ids (node) { let child = & nodes [id] ; if let Some (child) = child . downcast_ref :: < String > () { visitor . visit_string (nodes , child) ; continue ; } if let Some (child) = child . downcast_ref :: < Value > () { visitor . visit_value (nodes , child) ; continue ; } if let Some (child) = child . downcast_ref :: < ErrorNode > () { visitor . visit_error_node (nodes , child) ; continue ; } } visitor . leave (nodes , node) ; } # [doc = " Accessors of the fields of an `AstView<Pair>`"] pub trait PairView < 'a > { fn key (self) -> Result < auto_lsp :: core :: ast :: AstView < 'a , String > , auto_lsp :: core :: errors :: MissingField > ; fn value (self) -> Result < auto_lsp :: core :: ast :: AstView < 'a , Value > , auto_lsp :: core :: errors :: MissingField > ; } impl < 'a > PairView < 'a > for auto_lsp :: core :: ast :: AstView < 'a , Pair > { fn key (self) -> Result < auto_lsp :: core :: ast :: AstView < 'a , String > , auto_lsp :: core :: errors :: MissingField > { match & self . node () . key { Ok (node) => Ok (auto_lsp :: core :: ast :: AstView :: new (node . cast (self . nodes ()) , self . nodes ())) , Err (error) => Err (* error) , } } fn value (self) -> Result < auto_lsp :: core :: ast :: AstView < 'a , Value > , auto_lsp :: core :: errors :: MissingField > { match & self . node () . value { Ok (node) => Ok (auto_lsp :: core :: ast :: AstView :: new (node . cast (self . nodes ()) , self . nodes ())) , Err (error) => Err (* error) , } } } # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub struct String { pub children : Vec < auto_lsp :: core :: ast :: AstNodeId < EscapeSequence_StringContent >> , # [doc = r" Children that could not be built"] pub errors : Vec < auto_lsp :: core :: ast :: AstNodeId < auto_lsp :: core :: ast :: ErrorNode >> , _span : auto_lsp :: core :: ast :: Span , _id : usize , _parent : Option < usize > , _is_missing : bool } ast_node ! (String , "string" , 5u16 , true , [20u16] , fields : [] , children : [children , errors] , lenient : []) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for String { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { let mut children = vec ! [] ; ; let errors = builder . builder (db , & node , Some (id) , | b | { b . on_vec_children_id (& mut children) }) ; Ok (Self { children , errors , _span : auto_lsp :: core :: ast :: Span :: from (node) , _id : id , _parent : parent_id , _is_missing : node . is_missing () }) } } pub fn walk_string < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & String) { if visitor . enter (nodes , node) . is_break () { return ; } for id in auto_lsp :: core :: ast :: AstNode :: get_children_ids (node) { let child = & nodes [id] ; if let Some (child) = child . downcast_ref :: < EscapeSequence_StringContent > () { visitor . visit_escape_sequence_string_content (nodes , child) ; continue ; } if let Some (child) = child . downcast_ref :: < ErrorNode > () { visitor . visit_error_node (nodes , child) ; continue ; } } visitor . leave (nodes , node) ; } # [doc = " Accessors of the fields of an `AstView<String>`"] pub trait StringView < 'a > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , EscapeSequence_StringContent > > + ExactSizeIterator + 'a ; } impl < 'a > StringView < 'a > for auto_lsp :: core :: ast :: AstView < 'a , String > { fn children (self) -> impl DoubleEndedIterator < Item = auto_lsp :: core :: ast :: AstView < 'a , EscapeSequence_StringContent > > + ExactSizeIterator + 'a { self . node () . children . iter () . map (move | node | auto_lsp :: core :: ast :: AstView :: new (node . cast (self . nodes ()) , self . nodes ())) } } ast_leaf ! (Comment , "comment" , 11u16 , true , [14u16]) ; pub fn walk_comment < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Comment) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (EscapeSequence , "escape_sequence" , 12u16 , true , [9u16]) ; pub fn walk_escape_sequence < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & EscapeSequence) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (False , "false" , 13u16 , true , [12u16]) ; pub fn walk_false < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & False) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Null , "null" , 14u16 , true , [13u16]) ; pub fn walk_null < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Null) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Number , "number" , 15u16 , true , [10u16]) ; pub fn walk_number < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Number) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (StringContent , "string_content" , 16u16 , true , [8u16]) ; pub fn walk_string_content < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & StringContent) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (True , "true" , 17u16 , true , [11u16]) ; pub fn walk_true < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & True) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } } pub use structs :: * ; pub mod enums { use super :: * ; # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub enum EscapeSequence_StringContent { EscapeSequence (EscapeSequence) , StringContent (StringContent) } ast_enum ! (EscapeSequence_StringContent , [EscapeSequence , StringContent] , [9u16 , 8u16]) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for EscapeSequence_StringContent { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 9u16 => Ok (Self :: EscapeSequence (EscapeSequence :: try_from ((node , db , builder , id , parent_id)) ?)) , 8u16 => Ok (Self :: StringContent (StringContent :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: UnexpectedChild { range : node . range () , parent : node . parent () . map_or ("" , | parent | parent . kind ()) , found : node . kind () , expected : & ["escape_sequence" , "string_content"] , }) } } } pub fn walk_escape_sequence_string_content < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & EscapeSequence_StringContent) { match node { EscapeSequence_StringContent :: EscapeSequence (node) => visitor . visit_escape_sequence (nodes , node) , EscapeSequence_StringContent :: StringContent (node) => visitor . visit_string_content (nodes , node) } } } pub use enums :: * ; pub mod supertypes { use super :: * ; # [derive (Debug , Clone , PartialEq , Eq , Hash)] pub enum Value { Array (Array) , False (False) , Null (Null) , Number (Number) , Object (Object) , String (String) , True (True) } ast_enum ! (Value , [Array , False , Null , Number , Object , String , True] , [19u16 , 12u16 , 13u16 , 10u16 , 17u16 , 20u16 , 11u16]) ; impl < 'a > TryFrom < auto_lsp :: core :: ast :: TryFromParams < 'a >> for Value { type Error = auto_lsp :: core :: errors :: AstError ; fn try_from ((node , db , builder , id , parent_id) : auto_lsp :: core :: ast :: TryFromParams) -> Result < Self , auto_lsp :: core :: errors :: AstError > { match node . kind_id () { 19u16 => Ok (Self :: Array (Array :: try_from ((node , db , builder , id , parent_id)) ?)) , 12u16 => Ok (Self :: False (False :: try_from ((node , db , builder , id , parent_id)) ?)) , 13u16 => Ok (Self :: Null (Null :: try_from ((node , db , builder , id , parent_id)) ?)) , 10u16 => Ok (Self :: Number (Number :: try_from ((node , db , builder , id , parent_id)) ?)) , 17u16 => Ok (Self :: Object (Object :: try_from ((node , db , builder , id , parent_id)) ?)) , 20u16 => Ok (Self :: String (String :: try_from ((node , db , builder , id , parent_id)) ?)) , 11u16 => Ok (Self :: True (True :: try_from ((node , db , builder , id , parent_id)) ?)) , _ => Err (auto_lsp :: core :: errors :: AstError :: InvalidSupertypeVariant { range : node . range () , supertype : "_value" , found : node . kind () , expected : & ["array" , "false" , "null" , "number" , "object" , "string" , "true"] , }) } } } pub fn walk_value < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Value) { match node { Value :: Array (node) => visitor . visit_array (nodes , node) , Value :: False (node) => visitor . visit_false (nodes , node) , Value :: Null (node) => visitor . visit_null (nodes , node) , Value :: Number (node) => visitor . visit_number (nodes , node) , Value :: Object (node) => visitor . visit_object (nodes , node) , Value :: String (node) => visitor . visit_string (nodes , node) , Value :: True (node) => visitor . visit_true (nodes , node) } } } pub use supertypes :: * ; pub mod tokens { use super :: * ; ast_leaf ! (Token_DoubleQuote , "\"" , 6u16 , false , [7u16]) ; pub fn walk_token_double_quote < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_DoubleQuote) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Token_Comma , "," , 7u16 , false , [2u16]) ; pub fn walk_token_comma < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_Comma) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Token_Colon , ":" , 8u16 , false , [4u16]) ; pub fn walk_token_colon < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_Colon) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Token_LeftBracket , "[" , 9u16 , false , [5u16]) ; pub fn walk_token_left_bracket < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_LeftBracket) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Token_RightBracket , "]" , 10u16 , false , [6u16]) ; pub fn walk_token_right_bracket < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_RightBracket) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Token_LeftCurly , "{" , 18u16 , false , [1u16]) ; pub fn walk_token_left_curly < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_LeftCurly) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } ast_leaf ! (Token_RightCurly , "}" , 19u16 , false , [3u16]) ; pub fn walk_token_right_curly < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_RightCurly) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } } pub use tokens :: * ; pub mod operators { use super :: * ; } pub use operators :: * ; impl EscapeSequence_StringContent { pub fn is_escape_sequence (& self) -> bool { self . as_escape_sequence () . is_some () } pub fn as_escape_sequence (& self) -> Option < & EscapeSequence > { match self { Self :: EscapeSequence (node) => Some (node) , _ => None } } pub fn is_string_content (& self) -> bool { self . as_string_content () . is_some () } pub fn as_string_content (& self) -> Option < & StringContent > { match self { Self :: StringContent (node) => Some (node) , _ => None } } } impl Value { pub fn is_array (& self) -> bool { self . as_array () . is_some () } pub fn as_array (& self) -> Option < & Array > { match self { Self :: Array (node) => Some (node) , _ => None } } pub fn is_false (& self) -> bool { self . as_false () . is_some () } pub fn as_false (& self) -> Option < & False > { match self { Self :: False (node) => Some (node) , _ => None } } pub fn is_null (& self) -> bool { self . as_null () . is_some () } pub fn as_null (& self) -> Option < & Null > { match self { Self :: Null (node) => Some (node) , _ => None } } pub fn is_number (& self) -> bool { self . as_number () . is_some () } pub fn as_number (& self) -> Option < & Number > { match self { Self :: Number (node) => Some (node) , _ => None } } pub fn is_object (& self) -> bool { self . as_object () . is_some () } pub fn as_object (& self) -> Option < & Object > { match self { Self :: Object (node) => Some (node) , _ => None } } pub fn is_string (& self) -> bool { self . as_string () . is_some () } pub fn as_string (& self) -> Option < & String > { match self { Self :: String (node) => Some (node) , _ => None } } pub fn is_true (& self) -> bool { self . as_true () . is_some () } pub fn as_true (& self) -> Option < & True > { match self { Self :: True (node) => Some (node) , _ => None } } } pub fn walk_error_node < V : Visitor + ? Sized > (visitor : & mut V , nodes : & auto_lsp :: core :: ast :: AstArena , node : & ErrorNode) { if visitor . enter (nodes , node) . is_break () { return ; } visitor . leave (nodes , node) ; } # [doc = r" A visitor over the typed AST."] # [doc = r""] # [doc = r" Each node type has a `visit_<node>` method which, by default, calls the matching `walk_<node>` function"] # [doc = r" to visit its children in source order."] # [doc = r""] # [doc = r" Override a `visit_<node>` method to act on a specific node kind, and call `walk_<node>` to keep descending."] # [doc = r""] # [doc = r" [`Visitor::enter`] and [`Visitor::leave`] are called around every struct node,"] # [doc = r" returning [`std::ops::ControlFlow::Break`] from `enter` skips the node's subtree (and its `leave` call)."] pub trait Visitor { fn enter (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & dyn auto_lsp :: core :: ast :: AstNode) -> std :: ops :: ControlFlow < () > { std :: ops :: ControlFlow :: Continue (()) } fn leave (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & dyn auto_lsp :: core :: ast :: AstNode) { } fn visit_array (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Array) { walk_array (self , nodes , node) } fn visit_document (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Document) { walk_document (self , nodes , node) } fn visit_object (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Object) { walk_object (self , nodes , node) } fn visit_pair (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Pair) { walk_pair (self , nodes , node) } fn visit_string (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & String) { walk_string (self , nodes , node) } fn visit_token_double_quote (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_DoubleQuote) { walk_token_double_quote (self , nodes , node) } fn visit_token_comma (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_Comma) { walk_token_comma (self , nodes , node) } fn visit_token_colon (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_Colon) { walk_token_colon (self , nodes , node) } fn visit_token_left_bracket (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_LeftBracket) { walk_token_left_bracket (self , nodes , node) } fn visit_token_right_bracket (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_RightBracket) { walk_token_right_bracket (self , nodes , node) } fn visit_comment (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Comment) { walk_comment (self , nodes , node) } fn visit_escape_sequence (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & EscapeSequence) { walk_escape_sequence (self , nodes , node) } fn visit_false (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & False) { walk_false (self , nodes , node) } fn visit_null (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Null) { walk_null (self , nodes , node) } fn visit_number (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Number) { walk_number (self , nodes , node) } fn visit_string_content (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & StringContent) { walk_string_content (self , nodes , node) } fn visit_true (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & True) { walk_true (self , nodes , node) } fn visit_token_left_curly (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_LeftCurly) { walk_token_left_curly (self , nodes , node) } fn visit_token_right_curly (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Token_RightCurly) { walk_token_right_curly (self , nodes , node) } fn visit_escape_sequence_string_content (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & EscapeSequence_StringContent) { walk_escape_sequence_string_content (self , nodes , node) } fn visit_value (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & Value) { walk_value (self , nodes , node) } fn visit_error_node (& mut self , nodes : & auto_lsp :: core :: ast :: AstArena , node : & ErrorNode) { walk_error_node (self , nodes , node) } } # [doc = r" Kind of a node, with a variant for each generated struct."] # [doc = r""] # [doc = r" Enums share the kind of the struct they wrap, and `ErrorNode` stands for the nodes that could not be built."] # [doc = r""] # [doc = r" Matching on a `NodeKind` is exhaustive, and its discriminant can index tables of `NodeKind::COUNT` entries."] # [derive (Debug , Clone , Copy , PartialEq , Eq , Hash , PartialOrd , Ord)] # [repr (u16)] pub enum NodeKind { ErrorNode = auto_lsp :: core :: ast :: ErrorNode :: KIND_ID , Array = 1u16 , Document = 2u16 , Object = 3u16 , Pair = 4u16 , String = 5u16 , Token_DoubleQuote = 6u16 , Token_Comma = 7u16 , Token_Colon = 8u16 , Token_LeftBracket = 9u16 , Token_RightBracket = 10u16 , Comment = 11u16 , EscapeSequence = 12u16 , False = 13u16 , Null = 14u16 , Number = 15u16 , StringContent = 16u16 , True = 17u16 , Token_LeftCurly = 18u16 , Token_RightCurly = 19u16 } impl NodeKind { # [doc = r" Number of kinds, error nodes included"] pub const COUNT : usize = 20usize ; # [doc = r" Every kind, indexed by discriminant"] pub const ALL : [NodeKind ; 20usize] = [NodeKind :: ErrorNode , NodeKind :: Array , NodeKind :: Document , NodeKind :: Object , NodeKind :: Pair , NodeKind :: String , NodeKind :: Token_DoubleQuote , NodeKind :: Token_Comma , NodeKind :: Token_Colon , NodeKind :: Token_LeftBracket , NodeKind :: Token_RightBracket , NodeKind :: Comment , NodeKind :: EscapeSequence , NodeKind :: False , NodeKind :: Null , NodeKind :: Number , NodeKind :: StringContent , NodeKind :: True , NodeKind :: Token_LeftCurly , NodeKind :: Token_RightCurly] ; # [doc = r" Returns the kind of the given node."] # [doc = r""] # [doc = r" # Panics"] # [doc = r""] # [doc = r" Panics if the node was not generated along with this enum."] pub fn of (node : & dyn auto_lsp :: core :: ast :: AstNode) -> Self { match Self :: try_from (node . get_kind_id ()) { Ok (kind) => kind , Err (id) => panic ! ("Unknown node kind {id}") , } } # [doc = r" Returns the tree-sitter kind of the nodes of this kind, `ERROR` for error nodes."] pub fn name (self) -> & 'static str { match self { NodeKind :: ErrorNode => auto_lsp :: core :: ast :: ErrorNode :: KIND_NAME , NodeKind :: Array => "array" , NodeKind :: Document => "document" , NodeKind :: Object => "object" , NodeKind :: Pair => "pair" , NodeKind :: String => "string" , NodeKind :: Token_DoubleQuote => "\"" , NodeKind :: Token_Comma => "," , NodeKind :: Token_Colon => ":" , NodeKind :: Token_LeftBracket => "[" , NodeKind :: Token_RightBracket => "]" , NodeKind :: Comment => "comment" , NodeKind :: EscapeSequence => "escape_sequence" , NodeKind :: False => "false" , NodeKind :: Null => "null" , NodeKind :: Number => "number" , NodeKind :: StringContent => "string_content" , NodeKind :: True => "true" , NodeKind :: Token_LeftCurly => "{" , NodeKind :: Token_RightCurly => "}" } } } impl TryFrom < u16 > for NodeKind { type Error = u16 ; fn try_from (id : u16) -> Result < Self , u16 > { Self :: ALL . get (id as usize) . copied () . ok_or (id) } } impl From < NodeKind > for u16 { fn from (kind : NodeKind) -> u16 { kind as u16 } } # [doc = r" Checks that the node kind and field ids of this AST match the given language."] # [doc = r""] # [doc = r" Fails when the AST was generated from another version of the grammar."] pub fn verify_language (language : & auto_lsp :: core :: tree_sitter :: Language ,) -> Result < () , Vec < auto_lsp :: core :: errors :: Mismatch >> { auto_lsp :: core :: errors :: Mismatch :: check (language , & [("_value" , false , 16u16) , ("array" , true , 19u16) , ("comment" , true , 14u16) , ("document" , true , 15u16) , ("escape_sequence" , true , 9u16) , ("false" , true , 12u16) , ("null" , true , 13u16) , ("number" , true , 10u16) , ("object" , true , 17u16) , ("pair" , true , 18u16) , ("string" , true , 20u16) , ("string_content" , true , 8u16) , ("true" , true , 11u16) , ("\"" , false , 7u16) , ("," , false , 2u16) , (":" , false , 4u16) , ("[" , false , 5u16) , ("]" , false , 6u16) , ("{" , false , 1u16) , ("}" , false , 3u16)] , & [("key" , 1u16) , ("value" , 2u16)] ,) }
\ No newline at end of file
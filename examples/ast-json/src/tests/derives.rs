@@ -0,0 +1,20 @@
+use crate::db::create_json_db;
+use crate::generated::Pair;
+use auto_lsp::default::db::BaseDatabase;
+use auto_lsp::default::db::tracked::get_ast;
+use auto_lsp::lsp_types::Url;
+use std::collections::HashSet;
+
+#[test]
+fn nodes_derive_hash() {
+    let db = create_json_db(&[r#"{"a": 1, "b": 2}"#]);
+    let file = db
+        .get_file(&Url::parse("file:///test0.json").unwrap())
+        .unwrap();
+    let ast = get_ast(&db, file);
+
+    // `Eq` and `Hash` are added by the config of the build script
+    let pairs: HashSet<&Pair> = ast.of_type::<Pair>().collect();
+    assert_eq!(pairs.len(), 2);
+    assert!(pairs.iter().all(|pair| pair.value.is_ok()));
+}
@@ -0,0 +1,65 @@
+use crate::db::create_json_db;
+use auto_lsp::configure_parser;
+use auto_lsp::core::ast::AstNode;
+use auto_lsp::default::db::file::File;
+use auto_lsp::default::db::tracked::get_ast;
+use auto_lsp::default::db::{BaseDatabase, BaseDb, FileManager};
+use auto_lsp::lsp_types::{self, Url};
+
+// `ParseError` is already reported as too large by the parser of `crate::db`
+#[allow(clippy::result_large_err)]
+mod ast {
+    use super::configure_parser;
+
+    auto_lsp::generate_ast!(
+        node_types: "tree-sitter-json/src/node-types.json",
+        language: tree_sitter_json::LANGUAGE,
+        lenient_fields: true,
+        derives: [Eq, Hash],
+    );
+
+    configure_parser!(
+        MACRO_PARSER,
+        language: LANGUAGE,
+        ast_root: Document
+    );
+}
+
+const SOURCE: &str = r#"{"a": [1, true, null], "b": {"c": "d\n"}, "e": }"#;
+
+#[test]
+fn ids_match_language() {
+    // Ids are read from the parser.c next to node-types.json
+    assert_eq!(ast::verify_language(&ast::LANGUAGE.into()), Ok(()));
+}
+
+#[test]
+fn same_ast_as_build_script() {
+    let url = Url::parse("file:///test0.json").unwrap();
+    let mut db = BaseDb::default();
+    let file = File::from_string()
+        .db(&db)
+        .source(SOURCE.to_string())
+        .url(&url)
+        .parsers(&ast::MACRO_PARSER)
+        .encoding(&lsp_types::PositionEncodingKind::UTF8)
+        .call()
+        .unwrap();
+    db.add_file(file).unwrap();
+
+    let build_script_db = create_json_db(&[SOURCE]);
+    let build_script_file = build_script_db.get_file(&url).unwrap();
+
+    let nodes = |ast: Vec<_>| {
+        ast.into_iter()
+            .map(|node: &dyn AstNode| (node.get_kind_id(), node.get_span(), node.get_parent_id()))
+            .collect::<Vec<_>>()
+    };
+    let ast = get_ast(&db, file);
+    let build_script_ast = get_ast(&build_script_db, build_script_file);
+    assert_eq!(
+        nodes(ast.iter().map(|node| node.lower()).collect()),
+        nodes(build_script_ast.iter().map(|node| node.lower()).collect())
+    );
+    assert_eq!(ast.of_type::<ast::Pair>().count(), 4);
+}
@@ -0,0 +1,99 @@
+use auto_lsp::configure_parser;
+use auto_lsp::core::ast::{AstNode, ErrorNode};
+use auto_lsp::core::errors::{AstError, ParseError, ParseErrorAccumulator};
+use auto_lsp::default::db::file::File;
+use auto_lsp::default::db::tracked::get_ast;
+use auto_lsp::default::db::{BaseDb, FileManager};
+use auto_lsp::lsp_types::{
+    self, DidChangeTextDocumentParams, Position, Range, TextDocumentContentChangeEvent, Url,
+};
+
+// `ParseError` is already reported as too large by the parser of `crate::db`
+#[allow(clippy::result_large_err)]
+mod ast {
+    use super::configure_parser;
+
+    // Without `null`, pairs holding a null fail to build even though there is no syntax error
+    auto_lsp::generate_ast!(
+        node_types: "tree-sitter-json/src/node-types.json",
+        language: tree_sitter_json::LANGUAGE,
+        skip_rules: ["null"],
+    );
+
+    configure_parser!(
+        NO_NULL_PARSER,
+        language: LANGUAGE,
+        ast_root: Document
+    );
+}
+
+fn create_db(source: &str) -> (BaseDb, File) {
+    let mut db = BaseDb::default();
+    let file = File::from_string()
+        .db(&db)
+        .source(source.to_string())
+        .url(&Url::parse("file:///test0.json").unwrap())
+        .parsers(&ast::NO_NULL_PARSER)
+        .encoding(&lsp_types::PositionEncodingKind::UTF8)
+        .call()
+        .unwrap();
+    db.add_file(file).unwrap();
+    (db, file)
+}
+
+fn diagnostics(db: &BaseDb, file: File) -> Vec<ParseError> {
+    get_ast::accumulated::<ParseErrorAccumulator>(db, file)
+        .into_iter()
+        .map(|error| error.0.clone())
+        .collect()
+}
+
+#[test]
+fn reused_subtree_reports_its_errors() {
+    let (mut db, file) = create_db("[\n  {\"a\": null},\n  1\n]");
+    assert_eq!(diagnostics(&db, file).len(), 1);
+
+    // Inserts a line before the object, which is reused
+    let event = DidChangeTextDocumentParams {
+        text_document: lsp_types::VersionedTextDocumentIdentifier {
+            uri: file.url(&db).clone(),
+            version: 1,
+        },
+        content_changes: vec![TextDocumentContentChangeEvent {
+            range: Some(Range {
+                start: Position::new(1, 0),
+                end: Position::new(1, 0),
+            }),
+            range_length: None,
+            text: "  2,\n".into(),
+        }],
+    };
+    file.update_edit(&mut db, &event).unwrap();
+
+    let (fresh_db, fresh_file) = create_db(file.document(&db).as_str());
+    let incremental = diagnostics(&db, file);
+    assert_eq!(incremental, diagnostics(&fresh_db, fresh_file));
+
+    // The error moved with the object
+    let ParseError::AstError { error, .. } = &incremental[0] else {
+        panic!("Expected an AST error, got {:?}", incremental[0]);
+    };
+    assert!(matches!(error, AstError::InvalidSupertypeVariant { .. }));
+    assert_eq!(error.range().start_point.row, 2);
+}
+
+#[test]
+fn failed_child_keeps_its_kind() {
+    let (db, file) = create_db("{\"a\": null}");
+    let ast = get_ast(&db, file);
+
+    let object = ast
+        .iter()
+        .find_map(|node| node.lower().downcast_ref::<ast::Object>())
+        .unwrap();
+    let error = object.errors[0].cast(ast);
+    // The error node stands for the pair, but is reported as any other error node
+    assert_eq!(error.kind, "pair");
+    assert_eq!(error.get_kind_name(), ErrorNode::KIND_NAME);
+    assert_eq!(error.get_kind_name(), ast::NodeKind::ErrorNode.name());
+}
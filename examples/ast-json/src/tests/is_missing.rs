@@ -1,9 +1,34 @@
 use crate::db::create_json_db;
-use auto_lsp::default::db::BaseDatabase;
+use crate::generated::{Pair, PairView};
+use auto_lsp::configure_parser;
+use auto_lsp::core::ast::AstNode;
+use auto_lsp::core::errors::MissingField;
+use auto_lsp::default::db::file::File;
 use auto_lsp::default::db::tracked::get_ast;
-use auto_lsp::lsp_types::Url;
+use auto_lsp::default::db::{BaseDatabase, BaseDb, FileManager};
+use auto_lsp::lsp_types::{self, Url};
 use rstest::{fixture, rstest};
 
+// `ParseError` is already reported as too large by the parser of `crate::db`
+#[allow(clippy::result_large_err)]
+mod lenient {
+    use super::configure_parser;
+
+    // Without `null`, the value of a pair holding a null fails to build
+    auto_lsp::generate_ast!(
+        node_types: "tree-sitter-json/src/node-types.json",
+        language: tree_sitter_json::LANGUAGE,
+        lenient_fields: true,
+        skip_rules: ["null"],
+    );
+
+    configure_parser!(
+        NO_NULL_PARSER,
+        language: LANGUAGE,
+        ast_root: Document
+    );
+}
+
 #[fixture]
 fn valid_json() -> impl BaseDatabase {
     create_json_db(&[r#"{"key": "value", "number": 42}"#])
@@ -52,10 +77,10 @@ fn missing_value_detected(missing_value: impl BaseDatabase) {
 
     // MISSING node has a start_byte equals to end_byte
     for node in ast.iter().filter(|n| n.is_missing()) {
-        let range = node.get_range();
+        let span = node.get_span();
         assert_eq!(
-            range.start_byte,
-            range.end_byte,
+            span.start_byte(),
+            span.end_byte(),
             "MISSING node id={} should be zero-width",
             node.get_id()
         );
@@ -75,3 +100,96 @@ fn missing_pair_value_detected(missing_pair_value: impl BaseDatabase) {
         "Expected MISSING nodes in `{{\"a\": 1, \"b\": }}`"
     );
 }
+
+#[rstest]
+fn missing_value_keeps_pair(missing_value: impl BaseDatabase) {
+    let file = missing_value
+        .get_file(&Url::parse("file:///test0.json").unwrap())
+        .unwrap();
+    let ast = get_ast(&missing_value, file);
+
+    // Pairs are generated with lenient fields
+    let pair = ast
+        .iter()
+        .find_map(|node| node.lower().downcast_ref::<Pair>())
+        .expect("Pair should be built");
+    assert!(pair.has_all_required_fields());
+    assert!(
+        pair.key
+            .as_ref()
+            .is_ok_and(|key| !key.cast(ast).is_missing())
+    );
+    assert!(
+        pair.value
+            .as_ref()
+            .is_ok_and(|value| value.cast(ast).is_missing())
+    );
+}
+
+#[rstest]
+fn valid_json_has_all_required_fields(valid_json: impl BaseDatabase) {
+    let file = valid_json
+        .get_file(&Url::parse("file:///test0.json").unwrap())
+        .unwrap();
+    let ast = get_ast(&valid_json, file);
+
+    assert!(ast.iter().all(|node| node.has_all_required_fields()));
+
+    // Lenient fields are viewed as results
+    let document = file.document(&valid_json);
+    let pairs: Vec<_> = ast
+        .of_type::<Pair>()
+        .map(|pair| {
+            let pair = pair.view(ast);
+            (
+                pair.key().unwrap().get_text(document.as_bytes()).unwrap(),
+                pair.value().unwrap().get_text(document.as_bytes()).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![(r#""key""#, r#""value""#), (r#""number""#, "42")]
+    );
+}
+
+#[test]
+fn failed_value_keeps_pair() {
+    use lenient::PairView;
+
+    let mut db = BaseDb::default();
+    let file = File::from_string()
+        .db(&db)
+        .source(r#"{"key": null}"#.to_string())
+        .url(&Url::parse("file:///test0.json").unwrap())
+        .parsers(&lenient::NO_NULL_PARSER)
+        .encoding(&lsp_types::PositionEncodingKind::UTF8)
+        .call()
+        .unwrap();
+    db.add_file(file).unwrap();
+    let ast = get_ast(&db, file);
+
+    // The value could not be built, but the pair is kept without it
+    let pair = ast
+        .iter()
+        .find_map(|node| node.lower().downcast_ref::<lenient::Pair>())
+        .expect("Pair should be built");
+    let missing = MissingField {
+        parent: "pair",
+        field: "value",
+        expected: &["_value"],
+    };
+    assert_eq!(pair.value, Err(missing));
+    assert!(pair.key.is_ok());
+    assert!(!pair.has_all_required_fields());
+
+    let view = pair.view(ast);
+    assert_eq!(view.value().unwrap_err(), missing);
+    assert_eq!(
+        view.key()
+            .unwrap()
+            .get_text(file.document(&db).as_bytes())
+            .unwrap(),
+        r#""key""#
+    );
+}
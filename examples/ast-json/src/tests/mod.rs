@@ -1 +1,4 @@
+mod derives;
+mod generate_ast;
+mod incremental;
 mod is_missing;
@@ -1,8 +1,9 @@
-use auto_lsp_codegen::generate;
-use std::{fs, path::PathBuf};
+use auto_lsp_codegen::Generator;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process::Command};
 
 fn main() {
-    if std::env::var("AST_GEN").unwrap_or("0".to_string()) == "0" {
+    if env::var("AST_GEN").unwrap_or("0".to_string()) == "0" {
         return;
     }
 
@@ -10,12 +11,35 @@ fn main() {
 
     fs::write(
         output_path,
-        generate(
-            tree_sitter_python::NODE_TYPES,
-            &tree_sitter_python::LANGUAGE.into(),
-            None,
-        )
-        .to_string(),
+        Generator::new(&tree_sitter_python::LANGUAGE.into())
+            .with_grammar(&python_grammar())
+            .generate(tree_sitter_python::NODE_TYPES)
+            .unwrap()
+            .to_string(),
     )
     .unwrap();
 }
+
+/// Reads the grammar.json shipped with tree-sitter-python, which is not exported by the crate
+///
+/// The source directory of the dependency is found with `cargo metadata`,
+/// so the grammar always matches the version of the language.
+fn python_grammar() -> String {
+    let output = Command::new(env::var("CARGO").unwrap())
+        .args(["metadata", "--format-version", "1"])
+        .current_dir(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .output()
+        .unwrap();
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let manifest_path = metadata["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|package| package["name"] == "tree-sitter-python")
+        .and_then(|package| package["manifest_path"].as_str())
+        .expect("tree-sitter-python is a build dependency");
+
+    let path = Path::new(manifest_path).with_file_name("src/grammar.json");
+    println!("cargo:rerun-if-changed={}", path.display());
+    fs::read_to_string(path).unwrap()
+}
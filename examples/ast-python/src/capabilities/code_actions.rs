@@ -1,5 +1,4 @@
 use crate::generated::FunctionDefinition;
-use auto_lsp::core::dispatch;
 use auto_lsp::default::db::file::File;
 use auto_lsp::default::db::tracked::get_ast;
 use auto_lsp::default::db::BaseDatabase;
@@ -18,15 +17,9 @@ pub fn code_actions(
         .get_file(&uri)
         .ok_or_else(|| anyhow::format_err!("File not found in workspace"))?;
 
-    get_ast(db, file).iter().try_for_each(|node| {
-        dispatch!(
-            node.lower(),
-            [
-                FunctionDefinition => build_code_actions(db, file, &mut acc)
-            ]
-        );
-        anyhow::Ok(())
-    })?;
+    for function in get_ast(db, file).of_type::<FunctionDefinition>() {
+        function.build_code_actions(db, file, &mut acc)?;
+    }
     Ok(Some(acc))
 }
 
@@ -1,6 +1,5 @@
 use crate::generated::FunctionDefinition;
 use auto_lsp::core::ast::AstNode;
-use auto_lsp::core::dispatch;
 use auto_lsp::default::db::BaseDatabase;
 use auto_lsp::default::db::file::File;
 use auto_lsp::default::db::tracked::{ParsedAst, get_ast};
@@ -20,15 +19,9 @@ pub fn code_lenses(
         .ok_or_else(|| anyhow::format_err!("File not found in workspace"))?;
 
     let ast = get_ast(db, file);
-    ast.iter().try_for_each(|node| {
-        dispatch!(
-            node.lower(),
-            [
-                FunctionDefinition => build_code_lenses(db, file, ast, &mut acc)
-            ]
-        );
-        anyhow::Ok(())
-    })?;
+    for function in ast.of_type::<FunctionDefinition>() {
+        function.build_code_lenses(db, file, ast, &mut acc)?;
+    }
     Ok(Some(acc))
 }
 
@@ -1,7 +1,6 @@
 use crate::generated::FunctionDefinition;
 use auto_lsp::anyhow;
 use auto_lsp::core::ast::AstNode;
-use auto_lsp::core::dispatch;
 use auto_lsp::default::db::file::File;
 use auto_lsp::default::db::tracked::{get_ast, ParsedAst};
 use auto_lsp::default::db::BaseDatabase;
@@ -20,15 +19,9 @@ pub fn inlay_hints(
     let mut acc = vec![];
 
     let ast = get_ast(db, file);
-    ast.iter().try_for_each(|node| {
-        dispatch!(
-            node.lower(),
-            [
-                FunctionDefinition => build_inlay_hints(db, file, ast, &mut acc)
-            ]
-        );
-        anyhow::Ok(())
-    })?;
+    for function in ast.of_type::<FunctionDefinition>() {
+        function.build_inlay_hints(db, file, ast, &mut acc)?;
+    }
     Ok(Some(acc))
 }
 impl FunctionDefinition {
@@ -41,17 +34,17 @@ impl FunctionDefinition {
     ) -> anyhow::Result<()> {
         let doc = file.document(db);
 
-        let range = self.get_range();
+        let span = self.get_span();
         let name = format!(
             "[{} {}] - {}",
-            range.start_byte,
-            range.end_byte,
+            span.start_byte(),
+            span.end_byte(),
             self.name.cast(ast).get_text(doc.as_bytes())?
         );
         acc.push(auto_lsp::lsp_types::InlayHint {
             kind: Some(auto_lsp::lsp_types::InlayHintKind::TYPE),
             label: auto_lsp::lsp_types::InlayHintLabel::String(name),
-            position: self.name.cast(ast).get_start_position(),
+            position: self.name.cast(ast).get_start_position(doc),
             tooltip: None,
             text_edits: None,
             padding_left: None,
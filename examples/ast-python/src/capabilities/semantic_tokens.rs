@@ -1,6 +1,5 @@
 use crate::generated::FunctionDefinition;
 use auto_lsp::core::ast::AstNode;
-use auto_lsp::core::dispatch;
 use auto_lsp::core::semantic_tokens_builder::SemanticTokensBuilder;
 use auto_lsp::default::db::BaseDatabase;
 use auto_lsp::default::db::file::File;
@@ -11,6 +10,7 @@ use auto_lsp::{anyhow, define_semantic_token_modifiers, define_semantic_token_ty
 define_semantic_token_types![
     standard {
         FUNCTION,
+        KEYWORD,
     }
 
     custom {}
@@ -37,15 +37,9 @@ pub fn semantic_tokens_full(
     let mut builder = SemanticTokensBuilder::new("".into());
 
     let ast = get_ast(db, file);
-    ast.iter().try_for_each(|node| {
-        dispatch!(
-            node.lower(),
-            [
-                FunctionDefinition => build_semantic_tokens(db, file, ast, &mut builder)
-            ]
-        );
-        anyhow::Ok(())
-    })?;
+    for function in ast.of_type::<FunctionDefinition>() {
+        function.build_semantic_tokens(db, file, ast, &mut builder)?;
+    }
     Ok(Some(SemanticTokensResult::Tokens(builder.build())))
 }
 
@@ -62,19 +56,10 @@ pub fn semantic_tokens_range(
     let mut builder = SemanticTokensBuilder::new("".into());
 
     let ast = get_ast(db, file);
-    for node in ast.iter() {
-        let range = node.get_lsp_range(file.document(db))?;
-        if range.end <= params.range.start {
-            continue;
-        }
-        if range.start >= params.range.end {
-            break;
-        }
-        dispatch!(node.lower(),
-            [
-                FunctionDefinition => build_semantic_tokens(db, file, ast, &mut builder)
-            ]
-        );
+    for function in
+        ast.nodes_intersecting_of::<FunctionDefinition>(file.document(db), &params.range)
+    {
+        function.build_semantic_tokens(db, file, ast, &mut builder)?;
     }
 
     Ok(Some(SemanticTokensResult::Tokens(builder.build())))
@@ -88,6 +73,13 @@ impl FunctionDefinition {
         ast: &ParsedAst,
         builder: &mut SemanticTokensBuilder,
     ) -> anyhow::Result<()> {
+        if let Some(async_token) = &self.async_token {
+            builder.push(
+                async_token.cast(ast).get_lsp_range(file.document(db))?,
+                SUPPORTED_TYPES.iter().position(|x| *x == KEYWORD).unwrap() as u32,
+                0,
+            );
+        }
         builder.push(
             self.name.cast(ast).get_lsp_range(file.document(db))?,
             SUPPORTED_TYPES.iter().position(|x| *x == FUNCTION).unwrap() as u32,
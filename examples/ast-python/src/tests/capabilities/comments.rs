@@ -0,0 +1,105 @@
+use crate::db::create_python_db;
+use crate::generated::{ExpressionStatement, FunctionDefinition, PassStatement, ReturnStatement};
+use auto_lsp::core::ast::{AstNode, Comment, CommentRules, Comments};
+use auto_lsp::default::db::BaseDatabase;
+use auto_lsp::default::db::tracked::{ParsedAst, get_ast};
+use auto_lsp::lsp_types::Url;
+use rstest::{fixture, rstest};
+
+#[fixture]
+fn commented() -> impl BaseDatabase {
+    create_python_db(&[r#"# License header
+
+# Foo docs
+# more docs
+def foo():
+    x = 1  # trailing x
+    pass
+
+def bar():
+    # inside
+    return 1
+"#])
+}
+
+/// Returns the id of the nth node that is a `T`
+fn find<T: AstNode>(ast: &ParsedAst, nth: usize) -> usize {
+    (0..ast.len())
+        .filter(|id| ast.get::<T>(*id).is_some())
+        .nth(nth)
+        .unwrap()
+}
+
+#[rstest]
+fn leading_and_trailing(commented: impl BaseDatabase) {
+    let file = commented
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let source = file.document(&commented).as_bytes();
+    let ast = get_ast(&commented, file);
+    let text = |comments: &[Comment]| {
+        comments
+            .iter()
+            .map(|c| c.get_text(source).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(ast.comments().len(), 5);
+
+    // The license header is separated by a blank line
+    let foo = find::<FunctionDefinition>(ast, 0);
+    assert_eq!(
+        text(ast.leading_comments(foo)),
+        vec!["# Foo docs", "# more docs"]
+    );
+    assert!(ast.trailing_comment(foo).is_none());
+
+    let x = find::<ExpressionStatement>(ast, 0);
+    assert!(ast.leading_comments(x).is_empty());
+    assert_eq!(
+        ast.trailing_comment(x).unwrap().get_text(source).unwrap(),
+        "# trailing x"
+    );
+
+    let bar = find::<FunctionDefinition>(ast, 1);
+    assert!(ast.leading_comments(bar).is_empty());
+    let ret = find::<ReturnStatement>(ast, 0);
+    assert_eq!(text(ast.leading_comments(ret)), vec!["# inside"]);
+}
+
+#[rstest]
+fn custom_rules(commented: impl BaseDatabase) {
+    let file = commented
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&commented);
+    let source = document.as_bytes();
+    let ast = get_ast(&commented, file);
+    let foo = ast[find::<FunctionDefinition>(ast, 0)].get_span();
+    let x = ast[find::<ExpressionStatement>(ast, 0)].get_span();
+    let pass = ast[find::<PassStatement>(ast, 0)].get_span();
+
+    let comments = Comments::new(
+        ast,
+        document,
+        &CommentRules {
+            max_blank_lines: 1,
+            trailing: false,
+            ..Default::default()
+        },
+    );
+
+    // The license header is now part of the leading comments
+    assert_eq!(comments.leading(foo).len(), 3);
+    assert_eq!(
+        comments.leading(foo)[0].get_text(source),
+        Ok("# License header")
+    );
+
+    // The trailing comment becomes a leading comment of the next statement
+    assert!(comments.trailing(x).is_none());
+    assert_eq!(
+        comments.leading(pass)[0].get_text(source),
+        Ok("# trailing x")
+    );
+}
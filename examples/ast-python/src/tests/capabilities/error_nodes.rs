@@ -0,0 +1,80 @@
+use crate::db::create_python_db;
+use crate::generated::{FunctionDefinition, List};
+use auto_lsp::core::ast::{AstNode, ErrorNode};
+use auto_lsp::default::db::BaseDatabase;
+use auto_lsp::default::db::tracked::get_ast;
+use auto_lsp::lsp_types::Url;
+use rstest::rstest;
+
+#[rstest]
+fn error_node_in_function() {
+    let db = create_python_db(&[r#"class A:
+    def foo(self):
+        return 1 +
+"#]);
+    let file = db
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&db);
+    let ast = get_ast(&db, file);
+
+    // The function is still built, the broken statement becomes an error node
+    let function = ast
+        .iter()
+        .find_map(|node| node.lower().downcast_ref::<FunctionDefinition>())
+        .unwrap();
+    assert_eq!(function.errors.len(), 1);
+
+    let error = function.errors[0].cast(ast);
+    assert_eq!(error.kind, "ERROR");
+    assert_eq!(error.get_kind_name(), ErrorNode::KIND_NAME);
+    assert_eq!(error.child_kinds, vec!["return", "integer", "+"]);
+    assert_eq!(error.get_parent_id(), Some(function.get_id()));
+    assert_eq!(error.get_text(document.as_bytes()).unwrap(), "return 1 +");
+    assert!(function.get_children_ids().contains(&error.get_id()));
+
+    assert!(
+        ast.pretty(document)
+            .to_string()
+            .contains("        ErrorNode 2:8..2:18 \"return 1 +\"\n")
+    );
+}
+
+#[rstest]
+fn error_node_in_list() {
+    let db = create_python_db(&["x = [1, 2,, 3]\n"]);
+    let file = db
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&db);
+    let ast = get_ast(&db, file);
+
+    let list = ast
+        .iter()
+        .find_map(|node| node.lower().downcast_ref::<List>())
+        .unwrap();
+    // Valid elements are kept
+    assert_eq!(list.children.len(), 3);
+    assert_eq!(list.errors.len(), 1);
+    assert_eq!(
+        list.errors[0]
+            .cast(ast)
+            .get_text(document.as_bytes())
+            .unwrap(),
+        ","
+    );
+}
+
+#[rstest]
+fn no_error_nodes() {
+    let db = create_python_db(&["def foo(a):\n    return a + 1\n"]);
+    let file = db
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let ast = get_ast(&db, file);
+
+    assert!(
+        ast.iter()
+            .all(|node| node.lower().downcast_ref::<ErrorNode>().is_none())
+    );
+}
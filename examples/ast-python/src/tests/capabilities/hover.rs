@@ -28,6 +28,7 @@ fn foo_bar_hover(foo_bar: impl BaseDatabase) {
     let file = foo_bar
         .get_file(&Url::parse("file:///test0.py").unwrap())
         .unwrap();
+    let document = file.document(&foo_bar);
     let ast = get_ast(&foo_bar, file);
     let root = ast.get_root().unwrap();
     let module = root.downcast_ref::<Module>().unwrap();
@@ -46,7 +47,7 @@ fn foo_bar_hover(foo_bar: impl BaseDatabase) {
                     text_document: lsp_types::TextDocumentIdentifier {
                         uri: file.url(&foo_bar).clone(),
                     },
-                    position: foo_name.cast(ast).get_start_position(),
+                    position: foo_name.cast(ast).get_start_position(document),
                 },
                 work_done_progress_params: Default::default(),
             },
@@ -79,7 +80,7 @@ fn foo_bar_hover(foo_bar: impl BaseDatabase) {
                     text_document: lsp_types::TextDocumentIdentifier {
                         uri: file.url(&foo_bar).clone(),
                     },
-                    position: bar_name.cast(ast).get_start_position(),
+                    position: bar_name.cast(ast).get_start_position(document),
                 },
                 work_done_progress_params: Default::default(),
             },
@@ -1,8 +1,13 @@
 use crate::db::create_python_db;
-use crate::generated::{CompoundStatement_SimpleStatement, PassStatement, SimpleStatement};
+use crate::generated::{
+    BlockView, ClassDefinition, CompoundStatement_SimpleStatement, FunctionDefinition,
+    FunctionDefinitionView, Identifier, Module, ModuleView, NodeKind, ParametersView,
+    PassStatement, SimpleStatement, TypedDefaultParameterView,
+};
+use auto_lsp::core::ast::{AstArena, AstNode};
 use auto_lsp::default::db::BaseDatabase;
 use auto_lsp::default::db::tracked::get_ast;
-use auto_lsp::lsp_types::{Position, Url};
+use auto_lsp::lsp_types::{Position, Range, Url};
 use rstest::{fixture, rstest};
 
 #[fixture]
@@ -127,7 +132,7 @@ fn parents(foo_bar: impl BaseDatabase) {
     assert!(ast.get_root().unwrap().get_parent(ast).is_none());
 
     // All other nodes should have a parent
-    for node in ast[1..ast.len() - 1].iter() {
+    for node in ast.iter().skip(1) {
         assert!(node.get_parent(ast).is_some());
     }
 
@@ -203,3 +208,420 @@ fn descendant_at_between_siblings(foo_bar: impl BaseDatabase) {
         "descendant_at should return None past the end"
     );
 }
+
+#[rstest]
+fn children(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    // The module has two statements: foo and bar
+    assert_eq!(
+        ast.children(0)
+            .map(|n| n.get_text(source_code).unwrap())
+            .collect::<Vec<_>>(),
+        vec![
+            "def foo(param1, param2: int, param3: int = 5):\n    pass",
+            "def bar():\n    pass"
+        ]
+    );
+
+    // Generated children ids should match the children found through the subtree ranges
+    for node in ast.iter() {
+        assert_eq!(
+            node.get_children_ids().to_vec(),
+            ast.children(node.get_id())
+                .map(|n| n.get_id())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    // `foo` is a function definition with a name, parameters and a body
+    let foo = ast.children(0).next().unwrap();
+    assert_eq!(
+        ast.children(foo.get_id())
+            .map(|n| n.get_text(source_code).unwrap())
+            .collect::<Vec<_>>(),
+        vec!["foo", "(param1, param2: int, param3: int = 5)", "pass"]
+    );
+
+    // Out of bounds ids have no children
+    assert_eq!(ast.children(1000).count(), 0);
+}
+
+#[rstest]
+fn siblings(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    let foo = ast.children(0).next().unwrap();
+    let bar = ast.next_sibling(foo.get_id()).unwrap();
+    assert_eq!(bar.get_text(source_code).unwrap(), "def bar():\n    pass");
+    assert_eq!(
+        ast.prev_sibling(bar.get_id()).unwrap().get_id(),
+        foo.get_id()
+    );
+
+    // First and last children have no previous and next siblings
+    assert!(ast.prev_sibling(foo.get_id()).is_none());
+    assert!(ast.next_sibling(bar.get_id()).is_none());
+
+    // The root node has no siblings
+    assert!(ast.next_sibling(0).is_none());
+    assert!(ast.prev_sibling(0).is_none());
+
+    // "param1" -> "param2: int" -> "param3: int = 5"
+    let parameters = ast.children(foo.get_id()).nth(1).unwrap();
+    let param1 = ast.children(parameters.get_id()).next().unwrap();
+    let param2 = ast.next_sibling(param1.get_id()).unwrap();
+    let param3 = ast.next_sibling(param2.get_id()).unwrap();
+    assert_eq!(param2.get_text(source_code).unwrap(), "param2: int");
+    assert_eq!(param3.get_text(source_code).unwrap(), "param3: int = 5");
+    assert!(ast.next_sibling(param3.get_id()).is_none());
+}
+
+#[rstest]
+fn descendants(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    // The root node contains every other node
+    assert_eq!(ast.descendants(0).len(), ast.len() - 1);
+
+    let bar = ast.children(0).nth(1).unwrap();
+    assert_eq!(
+        ast.descendants(bar.get_id())
+            .map(|n| n.get_text(source_code).unwrap())
+            .collect::<Vec<_>>(),
+        vec!["bar", "()", "pass", "pass"]
+    );
+
+    // All descendants are contained in the range of their ancestor
+    for node in ast.iter() {
+        for descendant in ast.descendants(node.get_id()) {
+            assert!(descendant.get_span().start_byte() >= node.get_span().start_byte());
+            assert!(descendant.get_span().end_byte() <= node.get_span().end_byte());
+        }
+    }
+
+    // Leaves and out of bounds ids have no descendants
+    assert_eq!(ast.descendants(ast.len() - 1).len(), 0);
+    assert_eq!(ast.descendants(1000).len(), 0);
+}
+
+#[rstest]
+fn ancestors(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    // (1, 16) is inside "param2"
+    let param2 = ast
+        .descendant_for_position(document, &position(1, 16))
+        .unwrap();
+    assert_eq!(param2.get_text(source_code).unwrap(), "param2");
+
+    // From the closest ancestor to the root
+    assert_eq!(
+        ast.ancestors(param2.get_id())
+            .map(|n| n.get_kind_name())
+            .collect::<Vec<_>>(),
+        vec![
+            "typed_parameter",
+            "parameters",
+            "function_definition",
+            "module"
+        ]
+    );
+    assert_eq!(
+        ast.ancestors(param2.get_id())
+            .map(|n| n.get_text(source_code).unwrap())
+            .take(2)
+            .collect::<Vec<_>>(),
+        vec!["param2: int", "(param1, param2: int, param3: int = 5)"]
+    );
+
+    // The enclosing function of "param2" is foo
+    let function = ast
+        .ancestor_of_type::<FunctionDefinition>(param2.get_id())
+        .unwrap();
+    assert_eq!(
+        function.name.cast(ast).get_text(source_code).unwrap(),
+        "foo"
+    );
+    assert!(
+        ast.ancestor_of_type::<ClassDefinition>(param2.get_id())
+            .is_none()
+    );
+
+    // The root node has no ancestors
+    assert_eq!(ast.ancestors(0).count(), 0);
+    assert_eq!(ast.ancestors(1000).count(), 0);
+}
+
+#[rstest]
+fn typed_lookups(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    assert!(ast.get::<Module>(0).is_some());
+    assert!(ast.get::<Identifier>(0).is_none());
+    assert!(ast.get::<Module>(1000).is_none());
+
+    // foo is stored as a statement enum, but can be retrieved as its innermost struct
+    assert!(ast.get::<CompoundStatement_SimpleStatement>(1).is_some());
+    let foo = ast.get::<FunctionDefinition>(1).unwrap();
+
+    assert_eq!(
+        foo.name
+            .try_cast(ast)
+            .unwrap()
+            .get_text(source_code)
+            .unwrap(),
+        "foo"
+    );
+    // Out of bounds
+    assert!(foo.name.try_cast(&AstArena::default()).is_none());
+}
+
+fn range(start: Position, end: Position) -> Range {
+    Range { start, end }
+}
+
+#[rstest]
+fn nodes_in_range(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    let kinds: Vec<_> = ast
+        .nodes_in_range(document, &range(position(2, 4), position(2, 8)))
+        .map(|node| node.get_kind_name())
+        .collect();
+    assert_eq!(kinds, vec!["block", "pass_statement"]);
+
+    let functions: Vec<_> = ast
+        .nodes_in_range_of::<FunctionDefinition>(document, &range(position(0, 0), position(3, 0)))
+        .map(|node| node.name.cast(ast).get_text(source_code).unwrap())
+        .collect();
+    assert_eq!(functions, vec!["foo"]);
+
+    let functions: Vec<_> = ast
+        .nodes_in_range_of::<FunctionDefinition>(document, &range(position(0, 0), position(6, 0)))
+        .map(|node| node.name.cast(ast).get_text(source_code).unwrap())
+        .collect();
+    assert_eq!(functions, vec!["foo", "bar"]);
+
+    // Out of bounds
+    assert_eq!(
+        ast.nodes_in_range(document, &range(position(100, 0), position(101, 0)))
+            .count(),
+        0
+    );
+}
+
+#[rstest]
+fn nodes_intersecting(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    // Enclosing nodes are included
+    let kinds: Vec<_> = ast
+        .nodes_intersecting(document, &range(position(2, 5), position(2, 6)))
+        .map(|node| node.get_kind_name())
+        .collect();
+    assert_eq!(
+        kinds,
+        vec!["module", "function_definition", "block", "pass_statement"]
+    );
+
+    // bar starts at the end of the range
+    let kinds: Vec<_> = ast
+        .nodes_intersecting(document, &range(position(3, 0), position(4, 0)))
+        .map(|node| node.get_kind_name())
+        .collect();
+    assert_eq!(kinds, vec!["module"]);
+
+    let functions: Vec<_> = ast
+        .nodes_intersecting_of::<FunctionDefinition>(
+            document,
+            &range(position(2, 0), position(4, 5)),
+        )
+        .map(|node| node.name.cast(ast).get_text(source_code).unwrap())
+        .collect();
+    assert_eq!(functions, vec!["foo", "bar"]);
+}
+
+#[rstest]
+fn of_type(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    // Innermost struct
+    let functions: Vec<_> = ast
+        .of_type::<FunctionDefinition>()
+        .map(|node| node.name.cast(ast).get_text(source_code).unwrap())
+        .collect();
+    assert_eq!(functions, vec!["foo", "bar"]);
+
+    let functions: Vec<_> = ast
+        .of_type::<FunctionDefinition>()
+        .rev()
+        .map(|node| node.name.cast(ast).get_text(source_code).unwrap())
+        .collect();
+    assert_eq!(functions, vec!["bar", "foo"]);
+
+    // Stored type, the functions and their statements
+    assert_eq!(
+        ast.of_type::<CompoundStatement_SimpleStatement>().count(),
+        4
+    );
+    assert_eq!(ast.of_type::<PassStatement>().count(), 2);
+    assert_eq!(ast.of_type::<ClassDefinition>().count(), 0);
+
+    // Same nodes as a full iteration
+    assert!(
+        ast.of_type::<Identifier>()
+            .map(|node| node.get_id())
+            .eq((0..ast.len()).filter(|id| ast.get::<Identifier>(*id).is_some()))
+    );
+}
+
+#[rstest]
+fn node_kind(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let ast = get_ast(&foo_bar, file);
+
+    // Enums share the kind of their struct
+    let function = ast
+        .of_type::<CompoundStatement_SimpleStatement>()
+        .next()
+        .unwrap();
+    assert_eq!(function.kind(), NodeKind::FunctionDefinition);
+    assert_eq!(NodeKind::of(function), NodeKind::FunctionDefinition);
+    assert_eq!(
+        ast.get::<FunctionDefinition>(function.get_id())
+            .unwrap()
+            .kind(),
+        NodeKind::FunctionDefinition
+    );
+
+    // Kinds can index tables
+    let mut counts = [0; NodeKind::COUNT];
+    for node in ast.iter() {
+        let kind = NodeKind::of(node);
+        assert_eq!(kind.name(), node.get_kind_name());
+        counts[kind as usize] += 1;
+    }
+    assert_eq!(counts[NodeKind::FunctionDefinition as usize], 2);
+    assert_eq!(counts[NodeKind::PassStatement as usize], 2);
+    assert_eq!(counts[NodeKind::ErrorNode as usize], 0);
+
+    assert_eq!(
+        NodeKind::try_from(u16::from(NodeKind::Module)),
+        Ok(NodeKind::Module)
+    );
+    assert_eq!(NodeKind::try_from(u16::MAX), Err(u16::MAX));
+    assert_eq!(NodeKind::ErrorNode.name(), "ERROR");
+}
+
+#[rstest]
+fn ast_view(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let source_code = document.as_bytes();
+    let ast = get_ast(&foo_bar, file);
+
+    let foo = ast
+        .of_type::<FunctionDefinition>()
+        .next()
+        .unwrap()
+        .view(ast);
+
+    // Required field
+    assert_eq!(foo.name().get_text(source_code).unwrap(), "foo");
+
+    // Optional field
+    assert!(foo.return_type().is_none());
+    assert!(foo.type_parameters().is_none());
+
+    // Repeated children, through a chain of views
+    let parameters: Vec<_> = foo
+        .parameters()
+        .children()
+        .map(|parameter| parameter.get_text(source_code).unwrap())
+        .collect();
+    assert_eq!(parameters, vec!["param1", "param2: int", "param3: int = 5"]);
+    assert_eq!(foo.parameters().children().len(), 3);
+
+    // Views dereference to their node
+    assert_eq!(foo.get_id(), foo.node().get_id());
+    assert_eq!(foo.body().children().count(), 1);
+}
+
+#[rstest]
+fn enum_helpers(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let ast = get_ast(&foo_bar, file);
+
+    let module = ast.get_root().unwrap().downcast_ref::<Module>().unwrap();
+    let statements: Vec<_> = module.view(ast).children().collect();
+
+    // `FunctionDefinition` is nested in the `CompoundStatement` supertype
+    assert!(statements[0].is_compound_statement());
+    assert!(statements[0].is_function_definition());
+    assert!(!statements[0].is_class_definition());
+    assert!(!statements[0].is_simple_statement());
+
+    let foo = statements[0].as_function_definition().unwrap();
+    assert_eq!(foo.get_id(), statements[0].get_id());
+    assert!(statements[0].as_pass_statement().is_none());
+
+    // The default value of `param3` is nested in `Expression` then `PrimaryExpression`
+    let default_value = foo
+        .view(ast)
+        .parameters()
+        .children()
+        .filter_map(|parameter| parameter.node().as_typed_default_parameter())
+        .map(|parameter| parameter.view(ast).value())
+        .next()
+        .unwrap();
+    assert!(default_value.is_primary_expression());
+    assert!(default_value.is_integer());
+    assert!(default_value.as_float().is_none());
+}
@@ -1,7 +1,11 @@
 mod code_actions;
 mod code_lenses;
+mod comments;
 mod document_symbols;
+mod error_nodes;
 mod hover;
 mod inlay_hints;
 mod iter;
+mod printer;
 mod semantic_tokens;
+mod visitor;
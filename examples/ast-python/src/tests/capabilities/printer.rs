@@ -0,0 +1,93 @@
+use crate::db::create_python_db;
+use crate::generated::{CompoundStatement_SimpleStatement, FunctionDefinition};
+use auto_lsp::core::ast::PrintFormat;
+use auto_lsp::default::db::BaseDatabase;
+use auto_lsp::default::db::tracked::get_ast;
+use auto_lsp::lsp_types::Url;
+use rstest::{fixture, rstest};
+
+#[fixture]
+fn foo() -> impl BaseDatabase {
+    create_python_db(&[r#"def foo(a):
+    return a + 1
+"#])
+}
+
+#[rstest]
+fn pretty(foo: impl BaseDatabase) {
+    let file = foo
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo);
+    let ast = get_ast(&foo, file);
+
+    assert_eq!(
+        ast.pretty(document).to_string(),
+        r#"Module 0:0..2:0 "def foo(a):..."
+  FunctionDefinition 0:0..1:16 "def foo(a):..."
+    name: Identifier 0:4..0:7 "foo"
+    parameters: Parameters 0:7..0:10 "(a)"
+      Identifier 0:8..0:9 "a"
+    body: Block 1:4..1:16 "return a + 1"
+      ReturnStatement 1:4..1:16 "return a + 1"
+        BinaryOperator 1:11..1:16 "a + 1"
+          left: Identifier 1:11..1:12 "a"
+          operator: Token_Plus 1:13..1:14 "+"
+          right: Integer 1:15..1:16 "1"
+"#
+    );
+}
+
+#[rstest]
+fn sexp_matches_tree_sitter(foo: impl BaseDatabase) {
+    let file = foo
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo);
+    let ast = get_ast(&foo, file);
+
+    assert_eq!(
+        ast.pretty(document)
+            .format(PrintFormat::SExpression)
+            .to_string(),
+        document.tree.root_node().to_sexp()
+    );
+}
+
+#[rstest]
+fn dot(foo: impl BaseDatabase) {
+    let file = foo
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo);
+    let ast = get_ast(&foo, file);
+    let dot = ast.pretty(document).format(PrintFormat::Dot).to_string();
+
+    assert!(dot.starts_with("digraph ast {\n  node [shape=box];\n"));
+    assert!(dot.contains(r#"  n0 [label="Module\n0:0..2:0\n\"def foo(a):...\""];"#));
+    assert!(dot.contains(r#"  n1 -> n2 [label="name"];"#));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[rstest]
+fn serialize_without_descendants(foo: impl BaseDatabase) {
+    let file = foo
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let ast = get_ast(&foo, file);
+    let function = ast.of_type::<FunctionDefinition>().next().unwrap();
+
+    // Fields hold the IDs of the nodes they point to
+    let json = auto_lsp::core::serde_json::to_value(function).unwrap();
+    assert_eq!(
+        json.to_string(),
+        r#"{"id":1,"kind":"function_definition","span":{"start":0,"end":28},"fields":{"body":5,"name":2,"parameters":3,"return_type":null,"type_parameters":null,"async_token":null}}"#
+    );
+
+    // Enums are serialized as their variant
+    let statement = ast.get::<CompoundStatement_SimpleStatement>(1).unwrap();
+    assert_eq!(
+        auto_lsp::core::serde_json::to_value(statement).unwrap(),
+        json
+    );
+}
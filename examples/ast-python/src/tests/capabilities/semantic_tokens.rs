@@ -1,10 +1,12 @@
 use crate::capabilities::semantic_tokens::{
-    semantic_tokens_full, DECLARATION, FUNCTION, SUPPORTED_MODIFIERS, SUPPORTED_TYPES,
+    semantic_tokens_full, semantic_tokens_range, DECLARATION, FUNCTION, KEYWORD,
+    SUPPORTED_MODIFIERS, SUPPORTED_TYPES,
 };
 use crate::db::create_python_db;
 use auto_lsp::default::db::BaseDatabase;
 use auto_lsp::lsp_types::{
-    SemanticTokensParams, SemanticTokensResult, TextDocumentIdentifier, Url,
+    Position, Range, SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensResult,
+    TextDocumentIdentifier, Url,
 };
 use rstest::{fixture, rstest};
 
@@ -84,3 +86,89 @@ fn foo_bar_semantic_tokens(foo_bar: impl BaseDatabase) {
     assert_eq!(tokens[1].delta_start, 4);
     assert_eq!(tokens[1].length, 3); // def
 }
+
+#[rstest]
+fn foo_bar_semantic_tokens_range(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+
+    let tokens = semantic_tokens_range(
+        &foo_bar,
+        SemanticTokensRangeParams {
+            text_document: TextDocumentIdentifier {
+                uri: file.url(&foo_bar).clone(),
+            },
+            range: Range {
+                start: Position::new(4, 0),
+                end: Position::new(5, 0),
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        },
+    )
+    .unwrap()
+    .unwrap();
+
+    let tokens = if let SemanticTokensResult::Tokens(tokens) = tokens {
+        tokens.data
+    } else {
+        panic!("Expected tokens");
+    };
+
+    // Only bar is in the range
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].delta_line, 4);
+    assert_eq!(tokens[0].delta_start, 4);
+}
+
+#[fixture]
+fn async_foo() -> impl BaseDatabase {
+    create_python_db(&[r#"async def foo():
+    pass
+"#])
+}
+
+#[rstest]
+fn async_keyword_semantic_tokens(async_foo: impl BaseDatabase) {
+    let file = async_foo
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+
+    let tokens = semantic_tokens_full(
+        &async_foo,
+        SemanticTokensParams {
+            text_document: TextDocumentIdentifier {
+                uri: file.url(&async_foo).clone(),
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        },
+    )
+    .unwrap()
+    .unwrap();
+
+    let tokens = if let SemanticTokensResult::Tokens(tokens) = tokens {
+        tokens.data
+    } else {
+        panic!("Expected tokens");
+    };
+
+    // async and foo
+    assert_eq!(tokens.len(), 2);
+
+    assert_eq!(
+        tokens[0].token_type,
+        SUPPORTED_TYPES.iter().position(|x| *x == KEYWORD).unwrap() as u32,
+    );
+    assert_eq!(tokens[0].delta_start, 0);
+    assert_eq!(tokens[0].length, 5); // async
+
+    assert_eq!(
+        tokens[1].token_type,
+        SUPPORTED_TYPES.iter().position(|x| *x == FUNCTION).unwrap() as u32,
+    );
+    // foo is at char 10
+    assert_eq!(tokens[1].delta_line, 0);
+    assert_eq!(tokens[1].delta_start, 10);
+}
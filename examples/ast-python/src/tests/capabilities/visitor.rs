@@ -0,0 +1,109 @@
+use crate::db::create_python_db;
+use crate::generated::{FunctionDefinition, Identifier, Module, Visitor, walk_function_definition};
+use auto_lsp::core::ast::{AstArena, AstNode};
+use auto_lsp::default::db::BaseDatabase;
+use auto_lsp::default::db::tracked::get_ast;
+use auto_lsp::lsp_types::Url;
+use rstest::{fixture, rstest};
+use std::ops::ControlFlow;
+
+#[fixture]
+fn foo_bar() -> impl BaseDatabase {
+    create_python_db(&[r#"# foo comment
+def foo(param1, param2: int, param3: int = 5):
+    pass
+
+def bar():
+    pass
+"#])
+}
+
+/// Records the ids of entered and left nodes
+#[derive(Default)]
+struct Recorder {
+    entered: Vec<usize>,
+    left: Vec<usize>,
+}
+
+impl Visitor for Recorder {
+    fn enter(&mut self, _nodes: &AstArena, node: &dyn AstNode) -> ControlFlow<()> {
+        self.entered.push(node.get_id());
+        ControlFlow::Continue(())
+    }
+
+    fn leave(&mut self, _nodes: &AstArena, node: &dyn AstNode) {
+        self.left.push(node.get_id());
+    }
+}
+
+#[rstest]
+fn enter_leave(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let ast = get_ast(&foo_bar, file);
+    let module = ast.get_root().unwrap().downcast_ref::<Module>().unwrap();
+
+    let mut recorder = Recorder::default();
+    recorder.visit_module(ast, module);
+
+    // Every node is entered once, in source order
+    assert_eq!(recorder.entered, (0..ast.len()).collect::<Vec<_>>());
+
+    // Nodes are left once all their children have been left, the root being the last one
+    assert_eq!(recorder.left.len(), ast.len());
+    assert_eq!(recorder.left.last(), Some(&0));
+    for (i, id) in recorder.left.iter().enumerate() {
+        for descendant in ast.descendants(*id) {
+            assert!(recorder.left[..i].contains(&descendant.get_id()));
+        }
+    }
+}
+
+/// Collects the names of all function definitions, skipping the body of `foo`
+struct FunctionNames<'a> {
+    source_code: &'a [u8],
+    names: Vec<&'a str>,
+    identifiers: Vec<&'a str>,
+}
+
+impl Visitor for FunctionNames<'_> {
+    fn enter(&mut self, _nodes: &AstArena, node: &dyn AstNode) -> ControlFlow<()> {
+        match node.get_text(self.source_code).unwrap() {
+            "(param1, param2: int, param3: int = 5)" => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    }
+
+    fn visit_function_definition(&mut self, nodes: &AstArena, node: &FunctionDefinition) {
+        self.names
+            .push(node.name.cast(nodes).get_text(self.source_code).unwrap());
+        walk_function_definition(self, nodes, node);
+    }
+
+    fn visit_identifier(&mut self, _nodes: &AstArena, node: &Identifier) {
+        self.identifiers
+            .push(node.get_text(self.source_code).unwrap());
+    }
+}
+
+#[rstest]
+fn visit_and_skip(foo_bar: impl BaseDatabase) {
+    let file = foo_bar
+        .get_file(&Url::parse("file:///test0.py").unwrap())
+        .unwrap();
+    let document = file.document(&foo_bar);
+    let ast = get_ast(&foo_bar, file);
+    let module = ast.get_root().unwrap().downcast_ref::<Module>().unwrap();
+
+    let mut visitor = FunctionNames {
+        source_code: document.as_bytes(),
+        names: vec![],
+        identifiers: vec![],
+    };
+    visitor.visit_module(ast, module);
+
+    assert_eq!(visitor.names, vec!["foo", "bar"]);
+    // Parameters of foo are skipped
+    assert_eq!(visitor.identifiers, vec!["foo", "bar"]);
+}
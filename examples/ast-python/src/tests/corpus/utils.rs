@@ -11,7 +11,7 @@ macro_rules! snap {
             .get_file(&::auto_lsp::lsp_types::Url::parse("file:///test0.py").unwrap())
             .unwrap();
 
-        insta::assert_debug_snapshot!(get_ast(&db, file));
+        insta::assert_json_snapshot!(get_ast(&db, file).to_json(file.document(&db))?);
 
         let errors =
             get_ast::accumulated::<auto_lsp::core::errors::ParseErrorAccumulator>(&db, file);
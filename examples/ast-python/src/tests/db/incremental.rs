@@ -0,0 +1,222 @@
+use crate::db::PYTHON;
+use crate::generated::{CompoundStatement_SimpleStatement, FunctionDefinition};
+use auto_lsp::core::ast::{AstNode, StableNodeId};
+use auto_lsp::core::errors::{ParseError, ParseErrorAccumulator};
+use auto_lsp::default::db::file::File;
+use auto_lsp::default::db::tracked::{ParsedAst, get_ast};
+use auto_lsp::default::db::{BaseDb, FileManager};
+use auto_lsp::lsp_types::{
+    self, DidChangeTextDocumentParams, Position, Range, TextDocumentContentChangeEvent, Url,
+};
+use rstest::rstest;
+
+static SOURCE: &str = r#"# comment
+def foo(param1, param2: int = 5):
+    x = 1; y = 2
+    pass
+
+class Bar:
+    def baz(self):
+        return [1, 2, 3]
+
+def qux():
+    pass
+"#;
+
+fn create_db(source: &str) -> (BaseDb, File) {
+    let mut db = BaseDb::default();
+    let file = File::from_string()
+        .db(&db)
+        .source(source.to_string())
+        .url(&Url::parse("file:///test0.py").unwrap())
+        .parsers(&PYTHON)
+        .encoding(&lsp_types::PositionEncodingKind::UTF8)
+        .call()
+        .expect("Failed to create file");
+    db.add_file(file).expect("Failed to add file");
+    (db, file)
+}
+
+fn change(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+    TextDocumentContentChangeEvent {
+        range: Some(Range {
+            start: Position::new(start.0, start.1),
+            end: Position::new(end.0, end.1),
+        }),
+        range_length: None,
+        text: text.into(),
+    }
+}
+
+fn edit(db: &mut BaseDb, file: File, content_changes: Vec<TextDocumentContentChangeEvent>) {
+    let event = DidChangeTextDocumentParams {
+        text_document: lsp_types::VersionedTextDocumentIdentifier {
+            uri: file.url(db).clone(),
+            version: 1,
+        },
+        content_changes,
+    };
+    file.update_edit(db, &event).unwrap();
+}
+
+fn diagnostics(db: &BaseDb, file: File) -> Vec<ParseError> {
+    get_ast::accumulated::<ParseErrorAccumulator>(db, file)
+        .into_iter()
+        .map(|error| error.0.clone())
+        .collect()
+}
+
+/// Applies each list of changes as a separate edit,
+/// and checks that the incremental AST and its diagnostics match the ones built from scratch.
+fn assert_incremental(edits: Vec<Vec<TextDocumentContentChangeEvent>>) {
+    let (mut db, file) = create_db(SOURCE);
+    get_ast(&db, file);
+
+    for content_changes in edits {
+        edit(&mut db, file, content_changes);
+
+        let incremental = format!("{:#?}", get_ast(&db, file));
+
+        let (fresh_db, fresh_file) = create_db(file.document(&db).as_str());
+        let fresh = format!("{:#?}", get_ast(&fresh_db, fresh_file));
+
+        assert_eq!(incremental, fresh);
+        assert_eq!(diagnostics(&db, file), diagnostics(&fresh_db, fresh_file));
+
+        // The type index is kept up to date with reused nodes
+        let ids = |ast: &ParsedAst| {
+            ast.of_type::<FunctionDefinition>()
+                .map(|node| node.get_id())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(get_ast(&db, file)), ids(get_ast(&fresh_db, fresh_file)));
+
+        // The AST was not built from scratch
+        assert!(get_ast(&db, file).reused_len() > 0);
+    }
+}
+
+#[rstest]
+#[case::insert_line_before(vec![vec![change((0, 0), (0, 0), "import os\n")]])]
+#[case::insert_on_same_line(vec![vec![change((2, 4), (2, 4), "z = 0; ")]])]
+#[case::edit_function_body(vec![vec![change((3, 4), (3, 8), "return 1")]])]
+#[case::rename_identifier(vec![vec![change((1, 4), (1, 7), "foobar")]])]
+#[case::delete_function(vec![vec![change((9, 0), (11, 0), "")]])]
+#[case::delete_class(vec![vec![change((5, 0), (8, 0), "")]])]
+#[case::append(vec![vec![change((11, 0), (11, 0), "qux()\n")]])]
+#[case::syntax_error(vec![vec![change((7, 15), (7, 15), "(")]])]
+#[case::multiple_changes(vec![vec![
+    change((10, 4), (10, 8), "return None"),
+    change((0, 0), (0, 9), ""),
+    change((2, 0), (2, 0), "    # inserted\n"),
+]])]
+#[case::successive_edits(vec![
+    vec![change((0, 0), (0, 0), "import os\n")],
+    vec![change((4, 4), (4, 8), "return param1")],
+    vec![change((7, 0), (7, 0), "    x: int = 1\n")],
+    vec![change((1, 0), (1, 0), "\n\n")],
+])]
+#[case::fix_syntax_error(vec![
+    vec![change((1, 7), (1, 8), "")],
+    vec![change((1, 7), (1, 7), "(")],
+])]
+fn incremental_matches_full_build(#[case] edits: Vec<Vec<TextDocumentContentChangeEvent>>) {
+    assert_incremental(edits);
+}
+
+#[test]
+fn untouched_nodes_are_shared() {
+    let (mut db, file) = create_db(SOURCE);
+    let before = get_ast(&db, file).clone();
+    let foo = before
+        .of_type::<FunctionDefinition>()
+        .next()
+        .unwrap()
+        .get_id();
+
+    // Nodes before the edit keep their id and span, so they are not copied
+    edit(&mut db, file, vec![change((11, 0), (11, 0), "qux()\n")]);
+    let after = get_ast(&db, file).clone();
+    assert!(std::ptr::addr_eq(&before[foo], &after[foo]));
+
+    // Nodes after an edit are moved, so they are copied
+    edit(&mut db, file, vec![change((0, 0), (0, 0), "import os\n")]);
+    let moved = get_ast(&db, file);
+    assert!(moved.reused_len() > 0);
+    let moved_foo = moved.of_type::<FunctionDefinition>().next().unwrap();
+    assert!(!std::ptr::addr_eq(&after[foo], moved_foo));
+}
+
+/// Returns the stable id of the function with the given name
+fn function(ast: &ParsedAst, source: &str, name: &str) -> StableNodeId {
+    let id = (0..ast.len())
+        .find(|id| {
+            ast.get::<FunctionDefinition>(*id)
+                .is_some_and(|f| f.name.cast(ast).get_text(source.as_bytes()) == Ok(name))
+        })
+        .unwrap();
+    ast.stable_id(id).unwrap()
+}
+
+/// Resolves a stable id to the name of its function
+fn resolve(ast: &ParsedAst, source: &str, stable_id: StableNodeId) -> Option<String> {
+    let function = ast.get::<FunctionDefinition>(ast.resolve(stable_id)?)?;
+    Some(
+        function
+            .name
+            .cast(ast)
+            .get_text(source.as_bytes())
+            .unwrap()
+            .to_string(),
+    )
+}
+
+#[test]
+fn stable_ids() {
+    let (mut db, file) = create_db(SOURCE);
+    let ast = get_ast(&db, file).clone();
+    let source = file.document(&db).as_str().to_string();
+
+    let foo = function(&ast, &source, "foo");
+    let baz = function(&ast, &source, "baz");
+    let qux = function(&ast, &source, "qux");
+    assert_eq!(resolve(&ast, &source, qux).as_deref(), Some("qux"));
+
+    // Inserting a statement of another kind keeps all identities
+    edit(&mut db, file, vec![change((0, 0), (0, 0), "import os\n")]);
+    let ast = get_ast(&db, file);
+    let source = file.document(&db).as_str();
+    assert_eq!(resolve(ast, source, foo).as_deref(), Some("foo"));
+    assert_eq!(resolve(ast, source, baz).as_deref(), Some("baz"));
+    assert_eq!(resolve(ast, source, qux).as_deref(), Some("qux"));
+
+    // Inserting a function shifts the index of the following functions,
+    // nested ones keep their identity
+    edit(
+        &mut db,
+        file,
+        vec![change((1, 0), (1, 0), "def first():\n    pass\n\n")],
+    );
+    let ast = get_ast(&db, file);
+    let source = file.document(&db).as_str();
+    assert_eq!(resolve(ast, source, foo).as_deref(), Some("first"));
+    assert_eq!(resolve(ast, source, baz).as_deref(), Some("baz"));
+    assert_eq!(resolve(ast, source, qux).as_deref(), Some("foo"));
+
+    // Like `get`, the innermost struct resolves to the same node as the stored type
+    let baz_id = ast.resolve(baz);
+    assert!(baz_id.is_some());
+    assert_eq!(
+        ast.resolve_as::<FunctionDefinition>(baz)
+            .map(|node| node.get_id()),
+        baz_id
+    );
+    assert_eq!(
+        ast.resolve_as::<CompoundStatement_SimpleStatement>(baz)
+            .map(|node| node.get_id()),
+        baz_id
+    );
+
+    // Identities only depend on the kinds of the nodes, not on the Rust version
+    assert_eq!(u64::from(ast.stable_id(0).unwrap()), 11231695803086784576);
+}
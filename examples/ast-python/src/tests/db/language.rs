@@ -0,0 +1,13 @@
+use crate::db::PYTHON;
+use crate::generated::{Module, verify_language};
+use auto_lsp::core::ast::AstNode;
+
+#[test]
+fn generated_ids_match_language() {
+    assert_eq!(
+        verify_language(&tree_sitter_python::LANGUAGE.into()),
+        Ok(())
+    );
+    // Also checked by `configure_parser!` in debug builds
+    assert_eq!(Module::verify_language(&PYTHON.language), Ok(()));
+}
@@ -1,3 +1,5 @@
 mod callbacks;
+mod incremental;
+mod language;
 mod salsa;
 mod type_errors;
@@ -1,7 +1,6 @@
 use crate::db::create_python_db;
 use crate::generated::{
-    CompoundStatement, CompoundStatement_SimpleStatement, Expression, Module, Parameter,
-    PrimaryExpression, TypedDefaultParameter,
+    FunctionDefinitionView, Module, ModuleView, ParametersView, TypedDefaultParameter,
 };
 use auto_lsp::core::ast::AstNode;
 use auto_lsp::core::document::Document;
@@ -25,21 +24,13 @@ pub(crate) fn type_check_default_parameters<'db>(db: &'db dyn BaseDatabase, file
     let module = root.unwrap();
     let module = module.downcast_ref::<Module>().unwrap();
 
-    for node in &module.children {
-        if let CompoundStatement_SimpleStatement::CompoundStatement(
-            CompoundStatement::FunctionDefinition(function),
-        ) = node.cast(ast)
-        {
-            function
-                .parameters
-                .cast(ast)
-                .children
-                .iter()
-                .for_each(|param| {
-                    if let Parameter::TypedDefaultParameter(typed_param) = param.cast(ast) {
-                        typed_param.check(db, &doc, ast);
-                    }
-                });
+    for node in module.view(ast).children() {
+        if let Some(function) = node.as_function_definition() {
+            for param in function.view(ast).parameters().children() {
+                if let Some(typed_param) = param.as_typed_default_parameter() {
+                    typed_param.check(db, &doc, ast);
+                }
+            }
         }
     }
 }
@@ -102,50 +93,6 @@ impl TypedDefaultParameter {
     }
 }
 
-impl Expression {
-    pub fn is_integer(&self) -> bool {
-        matches!(
-            self,
-            Expression::PrimaryExpression(PrimaryExpression::Integer(_))
-        )
-    }
-
-    pub fn is_float(&self) -> bool {
-        matches!(
-            self,
-            Expression::PrimaryExpression(PrimaryExpression::Float(_))
-        )
-    }
-
-    pub fn is_true(&self) -> bool {
-        matches!(
-            self,
-            Expression::PrimaryExpression(PrimaryExpression::True(_))
-        )
-    }
-
-    pub fn is_false(&self) -> bool {
-        matches!(
-            self,
-            Expression::PrimaryExpression(PrimaryExpression::False(_))
-        )
-    }
-
-    pub fn is_string(&self) -> bool {
-        matches!(
-            self,
-            Expression::PrimaryExpression(PrimaryExpression::String(_))
-        )
-    }
-
-    pub fn is_none(&self) -> bool {
-        matches!(
-            self,
-            Expression::PrimaryExpression(PrimaryExpression::None(_))
-        )
-    }
-}
-
 #[fixture]
 fn foo_bar() -> impl BaseDatabase {
     create_python_db(&[r#"# foo comment
@@ -1,3 +1,4 @@
+use crate::core::ast::AstNode;
 use tree_sitter::Language;
 
 /// Generates a `pub static LazyLock<Parser>` for the given tree-sitter language and AST root.
@@ -19,21 +20,41 @@ use tree_sitter::Language;
 /// // ...later, at a call site:
 /// File::from_text_doc().session(session).doc(&doc).parsers(&PYTHON).call()?;
 /// ```
+///
+/// In debug builds, the parser panics on first use if the AST was generated from another version of the grammar,
+/// see the `verify_language` function of the generated code.
+///
+/// The rules used to attach comments to nodes can be customized with an optional `comments` parameter.
+///
+/// ```rust, ignore
+/// configure_parser!(
+///     PYTHON,
+///     language: tree_sitter_python::LANGUAGE,
+///     ast_root: Module,
+///     comments: CommentRules {
+///         max_blank_lines: 1,
+///         ..Default::default()
+///     },
+/// );
+/// ```
 #[macro_export]
 macro_rules! configure_parser {
     ($name: ident,
      language: $language: path,
-     ast_root: $root: ident $(,)?) => {
+     ast_root: $root: ident
+     $(, comments: $comments: expr)? $(,)?) => {
         pub static $name: std::sync::LazyLock<$crate::core::parsers::Parser> =
             std::sync::LazyLock::new(|| {
                 let data = $crate::configure::parsers::create_parser($language);
+                #[cfg(debug_assertions)]
+                $crate::configure::parsers::verify_language::<$root>(&data.1);
                 $crate::core::parsers::Parser {
                     parser: data.0,
                     language: data.1,
                     ast_parser:
                         |db: &dyn ::auto_lsp::salsa::Database,
-                         document: &$crate::core::document::Document| {
-                            let mut builder = $crate::core::ast::Builder::default();
+                         document: &$crate::core::document::Document,
+                         mut builder: $crate::core::ast::Builder| {
                             let root = $root::try_from((
                                 &document.tree.root_node(),
                                 db,
@@ -42,13 +63,18 @@ macro_rules! configure_parser {
                                 None,
                             ))
                             .map_err(|e| $crate::core::errors::ParseError::from(e))?;
-                            let mut nodes = builder.take_nodes();
-                            nodes.push(Box::new(root));
-                            Ok(nodes)
+                            Ok(builder.take_nodes(root))
                         },
+                    comment_rules: $crate::configure_parser!(@comments $($comments)?),
                 }
             });
     };
+    (@comments) => {
+        $crate::core::ast::CommentRules::default()
+    };
+    (@comments $comments: expr) => {
+        $comments
+    };
 }
 
 #[doc(hidden)]
@@ -61,3 +87,15 @@ pub fn create_parser(
 
     (parking_lot::RwLock::new(parser), language)
 }
+
+#[doc(hidden)]
+pub fn verify_language<T: AstNode>(language: &Language) {
+    if let Err(mismatches) = T::verify_language(language) {
+        let mismatches: Vec<_> = mismatches.iter().map(ToString::to_string).collect();
+        panic!(
+            "The AST of {} does not match the tree-sitter language, regenerate it:\n{}",
+            std::any::type_name::<T>(),
+            mismatches.join("\n")
+        );
+    }
+}
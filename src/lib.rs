@@ -45,6 +45,8 @@
 //! # Cargo Features
 //!
 //! - `lsp_server`: Enables the LSP server (uses [`lsp_server`]).
+//! - `macros`: Enables the [`generate_ast!`] macro, to generate an AST without a build script.
+//! - `serde`: Enables the JSON export of ASTs generated with the `serde` option of `auto_lsp_codegen`.
 //!
 //! # Inspirations / Similar projects
 //!
@@ -80,6 +82,9 @@ pub mod core {
 pub mod configure;
 
 pub use anyhow;
+// Proc macros (enabled with the feature `macros`)
+#[cfg(feature = "macros")]
+pub use auto_lsp_macros::generate_ast;
 #[cfg(feature = "lsp_server")]
 pub use lsp_server;
 pub use lsp_types;